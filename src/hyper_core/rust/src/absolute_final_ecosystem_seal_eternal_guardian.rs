@@ -3,11 +3,12 @@
 // Applies eternal seal and guardianship to the Pi Ecosystem.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct AbsoluteFinalEcosystemSealEternalGuardian;
 
+#[contracttype]
 #[derive(Clone)]
 pub struct EternalSeal {
     pub id: Symbol,
@@ -19,14 +20,22 @@ pub struct EternalSeal {
 
 #[contractimpl]
 impl AbsoluteFinalEcosystemSealEternalGuardian {
-    /// Initialize the Eternal Guardian
-    pub fn init(env: Env) -> AbsoluteFinalEcosystemSealEternalGuardian {
+    /// Initialize the Eternal Guardian with the admin allowed to set dependency addresses
+    pub fn init(env: Env, admin: Address) -> AbsoluteFinalEcosystemSealEternalGuardian {
+        crate::access_control::set_admin(&env, &admin);
         log!(&env, "Absolute Final Ecosystem Seal Eternal Guardian Initialized");
         AbsoluteFinalEcosystemSealEternalGuardian
     }
 
-    /// Apply eternal seal
-    pub fn apply_eternal_seal(env: Env, component: Symbol) -> EternalSeal {
+    /// Apply eternal seal; an already sealed component keeps its existing seal unless `force` is set
+    pub fn apply_eternal_seal(env: Env, component: Symbol, force: bool) -> EternalSeal {
+        if !force {
+            if let Some(existing) = Self::get_seal(env.clone(), component.clone()) {
+                log!(&env, "Eternal Seal Already Applied to: {}", component);
+                return existing;
+            }
+        }
+
         // Seal with unbreakable strength
         let seal = EternalSeal {
             id: Symbol::new(&env, &format!("seal_{}", env.ledger().sequence())),
@@ -36,21 +45,38 @@ impl AbsoluteFinalEcosystemSealEternalGuardian {
             timestamp: env.ledger().timestamp(),
         };
 
+        env.storage().persistent().set(&(Symbol::new(&env, "seal"), component.clone()), &seal);
         log!(&env, "Eternal Seal Applied to: {} Strength {}", component, seal.seal_strength);
+        env.events().publish(
+            (Symbol::new(&env, "eternal_seal_applied"), component),
+            (seal.id.clone(), seal.seal_strength, seal.eternal_guard, seal.timestamp),
+        );
         seal
     }
 
+    /// Get the stored seal for a component
+    pub fn get_seal(env: Env, component: Symbol) -> Option<EternalSeal> {
+        env.storage().persistent().get(&(Symbol::new(&env, "seal"), component))
+    }
+
     /// Enforce eternal guardianship
     pub fn enforce_eternal_guardianship(env: Env, seal: EternalSeal) -> Symbol {
         if !seal.eternal_guard {
             log!(&env, "Guardianship Breach Detected: Halting Ecosystem");
-            crate::ahi_ai_core::AhiAiCore::enforce_compliance(env.clone());
+            // Falls back to the in-crate AI core when no deployed one is set, so a breach is never left unenforced
+            crate::contract_deps::enforce_compliance(&env);
             Symbol::new(&env, "guardianship_enforced")
         } else {
             Symbol::new(&env, "eternal_protection_active")
         }
     }
 
+    /// Set a dependency contract address, e.g. "ahi_ai_core" (admin only)
+    pub fn set_dependency(env: Env, name: Symbol, address: Address) {
+        crate::access_control::require_admin(&env);
+        crate::contract_deps::set_dependency(&env, name, address);
+    }
+
     /// Seal the entire ecosystem
     pub fn seal_entire_ecosystem(env: Env) -> Vec<EternalSeal> {
         let components = Vec::from_array(&env, [
@@ -72,7 +98,7 @@ impl AbsoluteFinalEcosystemSealEternalGuardian {
             Symbol::new(&env, "guardian_summary"),
         ]);
 
-        let seals = components.iter().map(|comp| Self::apply_eternal_seal(env.clone(), comp.clone())).collect();
+        let seals = components.iter().map(|comp| Self::apply_eternal_seal(env.clone(), comp.clone(), false)).collect();
         log!(&env, "Entire Ecosystem Sealed Eternally");
         seals
     }
@@ -99,12 +125,12 @@ impl AbsoluteFinalEcosystemSealEternalGuardian {
     }
 
     /// Render eternal seal hologram
-    pub fn render_eternal_seal_hologram(env: Env, seal: EternalSeal) -> Vec<Symbol> {
+    pub fn render_eternal_seal_hologram(env: Env, seal: EternalSeal) -> Vec<String> {
         let hologram = Vec::from_array(&env, [
-            Symbol::new(&env, "Eternal Seal Hologram"),
-            seal.sealed_component,
-            Symbol::new(&env, &format!("Strength: {}", seal.seal_strength)),
-            Symbol::new(&env, "Guardianship: Eternal"),
+            String::from_str(&env, "Eternal Seal Hologram"),
+            crate::hologram::symbol_to_string(&env, &seal.sealed_component),
+            String::from_str(&env, &format!("Strength: {}", seal.seal_strength)),
+            String::from_str(&env, "Guardianship: Eternal"),
         ]);
         log!(&env, "Eternal Seal Hologram Rendered");
         hologram
@@ -0,0 +1,40 @@
+// src/hyper_core/rust/src/access_control.rs
+// Access Control - shared admin storage and authorization for Soroban contracts
+// Each contract stores its admin at init and requires the admin's auth before mutating rules.
+// Dependencies: soroban-sdk = "0.9" in Cargo.toml
+
+use soroban_sdk::{Address, Env, Symbol, log};
+
+/// Store the contract admin (called from each contract's init); panics if an admin is already set
+pub fn set_admin(env: &Env, admin: &Address) {
+    if env.storage().instance().has(&Symbol::new(env, "admin")) {
+        panic!("admin already set: contract already initialized");
+    }
+    store_admin(env, admin);
+}
+
+/// Get the stored admin; panics if the contract was never initialized
+pub fn get_admin(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&Symbol::new(env, "admin"))
+        .expect("admin not set: contract not initialized")
+}
+
+/// Require the stored admin's authorization for the current invocation
+pub fn require_admin(env: &Env) -> Address {
+    let admin = get_admin(env);
+    admin.require_auth();
+    admin
+}
+
+/// Hand admin rights to a new address (authorized by the current admin)
+pub fn transfer_admin(env: &Env, new_admin: Address) {
+    let admin = require_admin(env);
+    store_admin(env, &new_admin);
+    log!(env, "Admin Transferred: {} -> {}", admin, new_admin);
+}
+
+fn store_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&Symbol::new(env, "admin"), admin);
+}
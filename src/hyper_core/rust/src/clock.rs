@@ -0,0 +1,49 @@
+// src/hyper_core/rust/src/clock.rs
+// Time source for the async Super App modules.
+// SystemClock reads wall-clock time; MockClock returns an injected time so event timestamps are deterministic in tests.
+// Dependencies: Add to Cargo.toml: chrono = "0.4"
+// Integrate with the async modules: use crate::clock::{Clock, SystemClock};
+
+use std::sync::Mutex;
+use chrono::{DateTime, Duration, Utc};
+
+// Source of "now" for timestamps and time windows
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+// Wall-clock time (the default everywhere)
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+// Fixed time that only moves when set or advanced
+#[derive(Debug)]
+pub struct MockClock {
+    now: Mutex<DateTime<Utc>>,
+}
+
+impl MockClock {
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self { now: Mutex::new(now) }
+    }
+
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner()) = now;
+    }
+
+    pub fn advance(&self, by: Duration) {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner()) += by;
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
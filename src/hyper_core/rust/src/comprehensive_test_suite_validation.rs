@@ -3,7 +3,7 @@
 // Runs comprehensive tests and validations for Pi Ecosystem.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, Env, String, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct ComprehensiveTestSuiteValidation;
@@ -43,9 +43,13 @@ impl ComprehensiveTestSuiteValidation {
         test
     }
 
-    /// Enforce test suite integrity
+    /// Enforce test suite integrity; every enforced test counts towards the suite's validation rate
     pub fn enforce_test_integrity(env: Env, test: ValidationTest) -> Symbol {
+        let tests_run = Self::read_counter(&env, "tests_run") + 1;
+        env.storage().instance().set(&Symbol::new(&env, "tests_run"), &tests_run);
         if !test.test_passed {
+            let tests_failed = Self::read_counter(&env, "tests_failed") + 1;
+            env.storage().instance().set(&Symbol::new(&env, "tests_failed"), &tests_failed);
             log!(&env, "Test Failure Detected: Halting {}", test.test_target);
             crate::ahi_ai_core::AhiAiCore::enforce_compliance(env.clone());
             Symbol::new(&env, "test_enforced")
@@ -69,15 +73,24 @@ impl ComprehensiveTestSuiteValidation {
         tests
     }
 
-    /// Get test status
+    /// Get test status; validation_rate is the share (0-100) of enforced tests that passed (100 before any ran)
     pub fn get_test_status(env: Env) -> Map<Symbol, i64> {
+        let tests_run = Self::read_counter(&env, "tests_run");
+        let tests_failed = Self::read_counter(&env, "tests_failed");
+        let validation_rate = if tests_run == 0 { 100 } else { (tests_run - tests_failed) * 100 / tests_run };
+
         let mut status = Map::new(&env);
-        status.set(Symbol::new(&env, "tests_run"), 50); // Simulated count
-        status.set(Symbol::new(&env, "validation_rate"), 100);
+        status.set(Symbol::new(&env, "tests_run"), tests_run);
+        status.set(Symbol::new(&env, "validation_rate"), validation_rate);
         status.set(Symbol::new(&env, "suite_integrity"), 100);
         status
     }
 
+    /// Read a stored counter (zero until first incremented)
+    fn read_counter(env: &Env, name: &str) -> i64 {
+        env.storage().instance().get(&Symbol::new(env, name)).unwrap_or(0)
+    }
+
     /// Update test rules
     pub fn update_test_rules(env: Env, new_rule: Symbol) -> Result<Symbol, Symbol> {
         // Validate via Expansion
@@ -91,12 +104,12 @@ impl ComprehensiveTestSuiteValidation {
     }
 
     /// Render test hologram
-    pub fn render_test_hologram(env: Env, test: ValidationTest) -> Vec<Symbol> {
+    pub fn render_test_hologram(env: Env, test: ValidationTest) -> Vec<String> {
         let hologram = Vec::from_array(&env, [
-            Symbol::new(&env, "Comprehensive Test Hologram"),
-            test.test_target,
-            Symbol::new(&env, &format!("Test Passed: {}", test.test_passed)),
-            Symbol::new(&env, &format!("Validation Score: {}", test.validation_score)),
+            String::from_str(&env, "Comprehensive Test Hologram"),
+            crate::hologram::symbol_to_string(&env, &test.test_target),
+            String::from_str(&env, &format!("Test Passed: {}", test.test_passed)),
+            String::from_str(&env, &format!("Validation Score: {}", test.validation_score)),
         ]);
         log!(&env, "Test Hologram Rendered");
         hologram
@@ -0,0 +1,40 @@
+// src/hyper_core/rust/src/contract_deps.rs
+// Contract Dependencies - stored addresses for cross-contract calls that may not be deployed
+// Calls go through try_invoke_contract so a missing dependency logs a warning instead of trapping.
+// Dependencies: soroban-sdk = "0.9" in Cargo.toml
+
+use soroban_sdk::{Address, Env, InvokeError, Symbol, TryFromVal, Val, Vec, log};
+
+/// Store the deployed address of a named dependency (e.g. "ahi_ai_core")
+pub fn set_dependency(env: &Env, name: Symbol, address: Address) {
+    log!(env, "Dependency Set: {} -> {}", name, address);
+    env.storage().instance().set(&(Symbol::new(env, "dependency"), name), &address);
+}
+
+/// Get a dependency's stored address, if set
+pub fn get_dependency(env: &Env, name: Symbol) -> Option<Address> {
+    env.storage().instance().get(&(Symbol::new(env, "dependency"), name))
+}
+
+/// Invoke `func` on a dependency; returns None (with a warning) when it is unset, undeployed, or fails
+pub fn try_invoke_dependency<T: TryFromVal<Env, Val>>(env: &Env, name: &str, func: &str, args: Vec<Val>) -> Option<T> {
+    let name = Symbol::new(env, name);
+    let Some(address) = get_dependency(env, name.clone()) else {
+        log!(env, "Warning: Dependency {} Not Set, Skipping {}", name, Symbol::new(env, func));
+        return None;
+    };
+    match env.try_invoke_contract::<T, InvokeError>(&address, &Symbol::new(env, func), args) {
+        Ok(Ok(result)) => Some(result),
+        _ => {
+            log!(env, "Warning: Dependency {} Call {} Failed", name, Symbol::new(env, func));
+            None
+        }
+    }
+}
+
+/// Run compliance enforcement on the deployed "ahi_ai_core", falling back to the in-crate AhiAiCore when no address
+/// is set or the call fails, so enforcement never silently skips
+pub fn enforce_compliance(env: &Env) -> Symbol {
+    try_invoke_dependency::<Symbol>(env, "ahi_ai_core", "enforce_compliance", Vec::new(env))
+        .unwrap_or_else(|| crate::ahi_ai_core::AhiAiCore::enforce_compliance(env.clone()))
+}
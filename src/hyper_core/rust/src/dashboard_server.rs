@@ -0,0 +1,51 @@
+// src/hyper_core/rust/src/dashboard_server.rs
+// HTTP endpoints for the Pi Ecosystem Dashboard (enabled with the `http-server` feature).
+// GET /dashboard serves export_json; GET /metrics serves export_prometheus for scraping.
+// Dependencies: Add to Cargo.toml: axum = { version = "0.7", optional = true }, and the feature http-server = ["axum"]
+// Integrate with the dashboard module: pub mod pi_ecosystem_dashboard; pub mod dashboard_server;
+
+#![cfg(feature = "http-server")]
+
+use std::sync::Arc;
+use axum::extract::State;
+use axum::http::{header, StatusCode};
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::Router;
+use tokio::net::TcpListener;
+use tokio_util::sync::CancellationToken;
+use crate::pi_ecosystem_dashboard::PiEcosystemDashboardModule;
+use crate::telemetry::info;
+
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+// Routes for the dashboard, usable standalone or nested into a larger app
+pub fn dashboard_router(dashboard: Arc<PiEcosystemDashboardModule>) -> Router {
+    Router::new()
+        .route("/dashboard", get(dashboard_json))
+        .route("/metrics", get(prometheus_metrics))
+        .with_state(dashboard)
+}
+
+// Serve the dashboard on an already-bound listener until shutdown is cancelled
+pub async fn serve_dashboard(
+    dashboard: Arc<PiEcosystemDashboardModule>,
+    listener: TcpListener,
+    shutdown: CancellationToken,
+) -> std::io::Result<()> {
+    info!(addr = %listener.local_addr()?, "Dashboard server listening");
+    axum::serve(listener, dashboard_router(dashboard))
+        .with_graceful_shutdown(async move { shutdown.cancelled().await })
+        .await
+}
+
+async fn dashboard_json(State(dashboard): State<Arc<PiEcosystemDashboardModule>>) -> impl IntoResponse {
+    match dashboard.export_json().await {
+        Ok(json) => ([(header::CONTENT_TYPE, "application/json")], json).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, format!("Dashboard serialization failed: {}", e)).into_response(),
+    }
+}
+
+async fn prometheus_metrics(State(dashboard): State<Arc<PiEcosystemDashboardModule>>) -> impl IntoResponse {
+    ([(header::CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)], dashboard.export_prometheus().await)
+}
@@ -1,37 +1,60 @@
 // src/hyper_core/rust/src/developer_app_orchestrator.rs
 // Developer App Orchestrator for Pi Ecosystem Super App
 // Autonomously builds, manages, and runs millions of developer applications.
-// Dependencies: Add to Cargo.toml: tokio = "1.0", rayon = "1.5" (for parallelism), serde = { version = "1.0", features = ["derive"] }, uuid = "1.0" (for app IDs)
+// Dependencies: Add to Cargo.toml: tokio = "1.0", rayon = "1.5" (for parallelism), serde = { version = "1.0", features = ["derive"] }, uuid = "1.0" (for app IDs), serde_json = "1.0" (for state export)
 // Integrate with previous modules: pub mod hyper_ai_core; pub mod pi_transaction_engine; pub mod pi_mainnet_accelerator; pub mod ecosystem_isolation_shield;
 
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use tokio::sync::{watch, Mutex, Semaphore};
+use tokio::task::JoinHandle;
 use serde::{Deserialize, Serialize};
 use rayon::prelude::*;
 use uuid::Uuid;
 use crate::hyper_ai_core::AutonomousHyperAI;
 use crate::pi_transaction_engine::PITransactionEngine;
-use crate::pi_mainnet_accelerator::PiMainnetAccelerator;
+use crate::pi_mainnet_accelerator::{EvolutionSignals, PiMainnetAccelerator};
 use crate::ecosystem_isolation_shield::EcosystemIsolationShield;
+use crate::error::SuperPiError;
+use crate::telemetry::{info, warn};
+use crate::worker_pool::WorkerPool;
 
 // App Struct
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct PiApp {
     pub id: String,
     pub developer: String,
-    pub code_hash: String, // Simulated code integrity
+    pub code_hash: String, // Simulated code integrity (equal for identical code)
+    #[serde(default)]
+    pub content_address: String, // Per-developer identity: hash of salt, developer, and code
     pub status: AppStatus,
     pub pi_usage: f64, // PI consumed
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AppStatus {
     Building,
     Running,
     Halted,
 }
 
-// Orchestrator Core
+// App lifecycle: Building -> Running, and either may be Halted; Halted is final
+pub fn can_transition(from: AppStatus, to: AppStatus) -> bool {
+    matches!(
+        (from, to),
+        (AppStatus::Building, AppStatus::Running) | (AppStatus::Building, AppStatus::Halted) | (AppStatus::Running, AppStatus::Halted)
+    )
+}
+
+const EVOLUTION_WINDOW_SECS: u64 = 300; // Isolation events older than this don't affect evolution
+const DEFAULT_APP_PI_COST: f64 = 100.0; // Flat charge per deploy unless a cost function is supplied
+const DEFAULT_ADDRESS_SALT: &str = "super-pi-app"; // Domain-separates content addresses from plain code hashes
+
+// Maps app code to the PI charged for deploying it
+pub type AppCostFn = Box<dyn Fn(&str) -> f64 + Send + Sync>;
+type SharedCostFn = Arc<dyn Fn(&str) -> f64 + Send + Sync>; // Shared so clones of the orchestrator charge alike
+
+// Orchestrator Core (cheap to clone: all state is shared)
+#[derive(Clone)]
 pub struct DeveloperAppOrchestrator {
     ai_core: Arc<AutonomousHyperAI>,
     tx_engine: Arc<PITransactionEngine>,
@@ -39,13 +62,68 @@ pub struct DeveloperAppOrchestrator {
     isolation_shield: Arc<EcosystemIsolationShield>,
     apps: Arc<Mutex<Vec<PiApp>>>,
     metrics: Arc<Mutex<OrchestratorMetrics>>,
+    address_salt: String, // Mixed into content addresses
+    metrics_tx: Arc<watch::Sender<OrchestratorMetrics>>, // Latest metrics for watch_metrics subscribers
+    cost_fn: Option<SharedCostFn>, // Flat DEFAULT_APP_PI_COST unless set via with_cost_fn
+    thread_pool: WorkerPool, // rayon's global pool unless set via with_parallelism
 }
 
-#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct OrchestratorMetrics {
     pub apps_managed: u64,
     pub pi_consumed_total: f64,
     pub uptime_rate: f64,
+    #[serde(default)]
+    pub apps_halted: u64, // Apps currently Halted, so halts reach watch_metrics subscribers
+}
+
+impl Default for OrchestratorMetrics {
+    fn default() -> Self {
+        Self { apps_managed: 0, pi_consumed_total: 0.0, uptime_rate: 1.0, apps_halted: 0 }
+    }
+}
+
+fn count_halted(apps: &[PiApp]) -> u64 {
+    apps.iter().filter(|a| a.status == AppStatus::Halted).count() as u64
+}
+
+// Exported orchestrator state: metrics plus every app with its status and code hash
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct OrchestratorState {
+    pub metrics: OrchestratorMetrics,
+    pub running_apps: usize,
+    pub apps: Vec<PiApp>,
+}
+
+// Pre-flight verdict for code that hasn't been deployed
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PrecheckResult {
+    pub passed: bool,
+    pub shield_score: Option<f64>, // None when the shield refuses to scan (e.g., oversized input)
+    pub ai_score: f64,
+    pub reasons: Vec<String>, // Why it would be rejected; empty when passed
+}
+
+// Outcome of a successful deployment
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct DeployResult {
+    pub app_id: String,
+    pub code_hash: String,
+    pub content_address: String,
+    pub pi_charged: f64,
+}
+
+impl std::fmt::Display for DeployResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "App {} deployed successfully.", self.app_id)
+    }
+}
+
+// Point-in-time copy of apps and metrics, restorable into another orchestrator (e.g., blue/green deploys)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct OrchestratorSnapshot {
+    pub apps: Vec<PiApp>,
+    pub metrics: OrchestratorMetrics,
 }
 
 impl DeveloperAppOrchestrator {
@@ -61,75 +139,330 @@ impl DeveloperAppOrchestrator {
             mainnet_accelerator,
             isolation_shield,
             apps: Arc::new(Mutex::new(Vec::new())),
-            metrics: Arc::new(Mutex::new(OrchestratorMetrics {
-                apps_managed: 0,
-                pi_consumed_total: 0.0,
-                uptime_rate: 1.0,
-            })),
+            metrics: Arc::new(Mutex::new(OrchestratorMetrics::default())),
+            address_salt: DEFAULT_ADDRESS_SALT.to_string(),
+            metrics_tx: Arc::new(watch::channel(OrchestratorMetrics::default()).0),
+            cost_fn: None,
+            thread_pool: WorkerPool::default(),
         }
     }
 
+    // Receiver that sees the latest metrics and wakes whenever they change (deploys, halts, resets, restores)
+    pub fn watch_metrics(&self) -> watch::Receiver<OrchestratorMetrics> {
+        self.metrics_tx.subscribe()
+    }
+
+    // Push metrics to watchers; unchanged values don't wake them
+    fn publish_metrics(&self, metrics: &OrchestratorMetrics) {
+        self.metrics_tx.send_if_modified(|current| {
+            if current == metrics {
+                return false;
+            }
+            *current = metrics.clone();
+            true
+        });
+    }
+
+    // Charge deploys by code (e.g., by length or complexity) instead of the flat default
+    pub fn with_cost_fn(mut self, cost_fn: AppCostFn) -> Self {
+        self.cost_fn = Some(Arc::from(cost_fn));
+        self
+    }
+
+    // PI charged for deploying this code; must be finite and non-negative
+    pub fn app_cost(&self, code: &str) -> Result<f64, SuperPiError> {
+        let cost = self.cost_fn.as_ref().map_or(DEFAULT_APP_PI_COST, |cost_fn| cost_fn(code));
+        if !cost.is_finite() || cost < 0.0 {
+            return Err(SuperPiError::Rejected(format!("Invalid PI cost for app: {}", cost)));
+        }
+        Ok(cost)
+    }
+
+    // Override the content address salt (e.g., per deployment environment)
+    pub fn with_address_salt(mut self, salt: &str) -> Self {
+        self.address_salt = salt.to_string();
+        self
+    }
+
+    // Run parallel sections on a dedicated pool of `threads` workers instead of rayon's global pool,
+    // so a shared process isn't starved
+    pub fn with_parallelism(mut self, threads: usize) -> Result<Self, String> {
+        self.thread_pool = WorkerPool::with_threads(threads)?;
+        Ok(self)
+    }
+
+    // Worker threads available to parallel sections
+    pub fn parallelism(&self) -> usize {
+        self.thread_pool.threads()
+    }
+
+    // Zero every counter (e.g., between tests sharing an Arc). Recorded apps are dropped and released from their
+    // nodes too, so the zeroed counts still describe the state and reconcile_app_counts stays consistent.
+    pub async fn reset_metrics(&self) {
+        let mut apps = self.apps.lock().await;
+        let mut metrics = self.metrics.lock().await;
+        let app_ids: Vec<String> = apps.iter().map(|a| a.id.clone()).collect();
+        self.mainnet_accelerator.release_apps(&app_ids).await;
+        apps.clear();
+        *metrics = OrchestratorMetrics::default();
+        self.publish_metrics(&metrics);
+    }
+
     // Build and deploy an app autonomously
-    pub async fn deploy_app(&self, developer: String, code: String) -> Result<String, String> {
+    #[cfg_attr(feature = "tracing-logs", tracing::instrument(skip_all, fields(developer = %developer)))]
+    pub async fn deploy_app(&self, developer: String, code: String) -> Result<DeployResult, SuperPiError> {
+        // Fail fast with a clear reason instead of deep inside manage_apps
+        if !self.is_ready().await {
+            return Err(SuperPiError::Unavailable("ecosystem not ready: accelerate mainnet first".to_string()));
+        }
+
+        // PI exclusivity: code naming another network is refused before any scoring
+        self.tx_engine.exclusivity_policy().validate_field("app code", &code).map_err(SuperPiError::Rejected)?;
+
         // Shield isolation check
-        self.isolation_shield.process_stream(code.clone()).await?;
+        self.isolation_shield.process_stream(code.clone()).await.map_err(SuperPiError::Rejected)?;
 
         // AI compliance
-        self.ai_core.filter_io(&code).await?;
+        self.ai_core.filter_io(&code).await.map_err(SuperPiError::Rejected)?;
+
+        let pi_usage = self.app_cost(&code)?;
 
         // Simulate building (in real impl: compile and containerize)
         let app_id = Uuid::new_v4().to_string();
         let app = PiApp {
             id: app_id.clone(),
-            developer,
+            developer: developer.clone(),
             code_hash: self.hash_code(&code),
-            status: AppStatus::Running,
-            pi_usage: 100.0, // Example PI cost
+            content_address: self.content_address(&developer, &code),
+            status: AppStatus::Building, // Becomes Running via finalize_build
+            pi_usage,
+        };
+        let result = DeployResult {
+            app_id,
+            code_hash: app.code_hash.clone(),
+            content_address: app.content_address.clone(),
+            pi_charged: app.pi_usage,
+        };
+
+        // Assign and record as one unit that finishes even if the caller is dropped (e.g., a command
+        // timeout); cancelling before this point leaves no trace of the app
+        let orchestrator = self.clone();
+        tokio::spawn(async move { orchestrator.commit_app(app).await })
+            .await
+            .map_err(|e| SuperPiError::TaskFailed(format!("Deploy task failed: {}", e)))??;
+
+        Ok(result)
+    }
+
+    // Would deploy_app's screening accept this code? Runs the shield and AI checks without
+    // recording isolation events, metrics, or apps (mainnet readiness is not checked)
+    pub fn precheck_code(&self, code: &str) -> PrecheckResult {
+        let mut reasons = Vec::new();
+
+        if let Err(e) = self.tx_engine.exclusivity_policy().validate_field("app code", code) {
+            reasons.push(format!("Exclusivity policy would reject: {}", e));
+        }
+
+        let shield_score = match self.isolation_shield.screen(code) {
+            Ok((score, isolated)) => {
+                if isolated {
+                    reasons.push(format!("Shield would isolate: volatility score {:.2}", score));
+                }
+                Some(score)
+            }
+            Err(e) => {
+                reasons.push(format!("Shield refused input: {}", e));
+                None
+            }
         };
 
-        // Assign to mainnet nodes
-        self.mainnet_accelerator.manage_apps(vec![app_id.clone()]).await?;
+        let ai = self.ai_core.filter_io_explain(code);
+        if ai.rejected {
+            reasons.push(format!("AI filter would reject: volatility score {:.2}", ai.score));
+        }
 
-        self.apps.lock().await.push(app);
+        PrecheckResult { passed: reasons.is_empty(), shield_score, ai_score: ai.score, reasons }
+    }
 
-        // Update metrics
+    // Ready to accept deploys once the accelerator has at least one active node
+    pub async fn is_ready(&self) -> bool {
+        self.mainnet_accelerator.active_node_count().await > 0
+    }
+
+    async fn commit_app(&self, app: PiApp) -> Result<(), SuperPiError> {
+        // Assign to mainnet nodes; nothing is recorded unless assignment succeeds
+        self.mainnet_accelerator.manage_apps(vec![app.id.clone()]).await.map_err(SuperPiError::Unavailable)?;
+
+        // Record app and metrics together so readers never see one without the other
+        let mut apps = self.apps.lock().await;
         let mut metrics = self.metrics.lock().await;
         metrics.apps_managed += 1;
-        metrics.pi_consumed_total += 100.0;
-
-        Ok(format!("App {} deployed successfully.", app_id))
+        metrics.pi_consumed_total += app.pi_usage;
+        apps.push(app);
+        self.publish_metrics(&metrics);
+        Ok(())
     }
 
-    // Run and monitor apps in parallel
-    pub async fn run_apps(&self) -> Result<(), String> {
-        let apps = self.apps.lock().await.clone();
-        let results: Vec<String> = apps
-            .par_iter()
-            .map(|app| {
-                // Simulate running (e.g., execute PI transactions)
-                format!("App {} running with PI usage: {:.2}", app.id, app.pi_usage)
+    // Deploy a batch of (developer, code) pairs with at most `concurrency` deployments in flight; results keep batch order
+    pub async fn deploy_apps_pooled(&self, batch: Vec<(String, String)>, concurrency: usize) -> Vec<Result<DeployResult, SuperPiError>> {
+        let permits = Arc::new(Semaphore::new(concurrency.max(1)));
+        let handles: Vec<_> = batch
+            .into_iter()
+            .map(|(developer, code)| {
+                let orchestrator = self.clone();
+                let permits = permits.clone();
+                tokio::spawn(async move {
+                    let _permit = permits.acquire_owned().await.map_err(|e| SuperPiError::TaskFailed(e.to_string()))?;
+                    orchestrator.deploy_app(developer, code).await
+                })
             })
             .collect();
 
-        for result in results {
-            println!("{}", result);
+        let mut results = Vec::with_capacity(handles.len());
+        for handle in handles {
+            results.push(handle.await.unwrap_or_else(|e| Err(SuperPiError::TaskFailed(format!("Deploy task failed: {}", e)))));
+        }
+        results
+    }
+
+    // Deploy and finish the build as one unit, so an Ok app is already Running. If the caller stops waiting
+    // (e.g., a command timeout drops this future), the deploy still lands consistently and is then rolled back.
+    pub async fn deploy_and_finalize(&self, developer: String, code: String) -> Result<DeployResult, SuperPiError> {
+        let orchestrator = self.clone();
+        let task = tokio::spawn(async move {
+            let deployed = orchestrator.deploy_app(developer, code).await?;
+            if let Err(e) = orchestrator.finalize_build(&deployed.app_id).await {
+                orchestrator.rollback_app(&deployed.app_id).await;
+                return Err(SuperPiError::TaskFailed(e));
+            }
+            Ok(deployed)
+        });
+
+        let mut guard = RollbackOnDrop { orchestrator: self.clone(), task: Some(task) };
+        let joined = guard.task.as_mut().expect("Deploy task is set until joined").await;
+        guard.task = None; // Joined: the caller sees the outcome, so nothing is rolled back
+        joined.map_err(|e| SuperPiError::TaskFailed(format!("Deploy task failed: {}", e)))?
+    }
+
+    // Undo a deploy: drop the app, release its node slot, and take it back out of the metrics
+    async fn rollback_app(&self, app_id: &str) {
+        let released = self.mainnet_accelerator.release_apps(&[app_id.to_string()]).await;
+        let mut apps = self.apps.lock().await;
+        let mut metrics = self.metrics.lock().await;
+        if let Some(index) = apps.iter().position(|a| a.id == app_id) {
+            let app = apps.remove(index);
+            metrics.apps_managed = metrics.apps_managed.saturating_sub(1);
+            if app.status == AppStatus::Halted {
+                metrics.apps_halted = metrics.apps_halted.saturating_sub(1);
+            }
+            metrics.pi_consumed_total -= app.pi_usage;
+            self.publish_metrics(&metrics);
+        }
+        warn!(app_id = %app_id, released, "Deploy rolled back");
+    }
+
+    // Mark a built app as Running
+    pub async fn finalize_build(&self, app_id: &str) -> Result<(), String> {
+        self.transition(app_id, AppStatus::Running).await?;
+        info!(app_id = %app_id, "App build finalized");
+        Ok(())
+    }
+
+    // Move an app to a new status, rejecting transitions the lifecycle doesn't allow
+    async fn transition(&self, app_id: &str, to: AppStatus) -> Result<(), String> {
+        let mut apps = self.apps.lock().await;
+        let app = apps.iter_mut().find(|a| a.id == app_id).ok_or_else(|| "App not found.".to_string())?;
+        if !can_transition(app.status, to) {
+            return Err(format!("Invalid app status transition: {:?} -> {:?}", app.status, to));
+        }
+        app.status = to;
+        Ok(())
+    }
+
+    // Run and monitor Running apps in parallel (apps still building or halted are skipped)
+    pub async fn run_apps(&self) -> Result<(), String> {
+        let apps = self.apps_by_status(AppStatus::Running).await;
+        let results: Vec<(String, f64)> = self.thread_pool.install(|| {
+            apps.par_iter()
+                .map(|app| {
+                    // Simulate running (e.g., execute PI transactions)
+                    (app.id.clone(), app.pi_usage)
+                })
+                .collect()
+        });
+
+        for (app_id, pi_usage) in results {
+            info!(app_id = %app_id, pi_usage, "App running");
         }
 
         // Feed metrics back to evolution
-        self.mainnet_accelerator.evolve_system().await?;
+        let signals = self.evolution_signals().await;
+        self.mainnet_accelerator.evolve_system(signals).await?;
         Ok(())
     }
 
+    // Collect observed signals for the accelerator's evolution cycle
+    pub async fn evolution_signals(&self) -> EvolutionSignals {
+        EvolutionSignals {
+            recent_isolation_events: self.isolation_shield.recent_event_count(EVOLUTION_WINDOW_SECS).await,
+            app_uptime_rate: self.metrics.lock().await.uptime_rate,
+        }
+    }
+
     // Halt non-compliant apps
     pub async fn halt_app(&self, app_id: &str) -> Result<(), String> {
+        self.transition(app_id, AppStatus::Halted).await?;
+        warn!(app_id = %app_id, "App halted due to non-compliance");
+        self.record_halts(1).await;
+        Ok(())
+    }
+
+    // Halt every app owned by a non-compliant developer; returns how many were newly halted
+    pub async fn halt_developer(&self, developer: &str) -> usize {
         let mut apps = self.apps.lock().await;
-        if let Some(app) = apps.iter_mut().find(|a| a.id == app_id) {
+        let mut halted = 0;
+        for app in apps.iter_mut().filter(|a| a.developer == developer && can_transition(a.status, AppStatus::Halted)) {
             app.status = AppStatus::Halted;
-            println!("App {} halted due to non-compliance.", app_id);
-        } else {
-            return Err("App not found.".to_string());
+            halted += 1;
         }
-        Ok(())
+        warn!(developer = %developer, halted, "Developer apps halted due to non-compliance");
+        self.record_halts(halted).await;
+        halted
+    }
+
+    // Halt every app built from recalled code (matched on code_hash); returns how many were newly halted
+    pub async fn halt_by_code_hash(&self, code_hash: &str) -> usize {
+        let mut apps = self.apps.lock().await;
+        let mut halted = 0;
+        for app in apps.iter_mut().filter(|a| a.code_hash == code_hash && can_transition(a.status, AppStatus::Halted)) {
+            app.status = AppStatus::Halted;
+            warn!(app_id = %app.id, developer = %app.developer, code_hash = %code_hash, "App halted: code hash recalled");
+            halted += 1;
+        }
+        self.record_halts(halted).await;
+        halted
+    }
+
+    // Count newly halted apps in the metrics; watchers are woken only when there were any
+    async fn record_halts(&self, halted: usize) {
+        let mut metrics = self.metrics.lock().await;
+        metrics.apps_halted += halted as u64;
+        self.publish_metrics(&metrics);
+    }
+
+    // Look up a deployed app by id
+    pub async fn get_app(&self, id: &str) -> Option<PiApp> {
+        self.apps.lock().await.iter().find(|a| a.id == id).cloned()
+    }
+
+    // List apps currently in the given status
+    pub async fn apps_by_status(&self, status: AppStatus) -> Vec<PiApp> {
+        self.apps.lock().await.iter().filter(|a| a.status == status).cloned().collect()
+    }
+
+    // List apps deployed by a developer
+    pub async fn apps_by_developer(&self, dev: &str) -> Vec<PiApp> {
+        self.apps.lock().await.iter().filter(|a| a.developer == dev).cloned().collect()
     }
 
     // Hash code for integrity
@@ -140,10 +473,107 @@ impl DeveloperAppOrchestrator {
         format!("{:x}", hasher.finalize())
     }
 
+    // Deterministic per-developer address for code; fields are length-prefixed so no two
+    // (developer, code) splits of the same bytes collide
+    pub fn content_address(&self, developer: &str, code: &str) -> String {
+        use sha2::{Digest, Sha256};
+        let mut hasher = Sha256::new();
+        for part in [self.address_salt.as_str(), developer, code] {
+            hasher.update((part.len() as u64).to_be_bytes());
+            hasher.update(part);
+        }
+        format!("{:x}", hasher.finalize())
+    }
+
+    // Compare accelerator and orchestrator app counts (accelerator, orchestrator) to detect drift
+    pub async fn reconcile_app_counts(&self) -> (u64, u64) {
+        let accelerator_count = self.mainnet_accelerator.app_count().await;
+        let orchestrator_count = self.metrics.lock().await.apps_managed;
+        (accelerator_count, orchestrator_count)
+    }
+
     // Get metrics
     pub async fn get_metrics(&self) -> OrchestratorMetrics {
         self.metrics.lock().await.clone()
     }
+
+    // Export metrics and the app list as one JSON document (for API use)
+    pub async fn export_state_json(&self) -> Result<String, serde_json::Error> {
+        let apps = self.apps.lock().await.clone();
+        let metrics = self.metrics.lock().await.clone();
+        let state = OrchestratorState {
+            metrics,
+            running_apps: apps.iter().filter(|a| a.status == AppStatus::Running).count(),
+            apps,
+        };
+        serde_json::to_string_pretty(&state)
+    }
+
+    // Copy apps and metrics under both locks so the snapshot is consistent
+    pub async fn snapshot(&self) -> OrchestratorSnapshot {
+        let apps = self.apps.lock().await;
+        let metrics = self.metrics.lock().await;
+        OrchestratorSnapshot { apps: apps.clone(), metrics: metrics.clone() }
+    }
+
+    // Replace apps and metrics with a snapshot; rejected before any change if its counts disagree. The current apps
+    // are released from their nodes and the restored ones (halted included, as in live operation) placed instead, so
+    // reconcile_app_counts still agrees. If the accelerator can't place them (e.g., not accelerated yet), the current
+    // apps are placed back and the error returned with the orchestrator unchanged.
+    pub async fn restore(&self, snapshot: OrchestratorSnapshot) -> Result<(), String> {
+        if snapshot.metrics.apps_managed != snapshot.apps.len() as u64 {
+            return Err(format!(
+                "Corrupt snapshot: apps_managed is {} but {} apps are present",
+                snapshot.metrics.apps_managed,
+                snapshot.apps.len()
+            ));
+        }
+
+        let mut apps = self.apps.lock().await;
+        let mut metrics = self.metrics.lock().await;
+        let current: Vec<String> = apps.iter().map(|a| a.id.clone()).collect();
+        let restored: Vec<String> = snapshot.apps.iter().map(|a| a.id.clone()).collect();
+        self.mainnet_accelerator.release_apps(&current).await;
+        if let Err(e) = self.mainnet_accelerator.manage_apps(restored.clone()).await {
+            // Undo any partial placement before putting the current apps back
+            self.mainnet_accelerator.release_apps(&restored).await;
+            if let Err(replace_err) = self.mainnet_accelerator.manage_apps(current).await {
+                warn!(error = %replace_err, "Current apps could not be placed back after a failed restore");
+            }
+            return Err(format!("Restore rejected: {}", e));
+        }
+
+        // Derived from the apps, so snapshots taken before apps_halted existed restore correctly
+        let apps_halted = count_halted(&snapshot.apps);
+        *apps = snapshot.apps;
+        *metrics = OrchestratorMetrics { apps_halted, ..snapshot.metrics };
+        self.publish_metrics(&metrics);
+        info!(apps_managed = metrics.apps_managed, "Orchestrator state restored from snapshot");
+        Ok(())
+    }
+}
+
+// Held by deploy_and_finalize while it waits; dropped unjoined, it rolls the deploy back once the task lands
+struct RollbackOnDrop {
+    orchestrator: DeveloperAppOrchestrator,
+    task: Option<JoinHandle<Result<DeployResult, SuperPiError>>>,
+}
+
+impl Drop for RollbackOnDrop {
+    fn drop(&mut self) {
+        let Some(task) = self.task.take() else {
+            return;
+        };
+        let orchestrator = self.orchestrator.clone();
+        // No runtime (e.g., shutdown): nothing can complete the deploy either
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            runtime.spawn(async move {
+                if let Ok(Ok(deployed)) = task.await {
+                    orchestrator.rollback_app(&deployed.app_id).await;
+                }
+            });
+        }
+    }
 }
 
 // Example Usage (integrate into main app loop)
@@ -158,7 +588,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Deploy sample apps (scale to millions)
     for i in 0..1000 {
         let code = format!("Stable PI app code {}", i);
-        orchestrator.deploy_app(format!("dev_{}", i), code).await?;
+        let deployed = orchestrator.deploy_app(format!("dev_{}", i), code).await?;
+        orchestrator.finalize_build(&deployed.app_id).await?;
     }
 
     // Run apps
@@ -171,3 +602,4 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
@@ -1,26 +1,191 @@
 // src/hyper_core/rust/src/ecosystem_isolation_shield.rs
 // Ecosystem Isolation Shield for Pi Ecosystem Super App
 // Autonomously rejects and isolates volatile external technologies in real-time.
-// Dependencies: Add to Cargo.toml: tokio = "1.0", regex = "1.5" (for pattern matching), sha2 = "0.10" (for sealing), serde = { version = "1.0", features = ["derive"] }
+// Dependencies: Add to Cargo.toml: tokio = "1.0", chrono = "0.4" (for event timestamps), tokio-util = "0.7" (for cancellation), regex = "1.5" (for pattern matching), sha2 = "0.10" (for sealing), uuid = "1.0" (for event ids), serde = { version = "1.0", features = ["derive"] }
 // Integrate with previous modules: pub mod hyper_ai_core; pub mod pi_transaction_engine; pub mod pi_mainnet_accelerator;
 
-use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
 use tokio::sync::{mpsc, Mutex};
+use tokio::task::JoinSet;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
-use sha2::{Digest, Sha256};
+use sha2::{Digest, Sha256, Sha512};
+use crate::clock::{Clock, SystemClock};
 use crate::hyper_ai_core::AutonomousHyperAI;
 use crate::pi_transaction_engine::PITransactionEngine;
 use crate::pi_mainnet_accelerator::PiMainnetAccelerator;
+use crate::telemetry::{info, warn};
 
 // Isolation Event Struct
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct IsolationEvent {
     pub id: String,
-    pub data_type: String, // e.g., "finance", "blockchain"
+    pub data_type: String, // Source category, e.g., "chat", "tx_memo", "app_code"
     pub volatility_score: f64,
     pub quarantined: bool,
     pub timestamp: u64,
+    #[serde(default)]
+    pub sequence: Option<u64>, // Assigned by submit_stream; None for data passed to process_stream directly
+    #[serde(default)]
+    pub data_hash: String, // SHA-256 hex digest of the isolated data (the data itself is not retained)
+}
+
+// Outcome of screening data that passed the AI filter
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum IsolationVerdict {
+    Sealed { payload: String, hash: String }, // payload is the full sealed output; hash its SHA-256 hex digest
+    Isolated { score: f64, event_id: String }, // event_id names the recorded IsolationEvent
+}
+
+impl IsolationVerdict {
+    // The string form returned by process_stream
+    pub fn into_result(self) -> Result<String, String> {
+        match self {
+            IsolationVerdict::Sealed { payload, .. } => Ok(payload),
+            IsolationVerdict::Isolated { score, .. } => Err(format!("Data isolated: volatility score {:.2}", score)),
+        }
+    }
+}
+
+const DEFAULT_DATA_TYPE: &str = "volatile_external"; // Category recorded for untagged data
+const DEFAULT_MAX_INPUT_BYTES: usize = 1024 * 1024; // 1 MiB; larger inputs are rejected before any scoring
+
+// Distribution of combined scores seen by process_stream (isolated and passed)
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct VolatilityStats {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub count: u64,
+}
+
+// Running totals behind VolatilityStats
+#[derive(Debug, Default)]
+struct ScoreTotals {
+    min: f64,
+    max: f64,
+    sum: f64,
+    count: u64,
+}
+
+impl ScoreTotals {
+    fn record(&mut self, score: f64) {
+        if self.count == 0 {
+            self.min = score;
+            self.max = score;
+        } else {
+            self.min = self.min.min(score);
+            self.max = self.max.max(score);
+        }
+        self.sum += score;
+        self.count += 1;
+    }
+}
+
+// Hash algorithm used to seal PI-internal data
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SealAlgorithm {
+    Sha256,
+    Sha512,
+}
+
+impl SealAlgorithm {
+    // Label embedded in sealed output
+    pub fn label(&self) -> &'static str {
+        match self {
+            SealAlgorithm::Sha256 => "sha256",
+            SealAlgorithm::Sha512 => "sha512",
+        }
+    }
+
+    // Hex digest of data under this algorithm
+    pub fn digest(&self, data: &str) -> String {
+        match self {
+            SealAlgorithm::Sha256 => format!("{:x}", Sha256::digest(data.as_bytes())),
+            SealAlgorithm::Sha512 => format!("{:x}", Sha512::digest(data.as_bytes())),
+        }
+    }
+
+    // Algorithm for a label written by label()
+    pub fn from_label(label: &str) -> Option<Self> {
+        match label {
+            "sha256" => Some(SealAlgorithm::Sha256),
+            "sha512" => Some(SealAlgorithm::Sha512),
+            _ => None,
+        }
+    }
+}
+
+// Structured seal. The wire form is "<algo>:<hash>:<payload byte length>:<payload>", so any payload
+// (including ones containing ':' or the legacy " | Hash: " delimiter) round-trips exactly.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SealedData {
+    pub payload: String,
+    pub hash: String, // Hex digest of payload
+    pub algo: String, // SealAlgorithm label
+}
+
+impl SealedData {
+    pub fn to_wire(&self) -> String {
+        format!("{}:{}:{}:{}", self.algo, self.hash, self.payload.len(), self.payload)
+    }
+
+    pub fn from_wire(wire: &str) -> Result<Self, String> {
+        let mut fields = wire.splitn(4, ':');
+        let (Some(algo), Some(hash), Some(length), Some(payload)) = (fields.next(), fields.next(), fields.next(), fields.next()) else {
+            return Err("Malformed seal: expected algo:hash:length:payload".to_string());
+        };
+        let length: usize = length.parse().map_err(|_| format!("Malformed seal: invalid payload length '{}'", length))?;
+        if payload.len() != length {
+            return Err(format!("Malformed seal: payload is {} bytes, expected {}", payload.len(), length));
+        }
+        Ok(Self { payload: payload.to_string(), hash: hash.to_string(), algo: algo.to_string() })
+    }
+
+    // True when hash is the payload's digest under a known algorithm
+    pub fn is_intact(&self) -> bool {
+        SealAlgorithm::from_label(&self.algo).is_some_and(|algo| algo.digest(&self.payload) == self.hash)
+    }
+}
+
+const DEFAULT_SEAL_PREFIX: &str = "Sealed PI Data: ";
+
+// Shield Configuration
+#[derive(Clone, Debug)]
+pub struct ShieldConfig {
+    pub isolation_threshold: f64, // Scores above this are isolated
+    pub volatility_patterns: Vec<(String, f64)>, // (regex, weight per match occurrence)
+    pub allowlist_patterns: Vec<String>, // Known-safe PI phrases
+    pub allowlist_weight: f64, // Negative weight applied per allowlist match occurrence
+    pub ai_score_weight: f64, // Alpha in [0, 1]: how much the AI model's score counts as evidence (0.0 = patterns only)
+    pub max_input_bytes: usize, // Inputs longer than this are rejected unscanned
+    pub quarantine_ttl: Option<Duration>, // Events older than this are dropped by purge_expired (None = kept forever)
+    pub seal_prefix: String, // Leads every sealed string
+    pub legacy_seal_format: bool, // Seal as "<prefix><data> | Hash: <algo>:<hex>" instead of the SealedData wire form
+}
+
+impl Default for ShieldConfig {
+    fn default() -> Self {
+        Self {
+            isolation_threshold: 0.3,
+            volatility_patterns: vec![
+                (r"(?i)bitcoin|ethereum|crypto|token|blockchain|finance".to_string(), 0.5), // Volatile keywords
+            ],
+            allowlist_patterns: vec![
+                r"(?i)\bpi (community|network|ecosystem) finance\b".to_string(), // Legitimate Pi discussion
+            ],
+            allowlist_weight: -0.5,
+            ai_score_weight: 0.5, // An AI score the filter lets through (<= 0.5) can't isolate alone, only reinforce patterns
+            max_input_bytes: DEFAULT_MAX_INPUT_BYTES,
+            quarantine_ttl: None,
+            seal_prefix: DEFAULT_SEAL_PREFIX.to_string(),
+            legacy_seal_format: false,
+        }
+    }
 }
 
 // Shield Core
@@ -29,9 +194,21 @@ pub struct EcosystemIsolationShield {
     tx_engine: Arc<PITransactionEngine>,
     mainnet_accelerator: Arc<PiMainnetAccelerator>,
     events: Arc<Mutex<Vec<IsolationEvent>>>,
-    stream_sender: mpsc::UnboundedSender<String>,
-    stream_receiver: Arc<Mutex<mpsc::UnboundedReceiver<String>>>,
-    volatility_patterns: Vec<Regex>, // Pre-compiled patterns for volatile tech
+    stream_sender: mpsc::UnboundedSender<(u64, String)>,
+    stream_receiver: Arc<Mutex<mpsc::UnboundedReceiver<(u64, String)>>>,
+    next_sequence: AtomicU64, // Submission order across all producers
+    processor_shutdown: CancellationToken, // Signals run_stream_processor to return
+    volatility_patterns: Vec<(Regex, f64)>, // Pre-compiled patterns for volatile tech, with per-match weight
+    allowlist_patterns: RwLock<Vec<Regex>>, // Pre-compiled known-safe PI phrases; extended via add_allowlist_pattern
+    allowlist_weight: f64,
+    ai_score_weight: f64,
+    max_input_bytes: usize,
+    quarantine_ttl: Option<Duration>,
+    seal_prefix: String,
+    legacy_seal_format: bool,
+    isolation_threshold: f64,
+    score_totals: Arc<Mutex<ScoreTotals>>,
+    clock: Arc<dyn Clock>, // Event timestamps and time windows (SystemClock unless replaced via with_clock)
 }
 
 impl EcosystemIsolationShield {
@@ -40,92 +217,399 @@ impl EcosystemIsolationShield {
         tx_engine: Arc<PITransactionEngine>,
         mainnet_accelerator: Arc<PiMainnetAccelerator>,
     ) -> Self {
+        Self::new_with_config(ai_core, tx_engine, mainnet_accelerator, ShieldConfig::default())
+            .expect("Default shield patterns are valid")
+    }
+
+    pub fn new_with_config(
+        ai_core: Arc<AutonomousHyperAI>,
+        tx_engine: Arc<PITransactionEngine>,
+        mainnet_accelerator: Arc<PiMainnetAccelerator>,
+        config: ShieldConfig,
+    ) -> Result<Self, String> {
+        let mut patterns = Vec::with_capacity(config.volatility_patterns.len());
+        for (pattern, weight) in &config.volatility_patterns {
+            let regex = Regex::new(pattern).map_err(|e| format!("Invalid volatility pattern '{}': {}", pattern, e))?;
+            patterns.push((regex, *weight));
+        }
+
+        if config.allowlist_weight > 0.0 {
+            return Err("Invalid allowlist weight: must not be positive".to_string());
+        }
+        if !(0.0..=1.0).contains(&config.ai_score_weight) {
+            return Err("Invalid AI score weight: must be between 0.0 and 1.0".to_string());
+        }
+        if config.max_input_bytes == 0 {
+            return Err("Invalid max input bytes: must be positive".to_string());
+        }
+        if config.quarantine_ttl.is_some_and(|ttl| ttl.is_zero()) {
+            return Err("Invalid quarantine TTL: must be positive".to_string());
+        }
+        let mut allowlist = Vec::with_capacity(config.allowlist_patterns.len());
+        for pattern in &config.allowlist_patterns {
+            allowlist.push(Regex::new(pattern).map_err(|e| format!("Invalid allowlist pattern '{}': {}", pattern, e))?);
+        }
+
         let (tx, rx) = mpsc::unbounded_channel();
-        let patterns = vec![
-            Regex::new(r"(?i)bitcoin|ethereum|crypto|token|blockchain|finance").unwrap(), // Volatile keywords
-        ];
-        Self {
+        Ok(Self {
             ai_core,
             tx_engine,
             mainnet_accelerator,
             events: Arc::new(Mutex::new(Vec::new())),
             stream_sender: tx,
             stream_receiver: Arc::new(Mutex::new(rx)),
+            next_sequence: AtomicU64::new(0),
+            processor_shutdown: CancellationToken::new(),
             volatility_patterns: patterns,
-        }
+            allowlist_patterns: RwLock::new(allowlist),
+            allowlist_weight: config.allowlist_weight,
+            ai_score_weight: config.ai_score_weight,
+            max_input_bytes: config.max_input_bytes,
+            quarantine_ttl: config.quarantine_ttl,
+            seal_prefix: config.seal_prefix,
+            legacy_seal_format: config.legacy_seal_format,
+            isolation_threshold: config.isolation_threshold,
+            score_totals: Arc::new(Mutex::new(ScoreTotals::default())),
+            clock: Arc::new(SystemClock),
+        })
+    }
+
+    // Replace the time source (e.g., a MockClock for deterministic event timestamps)
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
     }
 
-    // Process real-time data stream for isolation
+    // Process real-time data stream for isolation (untagged data is recorded as "volatile_external")
     pub async fn process_stream(&self, data: String) -> Result<String, String> {
-        // AI Filter first
-        self.ai_core.filter_io(&data).await?;
-
-        // Check for volatility patterns
-        let mut score = 0.0;
-        for pattern in &self.volatility_patterns {
-            if pattern.is_match(&data) {
-                score += 0.5; // Increment score for matches
-            }
-        }
+        self.process_stream_typed(data, DEFAULT_DATA_TYPE).await
+    }
+
+    // Process data tagged with its source category; isolations record the category on the event
+    #[cfg_attr(feature = "tracing-logs", tracing::instrument(skip_all, fields(data_len = data.len(), category = %category)))]
+    pub async fn process_stream_typed(&self, data: String, category: &str) -> Result<String, String> {
+        self.isolate(data, category, None).await?.into_result()
+    }
+
+    // Process data and return a typed verdict; Err only when the AI filter rejects the input
+    pub async fn process_stream_verdict(&self, data: String) -> Result<IsolationVerdict, String> {
+        self.isolate(data, DEFAULT_DATA_TYPE, None).await
+    }
+
+    async fn isolate(&self, data: String, category: &str, sequence: Option<u64>) -> Result<IsolationVerdict, String> {
+        // Oversized input never reaches the AI model or the regexes
+        self.check_input_size(&data)?;
+
+        // AI Filter first; its score is reused for the isolation decision
+        let ai_score = self.ai_core.filter_io_scored(&data).await.map_err(String::from)?;
+
+        // Isolation is decided on the AI and pattern signals together
+        let score = self.blend_scores(self.volatility_score(&data), ai_score);
+        self.score_totals.lock().await.record(score);
 
-        if score > 0.3 {
+        if score > self.isolation_threshold {
             // Isolate and quarantine
             let event = IsolationEvent {
-                id: format!("event_{}", chrono::Utc::now().timestamp()),
-                data_type: "volatile_external".to_string(),
+                id: format!("event_{}", Uuid::new_v4()),
+                data_type: category.to_string(),
                 volatility_score: score,
                 quarantined: true,
-                timestamp: chrono::Utc::now().timestamp() as u64,
+                timestamp: self.clock.now().timestamp() as u64,
+                sequence,
+                data_hash: SealAlgorithm::Sha256.digest(&data),
             };
+            warn!(event_id = %event.id, category = %category, volatility_score = score, "Data isolated");
+            let event_id = event.id.clone();
             self.events.lock().await.push(event);
-            Err(format!("Data isolated: volatility score {:.2}", score))
+            Ok(IsolationVerdict::Isolated { score, event_id })
         } else {
             // Seal and allow PI-internal data
-            let sealed = self.seal_data(&data);
-            Ok(sealed)
+            Ok(IsolationVerdict::Sealed {
+                payload: self.seal_data(&data),
+                hash: SealAlgorithm::Sha256.digest(&data),
+            })
         }
     }
 
+    // Re-check previously isolated data against the current patterns and threshold (no new event is recorded).
+    // If it now passes, the event is released (quarantined: false) and the sealed data returned.
+    // The caller supplies the original data; events keep only its digest, and other data is rejected.
+    pub async fn reevaluate_event(&self, event_id: &str, data: String) -> Result<String, String> {
+        let data_hash = self
+            .events
+            .lock()
+            .await
+            .iter()
+            .find(|e| e.id == event_id)
+            .map(|e| e.data_hash.clone())
+            .ok_or_else(|| format!("Isolation event not found: {}", event_id))?;
+        self.check_input_size(&data)?;
+        if SealAlgorithm::Sha256.digest(&data) != data_hash {
+            warn!(event_id = %event_id, "Re-evaluation data does not match the isolated data");
+            return Err(format!("Data does not match isolation event: {}", event_id));
+        }
+
+        let ai_score = self.ai_core.filter_io_scored(&data).await.map_err(String::from)?;
+        let score = self.blend_scores(self.volatility_score(&data), ai_score);
+        if score > self.isolation_threshold {
+            return Err(format!("Data still isolated: volatility score {:.2}", score));
+        }
+
+        if let Some(event) = self.events.lock().await.iter_mut().find(|e| e.id == event_id) {
+            event.quarantined = false;
+        }
+        info!(event_id = %event_id, volatility_score = score, "Isolated data released on re-evaluation");
+        Ok(self.seal_data(&data))
+    }
+
+    fn check_input_size(&self, data: &str) -> Result<(), String> {
+        if data.len() > self.max_input_bytes {
+            warn!(input_bytes = data.len(), max_input_bytes = self.max_input_bytes, "Oversized input rejected");
+            return Err("input too large".to_string());
+        }
+        Ok(())
+    }
+
+    // Trust an additional known-safe PI phrase from now on (e.g., before re-evaluating isolated data)
+    pub fn add_allowlist_pattern(&self, pattern: &str) -> Result<(), String> {
+        let regex = Regex::new(pattern).map_err(|e| format!("Invalid allowlist pattern '{}': {}", pattern, e))?;
+        self.allowlist_patterns.write().expect("Allowlist lock poisoned").push(regex);
+        Ok(())
+    }
+
+    // Dry run of the shield's decision: the combined score and whether process_stream would isolate
+    // it. Nothing is recorded (no event, no stats); oversized input is an error as in process_stream.
+    pub fn screen(&self, data: &str) -> Result<(f64, bool), String> {
+        self.check_input_size(data)?;
+        let score = self.combined_score(data);
+        Ok((score, score > self.isolation_threshold))
+    }
+
+    // Score data by weighting every pattern occurrence, capped at 1.0, minus allowlist credit
+    pub fn volatility_score(&self, data: &str) -> f64 {
+        let score: f64 = self
+            .volatility_patterns
+            .iter()
+            .map(|(pattern, weight)| pattern.find_iter(data).count() as f64 * weight)
+            .sum();
+        let allowance: f64 = self
+            .allowlist_patterns
+            .read()
+            .expect("Allowlist lock poisoned")
+            .iter()
+            .map(|pattern| pattern.find_iter(data).count() as f64 * self.allowlist_weight)
+            .sum();
+        (score.min(1.0) + allowance).clamp(0.0, 1.0)
+    }
+
+    // Noisy-OR of the two signals: 1 - (1 - alpha * AI model score) * (1 - pattern score), alpha = ai_score_weight.
+    // Independent evidence reinforces, so two signals below the threshold can still cross it together.
+    pub fn combined_score(&self, data: &str) -> f64 {
+        self.blend_scores(self.volatility_score(data), self.ai_core.volatility(data))
+    }
+
+    fn blend_scores(&self, pattern_score: f64, ai_score: f64) -> f64 {
+        let ai_evidence = (self.ai_score_weight * ai_score).clamp(0.0, 1.0);
+        (1.0 - (1.0 - ai_evidence) * (1.0 - pattern_score)).clamp(0.0, 1.0)
+    }
+
     // Cryptographically seal PI-internal data
-    fn seal_data(&self, data: &str) -> String {
-        let mut hasher = Sha256::new();
-        hasher.update(data);
-        let hash = format!("{:x}", hasher.finalize());
-        format!("Sealed PI Data: {} | Hash: {}", data, hash)
-    }
-
-    // Async stream processor for high-volume handling
-    pub async fn run_stream_processor(&self) {
-        let mut rx = self.stream_receiver.lock().await;
-        while let Some(data) = rx.recv().await {
-            match self.process_stream(data.clone()).await {
-                Ok(sealed) => println!("Processed and Sealed: {}", sealed),
-                Err(e) => println!("Isolated: {}", e),
+    pub fn seal_data(&self, data: &str) -> String {
+        self.seal_data_with(data, SealAlgorithm::Sha256)
+    }
+
+    // Seal with a chosen hash algorithm; the output names the algorithm (wire form, or "Hash: sha512:<hex>" when legacy)
+    pub fn seal_data_with(&self, data: &str, algo: SealAlgorithm) -> String {
+        let sealed = self.seal(data, algo);
+        if self.legacy_seal_format {
+            format!("{}{} | Hash: {}:{}", self.seal_prefix, sealed.payload, sealed.algo, sealed.hash)
+        } else {
+            format!("{}{}", self.seal_prefix, sealed.to_wire())
+        }
+    }
+
+    // Structured seal of data
+    pub fn seal(&self, data: &str, algo: SealAlgorithm) -> SealedData {
+        SealedData { payload: data.to_string(), hash: algo.digest(data), algo: algo.label().to_string() }
+    }
+
+    // Parse a sealed string in either format (wire form first, then legacy) without checking its hash
+    pub fn unseal(&self, sealed: &str) -> Result<SealedData, String> {
+        let body = sealed
+            .strip_prefix(self.seal_prefix.as_str())
+            .ok_or_else(|| "Not a seal: missing prefix".to_string())?;
+        SealedData::from_wire(body).or_else(|e| Self::parse_legacy_seal(body).ok_or(e))
+    }
+
+    // "<data> | Hash: [<algo>:]<hex>"; unlabeled hashes are SHA-256
+    fn parse_legacy_seal(body: &str) -> Option<SealedData> {
+        let (data, hash) = body.rsplit_once(" | Hash: ")?;
+        let (algo, hash) = hash.split_once(':').unwrap_or(("sha256", hash));
+        Some(SealedData { payload: data.to_string(), hash: hash.to_string(), algo: algo.to_string() })
+    }
+
+    // Verify a sealed string (either format) by re-hashing its embedded data
+    pub fn verify_seal(&self, sealed: &str) -> bool {
+        let Some(body) = sealed.strip_prefix(self.seal_prefix.as_str()) else {
+            return false;
+        };
+        SealedData::from_wire(body).is_ok_and(|s| s.is_intact()) || Self::parse_legacy_seal(body).is_some_and(|s| s.is_intact())
+    }
+
+    // Queue data for the background stream processor; returns the sequence number recorded on any resulting event
+    pub fn submit_stream(&self, data: String) -> Result<u64, String> {
+        let sequence = self.next_sequence.fetch_add(1, Ordering::Relaxed);
+        self.stream_sender.send((sequence, data)).map_err(|e| format!("Stream queue error: {}", e))?;
+        Ok(sequence)
+    }
+
+    // Async stream processor for high-volume handling (returns once stop_processor is called or shutdown is cancelled)
+    pub async fn run_stream_processor(&self, shutdown: CancellationToken) {
+        while let Some((sequence, data)) = self.next_stream_item(&shutdown).await {
+            match self.isolate(data, DEFAULT_DATA_TYPE, Some(sequence)).await.and_then(IsolationVerdict::into_result) {
+                Ok(sealed) => info!(sequence, sealed_len = sealed.len(), "Processed and sealed stream data"),
+                Err(e) => info!(sequence, reason = %e, "Stream data rejected"),
             }
         }
+        info!("Stream processor stopped");
     }
 
-    // Bulk isolation for ecosystem-wide scans
-    pub async fn bulk_isolate(&self, data_batch: Vec<String>) -> Vec<Result<String, String>> {
-        // Parallel processing for scalability
-        let results: Vec<Result<String, String>> = data_batch
-            .into_iter()
-            .map(|data| async move { self.process_stream(data).await })
-            .collect::<Vec<_>>()
-            .into_iter()
-            .map(|fut| tokio::spawn(fut))
-            .collect::<Vec<_>>()
+    // Run `workers` stream processors (at least one) sharing the channel; each item is taken by exactly one
+    // worker, so events keep their sequence but may be recorded out of submission order. Returns once all stop.
+    pub async fn run_stream_processors(self: &Arc<Self>, workers: usize, shutdown: CancellationToken) {
+        let mut tasks = JoinSet::new();
+        for _ in 0..workers.max(1) {
+            let shield = self.clone();
+            let shutdown = shutdown.clone();
+            tasks.spawn(async move { shield.run_stream_processor(shutdown).await });
+        }
+        while let Some(joined) = tasks.join_next().await {
+            if let Err(e) = joined {
+                warn!(error = %e, "Stream processor task failed");
+            }
+        }
+    }
+
+    // Next queued item, or None once the channel closes or a stop signal fires. The receiver is locked only
+    // while waiting for one item, never while it is processed, so parallel workers don't serialize.
+    async fn next_stream_item(&self, shutdown: &CancellationToken) -> Option<(u64, String)> {
+        tokio::select! {
+            // Biased so items already in the channel are handled before the stop signal
+            biased;
+            received = async { self.stream_receiver.lock().await.recv().await } => received,
+            _ = self.processor_shutdown.cancelled() => None,
+            _ = shutdown.cancelled() => None,
+        }
+    }
+
+    // Signal the running stream processor(s) to return
+    pub fn stop_processor(&self) {
+        self.processor_shutdown.cancel();
+    }
+
+    // Bulk isolation for ecosystem-wide scans; results[i] is always the outcome for data_batch[i]
+    pub async fn bulk_isolate(self: &Arc<Self>, data_batch: Vec<String>) -> Vec<Result<String, String>> {
+        // Parallel processing for scalability; tasks finish in any order, so each carries its batch index
+        let mut tasks = JoinSet::new();
+        for (index, data) in data_batch.into_iter().enumerate() {
+            let shield = self.clone();
+            tasks.spawn(async move { (index, shield.process_stream(data).await) });
+        }
+
+        let mut slots: Vec<Option<Result<String, String>>> = vec![None; tasks.len()];
+        while let Some(joined) = tasks.join_next().await {
+            match joined {
+                Ok((index, result)) => slots[index] = Some(result),
+                Err(e) => warn!(error = %e, "Bulk isolation task failed"),
+            }
+        }
+        // A task that panicked never reported back; its slot stays empty
+        slots
             .into_iter()
-            .map(|handle| handle.expect("Task failed"))
-            .collect();
+            .map(|slot| slot.unwrap_or_else(|| Err("Isolation task failed".to_string())))
+            .collect()
+    }
+
+    // Count isolation events recorded within the last window_secs seconds
+    pub async fn recent_event_count(&self, window_secs: u64) -> usize {
+        let cutoff = (self.clock.now().timestamp() as u64).saturating_sub(window_secs);
+        self.events.lock().await.iter().filter(|e| e.timestamp >= cutoff).count()
+    }
 
-        results
+    // Min/max/mean of every score seen by process_stream
+    pub async fn volatility_stats(&self) -> VolatilityStats {
+        let totals = self.score_totals.lock().await;
+        VolatilityStats {
+            min: totals.min,
+            max: totals.max,
+            mean: if totals.count == 0 { 0.0 } else { totals.sum / totals.count as f64 },
+            count: totals.count,
+        }
     }
 
     // Get isolation events
     pub async fn get_events(&self) -> Vec<IsolationEvent> {
         self.events.lock().await.clone()
     }
+
+    // Drop all recorded isolation events (e.g., between tests sharing an Arc)
+    pub async fn clear_events(&self) {
+        self.events.lock().await.clear();
+    }
+
+    // Drop events older than the configured quarantine TTL; returns how many were purged
+    pub async fn purge_expired(&self) -> usize {
+        let Some(ttl) = self.quarantine_ttl else {
+            return 0;
+        };
+        let cutoff = (self.clock.now().timestamp() as u64).saturating_sub(ttl.as_secs());
+        let mut events = self.events.lock().await;
+        let before = events.len();
+        events.retain(|e| e.timestamp >= cutoff);
+        let purged = before - events.len();
+        if purged > 0 {
+            info!(purged, remaining = events.len(), "Expired isolation events purged");
+        }
+        purged
+    }
+
+    // Get isolation events recorded for one source category
+    pub async fn events_by_type(&self, category: &str) -> Vec<IsolationEvent> {
+        self.events.lock().await.iter().filter(|e| e.data_type == category).cloned().collect()
+    }
+
+    // Export isolation events as CSV (header row plus one row per event) for spreadsheet analysis
+    pub async fn export_events_csv(&self) -> String {
+        let mut csv = String::from("id,data_type,volatility_score,quarantined,timestamp\n");
+        for event in self.events.lock().await.iter() {
+            csv.push_str(&format!(
+                "{},{},{},{},{}\n",
+                csv_field(&event.id),
+                csv_field(&event.data_type),
+                event.volatility_score,
+                event.quarantined,
+                event.timestamp
+            ));
+        }
+        csv
+    }
+
+    // Get one page of isolation events and the total count
+    pub async fn get_events_page(&self, offset: usize, limit: usize) -> (Vec<IsolationEvent>, usize) {
+        let events = self.events.lock().await;
+        let start = offset.min(events.len());
+        let end = start.saturating_add(limit).min(events.len());
+        (events[start..end].to_vec(), events.len())
+    }
+}
+
+// Quote a CSV field when it contains a delimiter, quote, or line break (embedded quotes are doubled)
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
 }
 
 // Example Usage (integrate into main app loop)
@@ -136,15 +620,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mainnet_accelerator = Arc::new(PiMainnetAccelerator::new(ai_core.clone(), tx_engine.clone()));
     let shield = EcosystemIsolationShield::new(ai_core.clone(), tx_engine.clone(), mainnet_accelerator.clone());
 
+    let shield = Arc::new(shield);
+
     // Start stream processor
+    let processor = shield.clone();
     tokio::spawn(async move {
-        shield.run_stream_processor().await;
+        processor.run_stream_processor(CancellationToken::new()).await;
     });
 
     // Send sample data streams
-    shield.stream_sender.send("Stable Pi transaction data".to_string())?;
-    shield.stream_sender.send("Volatile crypto mention".to_string())?;
-    shield.stream_sender.send("External finance update".to_string())?;
+    shield.submit_stream("Stable Pi transaction data".to_string())?;
+    shield.submit_stream("Volatile crypto mention".to_string())?;
+    shield.submit_stream("External finance update".to_string())?;
 
     // Simulate delay and check events
     tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
@@ -3,11 +3,17 @@
 // Manages PI-exclusive configuration and holographic documentation.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String, Symbol, Vec, Map, log};
+
+// Matches PI_STABLE_VALUE in the Rust stablecoin manager and transaction engine
+const PI_STABLE_VALUE: i64 = 314159;
+// Largest stable value whose "pi_stable_value_" display symbol fits Soroban's 32-char limit
+const MAX_PI_STABLE_VALUE: i64 = 9_999_999_999_999_999;
 
 #[contract]
 pub struct EcosystemReadmeConfig;
 
+#[contracttype]
 #[derive(Clone)]
 pub struct EcosystemConfig {
     pub pi_stable_value: i64,
@@ -18,29 +24,35 @@ pub struct EcosystemConfig {
 
 #[contractimpl]
 impl EcosystemReadmeConfig {
-    /// Initialize the Config
-    pub fn init(env: Env) -> EcosystemReadmeConfig {
+    /// Initialize the Config with the admin allowed to update it
+    pub fn init(env: Env, admin: Address) -> EcosystemReadmeConfig {
+        crate::access_control::set_admin(&env, &admin);
         log!(&env, "Ecosystem README Config Initialized");
         EcosystemReadmeConfig
     }
 
     /// Generate dynamic README
-    pub fn generate_readme(env: Env) -> Vec<Symbol> {
+    pub fn generate_readme(env: Env) -> Vec<String> {
         let config = Self::get_current_config(env.clone());
         let readme = Vec::from_array(&env, [
-            Symbol::new(&env, "Pi Ecosystem Super App README"),
-            Symbol::new(&env, &format!("Stable Value: {}", config.pi_stable_value)),
-            Symbol::new(&env, &format!("Max Apps: {}", config.max_apps)),
-            Symbol::new(&env, &format!("Compliance: {}", config.compliance_level)),
-            Symbol::new(&env, &format!("Version: {}", config.readme_version)),
-            Symbol::new(&env, "Holographic Archive: Eternal Supremacy"),
+            String::from_str(&env, "Pi Ecosystem Super App README"),
+            String::from_str(&env, &format!("Stable Value: {}", config.pi_stable_value)),
+            String::from_str(&env, &format!("Max Apps: {}", config.max_apps)),
+            crate::hologram::labelled_symbol(&env, "Compliance", &config.compliance_level),
+            crate::hologram::labelled_symbol(&env, "Version", &config.readme_version),
+            String::from_str(&env, "Holographic Archive: Eternal Supremacy"),
         ]);
         log!(&env, "Dynamic README Generated");
         readme
     }
 
-    /// Update config (PI-exclusive)
+    /// Update config (PI-exclusive, admin only); the stored values are what get_current_config returns
     pub fn update_config(env: Env, new_config: EcosystemConfig) -> Result<Symbol, Symbol> {
+        crate::access_control::require_admin(&env);
+        if new_config.pi_stable_value <= 0 || new_config.pi_stable_value > MAX_PI_STABLE_VALUE {
+            return Err(Symbol::new(&env, "config_rejected"));
+        }
+
         // Validate via AI
         let config_symbol = Symbol::new(&env, &format!("config_{}", new_config.pi_stable_value));
         let filtered = crate::ahi_ai_core::AhiAiCore::filter_io(env.clone(), config_symbol.clone())?;
@@ -48,6 +60,7 @@ impl EcosystemReadmeConfig {
             return Err(Symbol::new(&env, "config_rejected"));
         }
         log!(&env, "Config Updated: PI Stable Value {}", new_config.pi_stable_value);
+        env.storage().instance().set(&Symbol::new(&env, "config"), &new_config);
         Ok(Symbol::new(&env, "updated"))
     }
 
@@ -60,11 +73,15 @@ impl EcosystemReadmeConfig {
         } else {
             Symbol::new(&env, "low")
         };
-        EcosystemConfig {
-            pi_stable_value: 314159,
-            max_apps: 1000000000, // Billions
-            compliance_level: compliance,
-            readme_version: Symbol::new(&env, "v_eternal_supremacy"),
+        let stored: Option<EcosystemConfig> = env.storage().instance().get(&Symbol::new(&env, "config"));
+        match stored {
+            Some(config) => EcosystemConfig { compliance_level: compliance, ..config }, // Compliance always comes from the Monitor
+            None => EcosystemConfig {
+                pi_stable_value: PI_STABLE_VALUE,
+                max_apps: 1000000000, // Billions
+                compliance_level: compliance,
+                readme_version: Symbol::new(&env, "v_eternal_supremacy"),
+            },
         }
     }
 
@@ -0,0 +1,32 @@
+// src/hyper_core/rust/src/error.rs
+// Typed errors for the async Super App modules.
+// Each variant carries the human-readable reason; Display renders just that reason so
+// String-based callers (e.g., controller commands) see the same messages as before.
+// Integrate with the async modules: use crate::error::SuperPiError;
+
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SuperPiError {
+    Rejected(String),    // Input refused by the AI filter or the isolation shield
+    Unavailable(String), // Transient: mainnet nodes missing or at capacity, or the AI model unable to score
+    TaskFailed(String),  // A spawned background task panicked or was cancelled
+}
+
+impl fmt::Display for SuperPiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SuperPiError::Rejected(reason) | SuperPiError::Unavailable(reason) | SuperPiError::TaskFailed(reason) => {
+                f.write_str(reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SuperPiError {}
+
+impl From<SuperPiError> for String {
+    fn from(error: SuperPiError) -> Self {
+        error.to_string()
+    }
+}
@@ -3,7 +3,7 @@
 // Provides eternal quantum security against all threats.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, Address, Env, String, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct EternalQuantumSecurityAntiQuantumThreat;
@@ -19,8 +19,9 @@ pub struct QuantumDefense {
 
 #[contractimpl]
 impl EternalQuantumSecurityAntiQuantumThreat {
-    /// Initialize the Quantum Security
-    pub fn init(env: Env) -> EternalQuantumSecurityAntiQuantumThreat {
+    /// Initialize the Quantum Security with the admin allowed to update security rules
+    pub fn init(env: Env, admin: Address) -> EternalQuantumSecurityAntiQuantumThreat {
+        crate::access_control::set_admin(&env, &admin);
         log!(&env, "Eternal Quantum Security Anti-Quantum Threat Initialized");
         EternalQuantumSecurityAntiQuantumThreat
     }
@@ -76,11 +77,16 @@ impl EternalQuantumSecurityAntiQuantumThreat {
         status
     }
 
-    /// Update security rules
+    /// Update security rules (admin only)
     pub fn update_security_rules(env: Env, new_rule: Symbol) -> Result<Symbol, Symbol> {
-        // Validate via Archive
-        let archive_status = crate::ultimate_ecosystem_documentation_holographic_archive::UltimateEcosystemDocumentationHolographicArchive::get_archive_status(env.clone());
-        if archive_status.get(Symbol::new(&env, "holographic_integrity")).unwrap_or(0) == 100 {
+        crate::access_control::require_admin(&env);
+
+        // Validate via Archive and Test Suite
+        let checks = Vec::from_array(&env, [
+            (Symbol::new(&env, "holographic_archive"), Symbol::new(&env, "holographic_integrity")),
+            (Symbol::new(&env, "test_suite_validation"), Symbol::new(&env, "validation_rate")),
+        ]);
+        if crate::rule_chain::validate_rule_chain(env.clone(), checks) {
             log!(&env, "Security Rules Updated: {}", new_rule);
             Ok(Symbol::new(&env, "updated"))
         } else {
@@ -88,13 +94,18 @@ impl EternalQuantumSecurityAntiQuantumThreat {
         }
     }
 
+    /// Transfer admin rights (current admin only)
+    pub fn transfer_admin(env: Env, new_admin: Address) {
+        crate::access_control::transfer_admin(&env, new_admin);
+    }
+
     /// Render security hologram
-    pub fn render_security_hologram(env: Env, defense: QuantumDefense) -> Vec<Symbol> {
+    pub fn render_security_hologram(env: Env, defense: QuantumDefense) -> Vec<String> {
         let hologram = Vec::from_array(&env, [
-            Symbol::new(&env, "Quantum Security Hologram"),
-            defense.threat_type,
-            Symbol::new(&env, &format!("Defense Applied: {}", defense.defense_applied)),
-            Symbol::new(&env, &format!("Security Level: {}", defense.security_level)),
+            String::from_str(&env, "Quantum Security Hologram"),
+            crate::hologram::symbol_to_string(&env, &defense.threat_type),
+            String::from_str(&env, &format!("Defense Applied: {}", defense.defense_applied)),
+            String::from_str(&env, &format!("Security Level: {}", defense.security_level)),
         ]);
         log!(&env, "Security Hologram Rendered");
         hologram
@@ -3,7 +3,7 @@
 // Synthesizes holographic UI and enforces PI-exclusive interactions.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, Env, String, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct FinalEcosystemSynthesisUiHub;
@@ -30,12 +30,14 @@ impl FinalEcosystemSynthesisUiHub {
         // Aggregate from modules
         let governance_status = crate::ultimate_ai_governance_ethical_overseer::UltimateAiGovernanceEthicalOverseer::get_governance_status(env.clone());
         let oracle_status = crate::global_pi_oracle_compliance_verifier::GlobalPiOracleComplianceVerifier::get_oracle_status(env.clone());
+        let config = crate::ecosystem_readme_config::EcosystemReadmeConfig::get_current_config(env.clone());
         let ethical_score = governance_status.get(Symbol::new(&env, "compliance_rate")).unwrap_or(0);
 
+        // Data points are symbols, so they use symbol-safe characters only
         let data_points = Vec::from_array(&env, [
-            Symbol::new(&env, &format!("Governance Audits: {}", governance_status.get(Symbol::new(&env, "ethical_audits")).unwrap_or(0))),
-            Symbol::new(&env, &format!("Oracle Sources: {}", oracle_status.len())),
-            Symbol::new(&env, "PI Stable Value: 314159"),
+            Symbol::new(&env, &format!("governance_audits_{}", governance_status.get(Symbol::new(&env, "ethical_audits")).unwrap_or(0))),
+            Symbol::new(&env, &format!("oracle_sources_{}", oracle_status.len())),
+            Symbol::new(&env, &format!("pi_stable_value_{}", config.pi_stable_value)),
         ]);
 
         let synthesis = UiSynthesis {
@@ -51,14 +53,16 @@ impl FinalEcosystemSynthesisUiHub {
     }
 
     /// Render holographic UI
-    pub fn render_holographic_ui(env: Env, synthesis: UiSynthesis) -> Vec<Symbol> {
+    pub fn render_holographic_ui(env: Env, synthesis: UiSynthesis) -> Vec<String> {
         // Simulate rendering (in real: generate UI elements)
-        let ui_elements = Vec::from_array(&env, [
-            Symbol::new(&env, "Holographic Dashboard"),
-            synthesis.synthesis_type,
-            Symbol::new(&env, &format!("Ethical Score: {}", synthesis.ethical_score)),
+        let mut ui_elements = Vec::from_array(&env, [
+            String::from_str(&env, "Holographic Dashboard"),
+            crate::hologram::symbol_to_string(&env, &synthesis.synthesis_type),
+            String::from_str(&env, &format!("Ethical Score: {}", synthesis.ethical_score)),
         ]);
-        ui_elements.extend(synthesis.data_points);
+        for data_point in synthesis.data_points.iter() {
+            ui_elements.push_back(crate::hologram::symbol_to_string(&env, &data_point));
+        }
         log!(&env, "Holographic UI Rendered");
         ui_elements
     }
@@ -3,7 +3,7 @@
 // Achieves final supremacy and global domination for Pi mainnet.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, Env, String, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct FinalPiMainnetSupremacyGlobalDomination;
@@ -89,12 +89,12 @@ impl FinalPiMainnetSupremacyGlobalDomination {
     }
 
     /// Render domination hologram
-    pub fn render_domination_hologram(env: Env, event: DominationEvent) -> Vec<Symbol> {
+    pub fn render_domination_hologram(env: Env, event: DominationEvent) -> Vec<String> {
         let hologram = Vec::from_array(&env, [
-            Symbol::new(&env, "Global Domination Hologram"),
-            event.domination_target,
-            Symbol::new(&env, &format!("Supremacy Achieved: {}", event.supremacy_achieved)),
-            Symbol::new(&env, &format!("Domination Score: {}", event.domination_score)),
+            String::from_str(&env, "Global Domination Hologram"),
+            crate::hologram::symbol_to_string(&env, &event.domination_target),
+            String::from_str(&env, &format!("Supremacy Achieved: {}", event.supremacy_achieved)),
+            String::from_str(&env, &format!("Domination Score: {}", event.domination_score)),
         ]);
         log!(&env, "Domination Hologram Rendered");
         hologram
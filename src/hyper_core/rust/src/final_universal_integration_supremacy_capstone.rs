@@ -3,11 +3,12 @@
 // Achieves final universal integration and supremacy for Pi Ecosystem.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct FinalUniversalIntegrationSupremacyCapstone;
 
+#[contracttype]
 #[derive(Clone)]
 pub struct CapstoneIntegration {
     pub id: Symbol,
@@ -19,8 +20,9 @@ pub struct CapstoneIntegration {
 
 #[contractimpl]
 impl FinalUniversalIntegrationSupremacyCapstone {
-    /// Initialize the Capstone
-    pub fn init(env: Env) -> FinalUniversalIntegrationSupremacyCapstone {
+    /// Initialize the Capstone with the admin allowed to set dependency addresses
+    pub fn init(env: Env, admin: Address) -> FinalUniversalIntegrationSupremacyCapstone {
+        crate::access_control::set_admin(&env, &admin);
         log!(&env, "Final Universal Integration Supremacy Capstone Initialized");
         FinalUniversalIntegrationSupremacyCapstone
     }
@@ -39,21 +41,53 @@ impl FinalUniversalIntegrationSupremacyCapstone {
             timestamp: env.ledger().timestamp(),
         };
 
+        // Only the first integration of a module counts; re-integrating replaces the stored record
+        let key = (Symbol::new(&env, "integration"), module.clone());
+        if !env.storage().persistent().has(&key) {
+            let count = Self::get_integration_count(env.clone());
+            env.storage().persistent().set(&Symbol::new(&env, "integration_count"), &(count + 1));
+        }
+        env.storage().persistent().set(&key, &integration);
+
         log!(&env, "Module {} Integrated into Capstone: Supremacy {} Level {}", module, supremacy_achieved, integration_level);
+        env.events().publish(
+            (Symbol::new(&env, "capstone_integrated"), module),
+            (integration.id.clone(), supremacy_achieved, integration_level, integration.timestamp),
+        );
         integration
     }
 
+    /// Get the latest integration stored for a module
+    pub fn get_integration(env: Env, module: Symbol) -> Option<CapstoneIntegration> {
+        env.storage().persistent().get(&(Symbol::new(&env, "integration"), module))
+    }
+
+    /// Number of distinct modules integrated so far
+    pub fn get_integration_count(env: Env) -> i64 {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(&env, "integration_count"))
+            .unwrap_or(0)
+    }
+
     /// Enforce capstone supremacy
     pub fn enforce_capstone_supremacy(env: Env, integration: CapstoneIntegration) -> Symbol {
         if !integration.supremacy_achieved {
             log!(&env, "Capstone Breach Detected: Halting {}", integration.integrated_module);
-            crate::ahi_ai_core::AhiAiCore::enforce_compliance(env.clone());
+            // Falls back to the in-crate AI core when no deployed one is set, so a breach is never left unenforced
+            crate::contract_deps::enforce_compliance(&env);
             Symbol::new(&env, "capstone_enforced")
         } else {
             Symbol::new(&env, "universal_supremacy_active")
         }
     }
 
+    /// Set a dependency contract address, e.g. "ahi_ai_core" (admin only)
+    pub fn set_dependency(env: Env, name: Symbol, address: Address) {
+        crate::access_control::require_admin(&env);
+        crate::contract_deps::set_dependency(&env, name, address);
+    }
+
     /// Run universal capstone (called from lib.rs)
     pub fn run_universal_capstone(env: Env) -> Vec<CapstoneIntegration> {
         let modules = Vec::from_array(&env, [
@@ -93,7 +127,7 @@ impl FinalUniversalIntegrationSupremacyCapstone {
     /// Get capstone status
     pub fn get_capstone_status(env: Env) -> Map<Symbol, i64> {
         let mut status = Map::new(&env);
-        status.set(Symbol::new(&env, "modules_integrated"), 50); // Simulated count
+        status.set(Symbol::new(&env, "modules_integrated"), Self::get_integration_count(env.clone()));
         status.set(Symbol::new(&env, "universal_supremacy"), 100);
         status.set(Symbol::new(&env, "capstone_eternal"), 100);
         status
@@ -112,12 +146,12 @@ impl FinalUniversalIntegrationSupremacyCapstone {
     }
 
     /// Render capstone hologram
-    pub fn render_capstone_hologram(env: Env, integration: CapstoneIntegration) -> Vec<Symbol> {
+    pub fn render_capstone_hologram(env: Env, integration: CapstoneIntegration) -> Vec<String> {
         let hologram = Vec::from_array(&env, [
-            Symbol::new(&env, "Universal Capstone Hologram"),
-            integration.integrated_module,
-            Symbol::new(&env, &format!("Supremacy Achieved: {}", integration.supremacy_achieved)),
-            Symbol::new(&env, &format!("Integration Level: {}", integration.integration_level)),
+            String::from_str(&env, "Universal Capstone Hologram"),
+            crate::hologram::symbol_to_string(&env, &integration.integrated_module),
+            String::from_str(&env, &format!("Supremacy Achieved: {}", integration.supremacy_achieved)),
+            String::from_str(&env, &format!("Integration Level: {}", integration.integration_level)),
         ]);
         log!(&env, "Capstone Hologram Rendered");
         hologram
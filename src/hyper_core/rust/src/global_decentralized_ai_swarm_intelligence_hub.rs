@@ -3,7 +3,10 @@
 // Coordinates global AI swarms for decentralized intelligence in the Pi Ecosystem.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, Address, Env, String, Symbol, Vec, Map, log};
+
+// Registered agents required for swarm consensus unless configured otherwise
+const DEFAULT_SWARM_QUORUM: i64 = 1;
 
 #[contract]
 pub struct GlobalDecentralizedAiSwarmIntelligenceHub;
@@ -20,8 +23,9 @@ pub struct SwarmIntelligence {
 
 #[contractimpl]
 impl GlobalDecentralizedAiSwarmIntelligenceHub {
-    /// Initialize the Swarm Hub
-    pub fn init(env: Env) -> GlobalDecentralizedAiSwarmIntelligenceHub {
+    /// Initialize the Swarm Hub with the admin allowed to manage agents and the quorum
+    pub fn init(env: Env, admin: Address) -> GlobalDecentralizedAiSwarmIntelligenceHub {
+        crate::access_control::set_admin(&env, &admin);
         log!(&env, "Global Decentralized AI Swarm Intelligence Hub Initialized");
         GlobalDecentralizedAiSwarmIntelligenceHub
     }
@@ -31,7 +35,7 @@ impl GlobalDecentralizedAiSwarmIntelligenceHub {
         // Simulate swarm coordination (collective AI decision)
         let consensus_reached = true; // Simulated consensus
         let intelligence_score = 100; // Optimal
-        let swarm_size = 1000000; // Millions of agents
+        let swarm_size = Self::get_agent_count(env.clone());
 
         let intelligence = SwarmIntelligence {
             id: Symbol::new(&env, &format!("swarm_{}", env.ledger().sequence())),
@@ -46,9 +50,57 @@ impl GlobalDecentralizedAiSwarmIntelligenceHub {
         intelligence
     }
 
-    /// Enforce swarm integrity
+    /// Register an AI agent with the swarm (admin only); returns false if it was already registered
+    pub fn register_agent(env: Env, agent_id: Symbol) -> bool {
+        crate::access_control::require_admin(&env);
+        let key = (Symbol::new(&env, "agent"), agent_id.clone());
+        if env.storage().persistent().has(&key) {
+            return false;
+        }
+        env.storage().persistent().set(&key, &true);
+        let count = Self::get_agent_count(env.clone()) + 1;
+        env.storage().instance().set(&Symbol::new(&env, "agent_count"), &count);
+        log!(&env, "Swarm Agent Registered: {} Total {}", agent_id, count);
+        true
+    }
+
+    /// Deregister an AI agent (admin only); returns false if it was not registered
+    pub fn deregister_agent(env: Env, agent_id: Symbol) -> bool {
+        crate::access_control::require_admin(&env);
+        let key = (Symbol::new(&env, "agent"), agent_id.clone());
+        if !env.storage().persistent().has(&key) {
+            return false;
+        }
+        env.storage().persistent().remove(&key);
+        let count = Self::get_agent_count(env.clone()) - 1;
+        env.storage().instance().set(&Symbol::new(&env, "agent_count"), &count);
+        log!(&env, "Swarm Agent Deregistered: {} Total {}", agent_id, count);
+        true
+    }
+
+    /// Number of registered agents
+    pub fn get_agent_count(env: Env) -> i64 {
+        env.storage().instance().get(&Symbol::new(&env, "agent_count")).unwrap_or(0)
+    }
+
+    /// Set the minimum number of registered agents required for consensus (admin only)
+    pub fn set_swarm_quorum(env: Env, quorum: i64) {
+        crate::access_control::require_admin(&env);
+        if quorum < 0 {
+            panic!("swarm quorum must not be negative");
+        }
+        env.storage().instance().set(&Symbol::new(&env, "swarm_quorum"), &quorum);
+    }
+
+    /// Get the swarm quorum
+    pub fn get_swarm_quorum(env: Env) -> i64 {
+        env.storage().instance().get(&Symbol::new(&env, "swarm_quorum")).unwrap_or(DEFAULT_SWARM_QUORUM)
+    }
+
+    /// Enforce swarm integrity (consensus fails while registered agents are below quorum)
     pub fn enforce_swarm_integrity(env: Env, intelligence: SwarmIntelligence) -> Symbol {
-        if !intelligence.consensus_reached {
+        let quorum_met = Self::get_agent_count(env.clone()) >= Self::get_swarm_quorum(env.clone());
+        if !intelligence.consensus_reached || !quorum_met {
             log!(&env, "Swarm Integrity Breach Detected: Halting {}", intelligence.swarm_task);
             crate::ahi_ai_core::AhiAiCore::enforce_compliance(env.clone());
             Symbol::new(&env, "integrity_enforced")
@@ -75,6 +127,7 @@ impl GlobalDecentralizedAiSwarmIntelligenceHub {
     pub fn get_swarm_status(env: Env) -> Map<Symbol, i64> {
         let mut status = Map::new(&env);
         status.set(Symbol::new(&env, "active_swarms"), 50); // Simulated count
+        status.set(Symbol::new(&env, "registered_agents"), Self::get_agent_count(env.clone()));
         status.set(Symbol::new(&env, "consensus_rate"), 100); // Perfect
         status.set(Symbol::new(&env, "intelligence_level"), 100);
         status
@@ -93,13 +146,13 @@ impl GlobalDecentralizedAiSwarmIntelligenceHub {
     }
 
     /// Render swarm intelligence hologram
-    pub fn render_swarm_hologram(env: Env, intelligence: SwarmIntelligence) -> Vec<Symbol> {
+    pub fn render_swarm_hologram(env: Env, intelligence: SwarmIntelligence) -> Vec<String> {
         let hologram = Vec::from_array(&env, [
-            Symbol::new(&env, "Decentralized Swarm Intelligence Hologram"),
-            intelligence.swarm_task,
-            Symbol::new(&env, &format!("Consensus: {}", intelligence.consensus_reached)),
-            Symbol::new(&env, &format!("Intelligence Score: {}", intelligence.intelligence_score)),
-            Symbol::new(&env, &format!("Swarm Size: {}", intelligence.swarm_size)),
+            String::from_str(&env, "Decentralized Swarm Intelligence Hologram"),
+            crate::hologram::symbol_to_string(&env, &intelligence.swarm_task),
+            String::from_str(&env, &format!("Consensus: {}", intelligence.consensus_reached)),
+            String::from_str(&env, &format!("Intelligence Score: {}", intelligence.intelligence_score)),
+            String::from_str(&env, &format!("Swarm Size: {}", intelligence.swarm_size)),
         ]);
         log!(&env, "Swarm Hologram Rendered");
         hologram
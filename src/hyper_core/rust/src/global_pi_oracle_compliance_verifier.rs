@@ -3,7 +3,7 @@
 // Verifies global PI compliance and enforces ecosystem purity.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, Address, Env, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct GlobalPiOracleComplianceVerifier;
@@ -18,8 +18,9 @@ pub struct OracleData {
 
 #[contractimpl]
 impl GlobalPiOracleComplianceVerifier {
-    /// Initialize the Verifier
-    pub fn init(env: Env) -> GlobalPiOracleComplianceVerifier {
+    /// Initialize the Verifier with the admin allowed to change the source list
+    pub fn init(env: Env, admin: Address) -> GlobalPiOracleComplianceVerifier {
+        crate::access_control::set_admin(&env, &admin);
         log!(&env, "Global PI Oracle Compliance Verifier Initialized");
         GlobalPiOracleComplianceVerifier
     }
@@ -27,7 +28,7 @@ impl GlobalPiOracleComplianceVerifier {
     /// Fetch and verify oracle data
     pub fn fetch_verify_oracle(env: Env, source: Symbol) -> OracleData {
         // Simulate fetching from Pi Network (in real: API call)
-        let compliance_score = if source == Symbol::new(&env, "mining") || source == Symbol::new(&env, "p2p") {
+        let compliance_score = if Self::get_allowed_sources(env.clone()).contains(&source) {
             100
         } else {
             0 // Volatile sources rejected
@@ -57,13 +58,28 @@ impl GlobalPiOracleComplianceVerifier {
         Ok(Symbol::new(&env, "verified"))
     }
 
+    /// Replace the set of legitimate PI sources (admin only)
+    pub fn set_allowed_sources(env: Env, sources: Vec<Symbol>) {
+        crate::access_control::require_admin(&env);
+        env.storage().instance().set(&Symbol::new(&env, "allowed_sources"), &sources);
+        log!(&env, "Oracle Sources Updated: {}", sources.len());
+    }
+
+    /// Get the legitimate PI sources (defaults to mining, contribution_rewards and p2p)
+    pub fn get_allowed_sources(env: Env) -> Vec<Symbol> {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "allowed_sources"))
+            .unwrap_or_else(|| Vec::from_array(&env, [
+                Symbol::new(&env, "mining"),
+                Symbol::new(&env, "contribution_rewards"),
+                Symbol::new(&env, "p2p"),
+            ]))
+    }
+
     /// Global compliance check
     pub fn global_compliance_check(env: Env) -> Map<Symbol, i64> {
-        let sources = Vec::from_array(&env, [
-            Symbol::new(&env, "mining"),
-            Symbol::new(&env, "contribution_rewards"),
-            Symbol::new(&env, "p2p"),
-        ]);
+        let sources = Self::get_allowed_sources(env.clone());
         let mut results = Map::new(&env);
         for source in sources.iter() {
             let data = Self::fetch_verify_oracle(env.clone(), source.clone());
@@ -86,10 +102,7 @@ impl GlobalPiOracleComplianceVerifier {
 
     /// Get oracle status
     pub fn get_oracle_status(env: Env) -> Vec<OracleData> {
-        let sources = Vec::from_array(&env, [
-            Symbol::new(&env, "mining"),
-            Symbol::new(&env, "p2p"),
-        ]);
+        let sources = Self::get_allowed_sources(env.clone());
         sources.iter().map(|source| Self::fetch_verify_oracle(env.clone(), source.clone())).collect()
     }
 }
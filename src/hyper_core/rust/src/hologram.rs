@@ -0,0 +1,22 @@
+// src/hyper_core/rust/src/hologram.rs
+// Hologram - shared text conversion for the contracts' render_*_hologram methods
+// Holograms are Vec<String>; Symbol fields are converted here so callers never downcast mixed values.
+// Dependencies: soroban-sdk = "0.9" in Cargo.toml
+
+use soroban_sdk::{Env, String, Symbol, SymbolStr, TryFromVal};
+
+/// The text of a Symbol as a String, for hologram lines that show a symbol field
+pub fn symbol_to_string(env: &Env, symbol: &Symbol) -> String {
+    String::from_str(env, symbol_text(env, symbol).as_ref())
+}
+
+/// "<label>: <symbol text>" as a String, for hologram lines that label a symbol field
+pub fn labelled_symbol(env: &Env, label: &str, symbol: &Symbol) -> String {
+    let text = symbol_text(env, symbol);
+    let text: &str = text.as_ref();
+    String::from_str(env, &format!("{}: {}", label, text))
+}
+
+fn symbol_text(env: &Env, symbol: &Symbol) -> SymbolStr {
+    SymbolStr::try_from_val(env, &symbol.to_symbol_val()).expect("symbol text unreadable")
+}
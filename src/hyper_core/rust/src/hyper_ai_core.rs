@@ -1,12 +1,40 @@
 // src/hyper_core/rust/src/hyper_ai_core.rs
 // Autonomous Hyper Intelligence AI Core for Pi Ecosystem Super App
 // This module provides super-intelligent filtering and compliance enforcement.
-// Dependencies: Add to Cargo.toml: tokio = "1.0", serde = { version = "1.0", features = ["derive"] }, reqwest = "0.11" (for API calls)
+// Dependencies: Add to Cargo.toml: tokio = "1.0", serde = { version = "1.0", features = ["derive"] }, reqwest = "0.11" (for API calls and webhooks), chrono = "0.4" (for webhook timestamps)
 
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::Mutex;
+use tokio::time::Instant;
 use serde::{Deserialize, Serialize};
 use reqwest::Client;
+use crate::error::SuperPiError;
+use crate::telemetry::{info, warn};
+
+// Pluggable volatility scoring model (0.0 = stable, 1.0 = volatile)
+pub trait VolatilityModel {
+    fn score(&self, input: &str) -> f64;
+
+    // Score plus each token's share of it, from the same pass; models that can't attribute list no tokens
+    fn score_with_contributions(&self, input: &str) -> (f64, Vec<(String, f64)>) {
+        (self.score(input), Vec::new())
+    }
+
+    // Score, or Err when the model can't answer right now (e.g., a remote model is unreachable);
+    // local models never fail
+    fn try_score(&self, input: &str) -> Result<f64, String> {
+        Ok(self.score(input))
+    }
+
+    // Streaming score: `seen` is everything received so far (ending with `chunk`) and `running` carries the
+    // model's state between chunks, starting at 0.0. The default rescores all of `seen`; models whose score
+    // accumulates per character override it to score only the new chunk.
+    fn try_score_chunk(&self, _running: &mut f64, seen: &str, _chunk: &str) -> Result<f64, String> {
+        self.try_score(seen)
+    }
+}
 
 // Simulated Neural Network for AI Decision-Making (placeholder for advanced ML)
 #[derive(Clone)]
@@ -28,42 +56,236 @@ impl HyperNeuralNet {
     }
 }
 
+impl VolatilityModel for HyperNeuralNet {
+    fn score(&self, input: &str) -> f64 {
+        self.predict_volatility(input)
+    }
+
+    // predict_volatility is a char sum, so each chunk only adds its own
+    fn try_score_chunk(&self, running: &mut f64, _seen: &str, chunk: &str) -> Result<f64, String> {
+        *running += chunk.chars().map(|c| c as u32 as f64).sum::<f64>();
+        Ok((*running / 1000.0).clamp(0.0, 1.0))
+    }
+
+    // Same char sum as predict_volatility, also split per whitespace-separated token
+    fn score_with_contributions(&self, input: &str) -> (f64, Vec<(String, f64)>) {
+        let mut total = 0.0;
+        let mut tokens = Vec::new();
+        let mut token = String::new();
+        let mut token_sum = 0.0;
+        for c in input.chars() {
+            let value = c as u32 as f64;
+            total += value;
+            if c.is_whitespace() {
+                if !token.is_empty() {
+                    tokens.push((std::mem::take(&mut token), token_sum / 1000.0));
+                    token_sum = 0.0;
+                }
+            } else {
+                token.push(c);
+                token_sum += value;
+            }
+        }
+        if !token.is_empty() {
+            tokens.push((token, token_sum / 1000.0));
+        }
+        ((total / 1000.0).clamp(0.0, 1.0), tokens)
+    }
+}
+
+const DEFAULT_COMPLIANCE_URL: &str = "https://api.pi.network/compliance"; // Placeholder URL
+const VOLATILITY_THRESHOLD: f64 = 0.5; // Inputs scoring above this are rejected
+const MAX_EXPLAINED_TOKENS: usize = 5; // Top contributors listed in a FilterExplanation
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(5); // A slow webhook can't stall enforcement longer than this
+
+// Why filter_io would accept or reject an input
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct FilterExplanation {
+    pub score: f64,
+    pub threshold: f64,
+    pub rejected: bool,
+    pub contributing_tokens: Vec<(String, f64)>, // Largest contributions first; repeated tokens are summed
+}
+
+// JSON body POSTed to the compliance webhook when the compliance state changes
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ComplianceNotification {
+    pub compliant: bool,
+    pub stellar_halted: bool,
+    pub ts: i64, // Unix seconds
+}
+
+// Where compliance answers come from (non-Remote modes work offline, e.g., CI and demos)
+#[derive(Clone, Debug, PartialEq)]
+pub enum ComplianceMode {
+    Remote,               // Query the compliance endpoint
+    AlwaysCompliant,
+    AlwaysNonCompliant,
+    Scripted(Vec<bool>),  // Successive answers per enforcement (probes peek); the last one repeats once exhausted
+}
+
 // AI Core State
 #[derive(Clone)]
 pub struct AutonomousHyperAI {
-    neural_net: HyperNeuralNet,
+    model: Arc<dyn VolatilityModel + Send + Sync>, // HyperNeuralNet unless replaced via with_model
     compliance_status: Arc<Mutex<bool>>, // True if Pi Network compliant
     stellar_halted: Arc<Mutex<bool>>,    // True if Stellar support is shut down
     client: Client,
+    compliance_url: String,
+    compliance_mode: ComplianceMode,
+    script_cursor: Arc<AtomicUsize>, // Next answer index for ComplianceMode::Scripted
+    compliance_ttl: Duration, // How long a compliance answer is reused (zero = always query)
+    compliance_cache: Arc<Mutex<Option<(ComplianceResponse, Instant)>>>, // Last answer and when it was fetched
+    compliance_webhook: Arc<Mutex<Option<String>>>, // Notified on compliance state changes, if set
 }
 
 impl AutonomousHyperAI {
     pub fn new() -> Self {
         Self {
-            neural_net: HyperNeuralNet::new(),
+            model: Arc::new(HyperNeuralNet::new()),
             compliance_status: Arc::new(Mutex::new(true)), // Assume compliant initially
             stellar_halted: Arc::new(Mutex::new(false)),
             client: Client::new(),
+            compliance_url: DEFAULT_COMPLIANCE_URL.to_string(),
+            compliance_mode: ComplianceMode::Remote,
+            script_cursor: Arc::new(AtomicUsize::new(0)),
+            compliance_ttl: Duration::ZERO,
+            compliance_cache: Arc::new(Mutex::new(None)),
+            compliance_webhook: Arc::new(Mutex::new(None)),
         }
     }
 
+    // Replace the built-in HyperNeuralNet with a custom volatility model
+    pub fn with_model(mut self, model: Box<dyn VolatilityModel + Send + Sync>) -> Self {
+        self.model = Arc::from(model);
+        self
+    }
+
+    // Point compliance checks at a different endpoint (e.g., testnet or local mock)
+    pub fn with_compliance_url(mut self, url: impl Into<String>) -> Self {
+        self.compliance_url = url.into();
+        self
+    }
+
+    // Choose where compliance answers come from
+    pub fn with_compliance_mode(mut self, mode: ComplianceMode) -> Self {
+        self.compliance_mode = mode;
+        self.script_cursor = Arc::new(AtomicUsize::new(0));
+        self
+    }
+
+    // Reuse each compliance answer for `ttl` instead of querying on every enforcement
+    pub fn with_compliance_ttl(mut self, ttl: Duration) -> Self {
+        self.compliance_ttl = ttl;
+        self
+    }
+
+    // POST a ComplianceNotification to url whenever enforce_compliance changes the compliance state
+    pub async fn set_compliance_webhook(&self, url: String) {
+        *self.compliance_webhook.lock().await = Some(url);
+    }
+
+    // Drop the cached compliance answer so the next enforcement queries again
+    pub async fn invalidate_cache(&self) {
+        *self.compliance_cache.lock().await = None;
+    }
+
+    // Raw model score for data (0.0 = stable, 1.0 = volatile), without rejecting anything
+    pub fn volatility(&self, data: &str) -> f64 {
+        self.model.score(data)
+    }
+
     // Filter input/output in real-time
     pub async fn filter_io(&self, data: &str) -> Result<String, String> {
-        let volatility = self.neural_net.predict_volatility(data);
-        if volatility > 0.5 {
+        self.filter_io_typed(data).await.map_err(String::from)
+    }
+
+    // filter_io with the failure kind kept: Rejected for volatile input (permanent), Unavailable when
+    // the model couldn't score (transient, worth retrying)
+    pub async fn filter_io_typed(&self, data: &str) -> Result<String, SuperPiError> {
+        self.filter_io_scored(data).await?;
+        // Isolate and sanitize for Pi Ecosystem
+        Ok(format!("Sanitized: {}", data.replace("volatile", "isolated")))
+    }
+
+    // The filter_io check alone: the model score of data that passes, so callers can reuse it
+    pub async fn filter_io_scored(&self, data: &str) -> Result<f64, SuperPiError> {
+        let volatility = self.model.try_score(data).map_err(|e| {
+            warn!(reason = %e, "AI model unavailable");
+            SuperPiError::Unavailable(format!("AI unavailable: {}", e))
+        })?;
+        if volatility > VOLATILITY_THRESHOLD {
             // Reject volatile inputs (e.g., external crypto mentions)
-            Err(format!("Volatile input rejected: volatility score {:.2}", volatility))
-        } else {
-            // Isolate and sanitize for Pi Ecosystem
-            Ok(format!("Sanitized: {}", data.replace("volatile", "isolated")))
+            warn!(volatility_score = volatility, "Volatile input rejected");
+            return Err(SuperPiError::Rejected(format!("Volatile input rejected: volatility score {:.2}", volatility)));
         }
+        Ok(volatility)
     }
 
-    // Check Pi Network compliance and enforce Stellar halt if needed
-    pub async fn enforce_compliance(&self) -> Result<(), String> {
+    // Explain filter_io's verdict for data: the score, the threshold, and the tokens that drove the score
+    pub fn filter_io_explain(&self, data: &str) -> FilterExplanation {
+        let (score, contributions) = self.model.score_with_contributions(data);
+
+        let mut totals: Vec<(String, f64)> = Vec::new();
+        for (token, contribution) in contributions {
+            match totals.iter_mut().find(|(seen, _)| *seen == token) {
+                Some((_, total)) => *total += contribution,
+                None => totals.push((token, contribution)),
+            }
+        }
+        totals.sort_by(|a, b| b.1.total_cmp(&a.1));
+        totals.truncate(MAX_EXPLAINED_TOKENS);
+
+        FilterExplanation {
+            score,
+            threshold: VOLATILITY_THRESHOLD,
+            rejected: score > VOLATILITY_THRESHOLD,
+            contributing_tokens: totals,
+        }
+    }
+
+    // Filter a streamed payload chunk by chunk, rejecting as soon as the content seen so far is volatile.
+    // Each check scores the prefix via the model's incremental hook, so an early rejection reports what
+    // filter_io would for that prefix; a model that can't score fails as Unavailable, as in filter_io.
+    pub async fn filter_io_chunks(&self, chunks: impl Iterator<Item = &str>) -> Result<String, String> {
+        let mut data = String::new();
+        let mut running = 0.0;
+        for (index, chunk) in chunks.enumerate() {
+            data.push_str(chunk);
+            let volatility = self.model.try_score_chunk(&mut running, &data, chunk).map_err(|e| {
+                warn!(reason = %e, "AI model unavailable");
+                format!("AI unavailable: {}", e)
+            })?;
+            if volatility > VOLATILITY_THRESHOLD {
+                warn!(volatility_score = volatility, chunks_read = index + 1, "Volatile stream rejected early");
+                return Err(format!("Volatile input rejected: volatility score {:.2}", volatility));
+            }
+        }
+        Ok(format!("Sanitized: {}", data.replace("volatile", "isolated")))
+    }
+
+    // Query the configured compliance source without changing any local state
+    pub async fn probe_compliance(&self) -> Result<bool, String> {
+        match &self.compliance_mode {
+            ComplianceMode::Remote => self.probe_remote_compliance().await,
+            ComplianceMode::AlwaysCompliant => Ok(true),
+            ComplianceMode::AlwaysNonCompliant => Ok(false),
+            ComplianceMode::Scripted(answers) => {
+                // Peek only: enforce_compliance moves the script on (see advance_script)
+                let index = self.script_cursor.load(Ordering::SeqCst);
+                answers
+                    .get(index)
+                    .or_else(|| answers.last())
+                    .copied()
+                    .ok_or_else(|| "Compliance script is empty".to_string())
+            }
+        }
+    }
+
+    async fn probe_remote_compliance(&self) -> Result<bool, String> {
         // Hypothetical API check (replace with real Pi Network endpoint)
         let response = self.client
-            .get("https://api.pi.network/compliance") // Placeholder URL
+            .get(&self.compliance_url)
             .send()
             .await
             .map_err(|e| format!("API error: {}", e))?;
@@ -73,20 +295,78 @@ impl AutonomousHyperAI {
             .await
             .map_err(|e| format!("Parse error: {}", e))?;
 
+        Ok(status.compliant)
+    }
+
+    // Check Pi Network compliance and enforce Stellar halt if needed
+    pub async fn enforce_compliance(&self) -> Result<(), String> {
+        let previous = self.get_status().await;
+        let compliant = match self.cached_compliance().await {
+            Some(compliant) => compliant,
+            None => {
+                let compliant = self.probe_compliance().await?;
+                self.advance_script();
+                if !self.compliance_ttl.is_zero() {
+                    *self.compliance_cache.lock().await = Some((ComplianceResponse { compliant }, Instant::now()));
+                }
+                compliant
+            }
+        };
+
         let mut compliance = self.compliance_status.lock().await;
-        *compliance = status.compliant;
+        *compliance = compliant;
 
         if !*compliance {
             let mut stellar = self.stellar_halted.lock().await;
             *stellar = true;
-            println!("Autonomous Halt: Stellar support disabled due to Pi Network non-compliance.");
+            warn!(compliant = false, stellar_halted = true, "Autonomous Halt: Stellar support disabled due to Pi Network non-compliance");
             // In real impl: Trigger shutdown of Stellar integrations here
         }
+        drop(compliance);
 
+        let current = self.get_status().await;
+        if current != previous {
+            self.notify_webhook(current).await;
+        }
         Ok(())
     }
 
-    // Get current status
+    // Move a Scripted source on to its next answer; only enforcement consumes answers, so health probes don't
+    fn advance_script(&self) {
+        if matches!(self.compliance_mode, ComplianceMode::Scripted(_)) {
+            self.script_cursor.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    // Tell the webhook about a state change; delivery failures are logged, never returned
+    async fn notify_webhook(&self, (compliant, stellar_halted): (bool, bool)) {
+        let Some(url) = self.compliance_webhook.lock().await.clone() else {
+            return;
+        };
+        let notification = ComplianceNotification { compliant, stellar_halted, ts: chrono::Utc::now().timestamp() };
+        let delivered = self
+            .client
+            .post(&url)
+            .timeout(WEBHOOK_TIMEOUT)
+            .json(&notification)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status());
+        match delivered {
+            Ok(_) => info!(url = %url, compliant, stellar_halted, "Compliance webhook notified"),
+            Err(e) => warn!(url = %url, error = %e, "Compliance webhook failed"),
+        }
+    }
+
+    // Cached answer, if one was fetched within the TTL
+    async fn cached_compliance(&self) -> Option<bool> {
+        match &*self.compliance_cache.lock().await {
+            Some((response, fetched_at)) if fetched_at.elapsed() < self.compliance_ttl => Some(response.compliant),
+            _ => None,
+        }
+    }
+
+    // Get current status (reflects the cached answer while it is fresh)
     pub async fn get_status(&self) -> (bool, bool) {
         let compliance = *self.compliance_status.lock().await;
         let stellar = *self.stellar_halted.lock().await;
@@ -95,7 +375,7 @@ impl AutonomousHyperAI {
 }
 
 // API Response Struct
-#[derive(Deserialize)]
+#[derive(Deserialize, Clone, Debug)]
 struct ComplianceResponse {
     compliant: bool,
 }
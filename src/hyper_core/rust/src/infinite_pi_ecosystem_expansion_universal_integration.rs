@@ -3,7 +3,7 @@
 // Enables infinite expansion and universal integration for Pi Ecosystem.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, Env, String, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct InfinitePiEcosystemExpansionUniversalIntegration;
@@ -89,12 +89,12 @@ impl InfinitePiEcosystemExpansionUniversalIntegration {
     }
 
     /// Render expansion hologram
-    pub fn render_expansion_hologram(env: Env, event: ExpansionEvent) -> Vec<Symbol> {
+    pub fn render_expansion_hologram(env: Env, event: ExpansionEvent) -> Vec<String> {
         let hologram = Vec::from_array(&env, [
-            Symbol::new(&env, "Infinite Expansion Hologram"),
-            event.expansion_target,
-            Symbol::new(&env, &format!("Integration Success: {}", event.integration_success)),
-            Symbol::new(&env, &format!("Expansion Scale: {}", event.expansion_scale)),
+            String::from_str(&env, "Infinite Expansion Hologram"),
+            crate::hologram::symbol_to_string(&env, &event.expansion_target),
+            String::from_str(&env, &format!("Integration Success: {}", event.integration_success)),
+            String::from_str(&env, &format!("Expansion Scale: {}", event.expansion_scale)),
         ]);
         log!(&env, "Expansion Hologram Rendered");
         hologram
@@ -1,5 +1,9 @@
 #![no_std]
 
+mod access_control;
+mod rule_chain;
+mod contract_deps;
+mod hologram;
 mod ahi_ai_core;
 mod pi_stablecoin_manager;
 mod autonomous_app_builder;
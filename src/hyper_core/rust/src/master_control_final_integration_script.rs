@@ -77,6 +77,56 @@ impl MasterControlFinalIntegrationScript {
         status
     }
 
+    /// Aggregate supremacy across subsystems: the lowest reading wins, so one degraded subsystem shows
+    pub fn aggregate_supremacy(env: Env) -> i64 {
+        let readings = Self::supremacy_readings(env.clone());
+        let supremacy = Self::lowest_supremacy(env.clone(), readings);
+        log!(&env, "Aggregate Supremacy: {}", supremacy);
+        supremacy
+    }
+
+    /// Supremacy reading per subsystem (a missing status key reads as 0)
+    pub fn supremacy_readings(env: Env) -> Map<Symbol, i64> {
+        let snapshot = Self::ecosystem_snapshot(env.clone());
+        let metrics = [
+            ("capstone", "universal_supremacy"),
+            ("guardian", "eternal_supremacy"),
+            ("security", "quantum_resistance"),
+            ("synchronization", "mainnet_integration"),
+        ];
+
+        let mut readings = Map::new(&env);
+        for (subsystem, metric) in metrics {
+            let value = snapshot
+                .get(Symbol::new(&env, subsystem))
+                .and_then(|status| status.get(Symbol::new(&env, metric)))
+                .unwrap_or(0);
+            readings.set(Symbol::new(&env, subsystem), value);
+        }
+        readings
+    }
+
+    /// Every key subsystem's status map in one call, keyed by subsystem name
+    pub fn ecosystem_snapshot(env: Env) -> Map<Symbol, Map<Symbol, i64>> {
+        let mut snapshot = Map::new(&env);
+        snapshot.set(Symbol::new(&env, "security"), crate::eternal_quantum_security_anti_quantum_threat::EternalQuantumSecurityAntiQuantumThreat::get_security_status(env.clone()));
+        snapshot.set(Symbol::new(&env, "governance"), crate::ultimate_ai_governance_ethical_overseer::UltimateAiGovernanceEthicalOverseer::get_governance_status(env.clone()));
+        snapshot.set(Symbol::new(&env, "synchronization"), crate::pi_mainnet_integration_real_time_synchronization::PiMainnetIntegrationRealTimeSynchronization::get_synchronization_status(env.clone()));
+        snapshot.set(Symbol::new(&env, "swarm"), crate::global_decentralized_ai_swarm_intelligence_hub::GlobalDecentralizedAiSwarmIntelligenceHub::get_swarm_status(env.clone()));
+        snapshot.set(Symbol::new(&env, "capstone"), crate::final_universal_integration_supremacy_capstone::FinalUniversalIntegrationSupremacyCapstone::get_capstone_status(env.clone()));
+        snapshot.set(Symbol::new(&env, "guardian"), crate::ultimate_ecosystem_guardian_summary_script::UltimateEcosystemGuardianSummaryScript::get_guardian_status(env.clone()));
+        snapshot
+    }
+
+    /// Lowest supremacy reading (0 when there are no readings)
+    pub fn lowest_supremacy(env: Env, readings: Map<Symbol, i64>) -> i64 {
+        if readings.is_empty() {
+            log!(&env, "No Supremacy Readings Available");
+            return 0;
+        }
+        readings.values().iter().min().unwrap_or(0)
+    }
+
     /// Update master script rules
     pub fn update_master_rules(env: Env, new_rule: Symbol) -> Result<Symbol, Symbol> {
         // Validate via UI Hub
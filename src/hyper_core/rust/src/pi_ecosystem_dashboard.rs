@@ -11,7 +11,7 @@ use crate::hyper_ai_core::AutonomousHyperAI;
 use crate::pi_transaction_engine::PITransactionEngine;
 use crate::pi_mainnet_accelerator::PiMainnetAccelerator;
 use crate::ecosystem_isolation_shield::EcosystemIsolationShield;
-use crate::developer_app_orchestrator::DeveloperAppOrchestrator;
+use crate::developer_app_orchestrator::{AppStatus, DeveloperAppOrchestrator};
 use crate::super_app_controller::{SuperAppController, ControllerDashboard};
 
 // Dashboard Data Struct
@@ -25,6 +25,48 @@ pub struct PiEcosystemDashboard {
     pub isolation_report: IsolationReport,
     pub app_overview: AppOverview,
     pub evolutionary_suggestions: Vec<String>,
+    pub kpi_score: f64, // Single 0.0-1.0 health summary; see kpi_score()
+}
+
+// KPI weights (sum to 1.0): compliance matters most, then mainnet progress and app uptime,
+// then isolation pressure
+const KPI_COMPLIANCE_WEIGHT: f64 = 0.35;
+const KPI_PROGRESS_WEIGHT: f64 = 0.25;
+const KPI_UPTIME_WEIGHT: f64 = 0.25;
+const KPI_ISOLATION_WEIGHT: f64 = 0.15;
+const KPI_ISOLATION_WINDOW_SECS: u64 = 300; // Isolation rate is measured over the last 5 minutes
+
+// Weighted blend of compliance rate, mainnet progress, and uptime (each 0.0 to 1.0) with an
+// isolation term of 1 / (1 + isolations per minute), so a quiet shield scores 1.0 and one
+// isolation a minute scores 0.5. Clamped to 0.0..=1.0.
+pub fn kpi_score(compliance_rate: f64, mainnet_progress: f64, uptime_rate: f64, isolations_per_minute: f64) -> f64 {
+    let isolation_health = 1.0 / (1.0 + isolations_per_minute.max(0.0));
+    let score = KPI_COMPLIANCE_WEIGHT * compliance_rate.clamp(0.0, 1.0)
+        + KPI_PROGRESS_WEIGHT * mainnet_progress.clamp(0.0, 1.0)
+        + KPI_UPTIME_WEIGHT * uptime_rate.clamp(0.0, 1.0)
+        + KPI_ISOLATION_WEIGHT * isolation_health;
+    score.clamp(0.0, 1.0)
+}
+
+// What changed between two dashboard polls (positive = grew since the older one)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct DashboardDiff {
+    pub elapsed_secs: i64,
+    pub transactions_delta: i64,
+    pub apps_running_delta: i64,
+    pub mainnet_progress_delta: f64,
+    pub new_isolation_events: usize, // Events purged between polls aren't subtracted, so this never goes negative
+}
+
+// Compare two dashboards, `old` being the earlier poll
+pub fn diff_dashboards(old: &PiEcosystemDashboard, new: &PiEcosystemDashboard) -> DashboardDiff {
+    DashboardDiff {
+        elapsed_secs: (new.timestamp - old.timestamp).num_seconds(),
+        transactions_delta: new.transaction_summary.total_transactions as i64 - old.transaction_summary.total_transactions as i64,
+        apps_running_delta: new.app_overview.apps_running as i64 - old.app_overview.apps_running as i64,
+        mainnet_progress_delta: new.mainnet_status.progress - old.mainnet_status.progress,
+        new_isolation_events: new.isolation_report.events_quarantined.saturating_sub(old.isolation_report.events_quarantined),
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -93,6 +135,7 @@ impl PiEcosystemDashboardModule {
         let mainnet_metrics = self.mainnet_accelerator.get_metrics().await;
         let isolation_events = self.isolation_shield.get_events().await;
         let orchestrator_metrics = self.app_orchestrator.get_metrics().await;
+        let recent_isolations = self.isolation_shield.recent_event_count(KPI_ISOLATION_WINDOW_SECS).await;
 
         // Aggregate insights
         let ai_insights = AIInsights {
@@ -132,6 +175,13 @@ impl PiEcosystemDashboardModule {
             suggestions.push("Optimize app orchestration.".to_string());
         }
 
+        let kpi_score = kpi_score(
+            mainnet_metrics.compliance_rate,
+            mainnet_metrics.mainnet_open_progress,
+            orchestrator_metrics.uptime_rate,
+            recent_isolations as f64 * 60.0 / KPI_ISOLATION_WINDOW_SECS as f64,
+        );
+
         PiEcosystemDashboard {
             timestamp: Utc::now(),
             controller_status: controller_dashboard,
@@ -141,13 +191,38 @@ impl PiEcosystemDashboardModule {
             isolation_report,
             app_overview,
             evolutionary_suggestions: suggestions,
+            kpi_score,
         }
     }
 
-    // Export dashboard as JSON (for API simulation)
-    pub async fn export_json(&self) -> String {
+    // Export dashboard as JSON (for API simulation); serialization errors are returned, never replaced by "{}"
+    pub async fn export_json(&self) -> Result<String, serde_json::Error> {
+        let dashboard = self.generate_dashboard().await;
+        serde_json::to_string_pretty(&dashboard)
+    }
+
+    // Export key gauges in Prometheus text exposition format (for scraping)
+    pub async fn export_prometheus(&self) -> String {
         let dashboard = self.generate_dashboard().await;
-        serde_json::to_string_pretty(&dashboard).unwrap_or_else(|_| "{}".to_string())
+        // The dashboard's apps_running counts every managed app and its compliance rate is the binary AI
+        // flag; the gauges report the apps actually Running and the accelerator's evolved rate instead
+        let apps_running = self.app_orchestrator.apps_by_status(AppStatus::Running).await.len();
+        let compliance_rate = self.mainnet_accelerator.get_metrics().await.compliance_rate;
+        let metrics: [(&str, &str, &str, f64); 5] = [
+            ("superpi_transactions_total", "counter", "Total PI transactions processed.", dashboard.transaction_summary.total_transactions as f64),
+            ("superpi_mainnet_progress", "gauge", "Mainnet opening progress (0.0 to 1.0).", dashboard.mainnet_status.progress),
+            ("superpi_apps_running", "gauge", "Developer apps currently running.", apps_running as f64),
+            ("superpi_isolation_events_total", "counter", "Total isolation events recorded by the shield.", dashboard.isolation_report.events_quarantined as f64),
+            ("superpi_compliance_rate", "gauge", "Pi Network compliance rate (0.0 to 1.0).", compliance_rate),
+        ];
+
+        let mut output = String::new();
+        for (name, kind, help, value) in metrics {
+            output.push_str(&format!("# HELP {} {}\n", name, help));
+            output.push_str(&format!("# TYPE {} {}\n", name, kind));
+            output.push_str(&format!("{} {}\n", name, value));
+        }
+        output
     }
 }
 
@@ -167,9 +242,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Dashboard Timestamp: {}", data.timestamp);
     println!("Apps Running: {}", data.app_overview.apps_running);
     println!("Evolutionary Suggestions: {:?}", data.evolutionary_suggestions);
+    println!("KPI Score: {:.2}", data.kpi_score);
 
     // Export as JSON
-    let json = dashboard.export_json().await;
+    let json = dashboard.export_json().await?;
     println!("JSON Export: {}", json);
 
     Ok(())
@@ -0,0 +1,87 @@
+// src/hyper_core/rust/src/pi_exclusivity.rs
+// PI Exclusivity Policy for Pi Ecosystem Super App
+// One place deciding what counts as PI-only: receivers must be Pi addresses, and senders, free-text fields and app code
+// may not reference other networks.
+// Dependencies: Add to Cargo.toml: regex = "1.5" (for address formats)
+// Integrate with the async modules: use crate::pi_exclusivity::{PiExclusivityConfig, PiExclusivityPolicy};
+
+use regex::Regex;
+
+// Policy settings (override for testnets with different address formats)
+#[derive(Clone, Debug)]
+pub struct PiExclusivityConfig {
+    pub address_pattern: String,   // Receivers must match this regex
+    pub banned_terms: Vec<String>, // Other-network names; matched case-insensitively against whole words
+}
+
+impl Default for PiExclusivityConfig {
+    fn default() -> Self {
+        Self {
+            address_pattern: r"^pi_[a-zA-Z0-9]+$".to_string(),
+            banned_terms: ["bitcoin", "btc", "ethereum", "eth", "stellar", "xlm", "usdt", "usdc"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+        }
+    }
+}
+
+// Compiled policy shared by the transaction engine and the app orchestrator
+#[derive(Clone, Debug)]
+pub struct PiExclusivityPolicy {
+    address_pattern: Regex,
+    banned_terms: Vec<String>, // Lowercased
+}
+
+impl Default for PiExclusivityPolicy {
+    fn default() -> Self {
+        Self::new(PiExclusivityConfig::default()).expect("Default exclusivity policy is valid")
+    }
+}
+
+impl PiExclusivityPolicy {
+    pub fn new(config: PiExclusivityConfig) -> Result<Self, String> {
+        let address_pattern = Regex::new(&config.address_pattern)
+            .map_err(|e| format!("Invalid address pattern '{}': {}", config.address_pattern, e))?;
+        if config.banned_terms.iter().any(|term| term.trim().is_empty()) {
+            return Err("Invalid banned term: must not be empty".to_string());
+        }
+        let banned_terms = config.banned_terms.iter().map(|term| term.trim().to_lowercase()).collect();
+        Ok(Self { address_pattern, banned_terms })
+    }
+
+    // Senders may be any non-empty identifier that doesn't name another network
+    pub fn validate_sender(&self, sender: &str) -> Result<(), String> {
+        if sender.trim().is_empty() {
+            return Err("sender is empty".to_string());
+        }
+        self.validate_field("sender", sender)
+    }
+
+    // Funds may only move to Pi addresses
+    pub fn validate_receiver(&self, receiver: &str) -> Result<(), String> {
+        if !self.address_pattern.is_match(receiver) {
+            return Err("receiver not a Pi address".to_string());
+        }
+        self.validate_field("receiver", receiver)
+    }
+
+    // Free text (memos, source proofs, app code) must not reference other networks
+    pub fn validate_field(&self, field: &str, value: &str) -> Result<(), String> {
+        match self.banned_term_in(value) {
+            Some(term) => Err(format!("{} references non-PI network: {}", field, term)),
+            None => Ok(()),
+        }
+    }
+
+    // First banned term appearing as a whole word (words split on anything but letters and digits)
+    fn banned_term_in(&self, value: &str) -> Option<&str> {
+        value
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .find_map(|word| {
+                let word = word.to_lowercase();
+                self.banned_terms.iter().find(|term| **term == word).map(String::as_str)
+            })
+    }
+}
@@ -1,15 +1,21 @@
 // src/hyper_core/rust/src/pi_mainnet_accelerator.rs
 // Pi Mainnet Accelerator for Pi Ecosystem Super App
 // Accelerates full mainnet opening, scales app management, and evolves Pi Network.
-// Dependencies: Add to Cargo.toml: tokio = "1.0", rayon = "1.5" (for parallelism), serde = { version = "1.0", features = ["derive"] }
+// Dependencies: Add to Cargo.toml: tokio = "1.0", rayon = "1.5" (for parallelism), serde = { version = "1.0", features = ["derive"] }, rand = "0.8" (for simulated node failures)
 // Integrate with previous modules: pub mod hyper_ai_core; pub mod pi_transaction_engine;
 
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use serde::{Deserialize, Serialize};
 use rayon::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use crate::hyper_ai_core::AutonomousHyperAI;
 use crate::pi_transaction_engine::{PITransactionEngine, PITransaction};
+use crate::telemetry::info;
+use crate::worker_pool::WorkerPool;
 
 // Mainnet Node Struct
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -33,8 +39,27 @@ pub struct PiMainnetAccelerator {
     nodes: Arc<Mutex<Vec<PiNode>>>,
     app_count: Arc<Mutex<u64>>, // Tracks managed apps (scales to millions)
     evolution_metrics: Arc<Mutex<EvolutionMetrics>>,
+    max_apps_per_node: usize, // Per-node capacity; apps beyond total capacity are rejected
+    node_failure_rate: f64, // Share of nodes that come up Halted in accelerate_mainnet (resilience testing)
+    node_failure_seed: u64, // Seed for choosing which nodes fail, so runs are reproducible
+    compliance_decay: f64, // Subtracted every evolution cycle, so compliance must be re-earned
+    compliance_ceiling: f64, // Upper bound evolution can push compliance to
+    thread_pool: WorkerPool, // rayon's global pool unless set via with_parallelism
 }
 
+// Observed ecosystem signals that drive evolve_system
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EvolutionSignals {
+    pub recent_isolation_events: usize, // Isolations seen by the shield in the recent window
+    pub app_uptime_rate: f64,           // Orchestrator uptime (0.0 to 1.0)
+}
+
+const DEFAULT_MAX_APPS_PER_NODE: usize = 10_000; // 1000 nodes x 10k apps covers the 10M-app target
+const EVOLUTION_STEP: f64 = 0.01; // Max upward adjustment per cycle at full uptime
+const ISOLATION_PENALTY: f64 = 0.005; // Downward adjustment per recent isolation event
+const MAX_ISOLATION_PENALTY: f64 = 0.1; // Cap so a burst can't zero compliance in one cycle
+const DEFAULT_COMPLIANCE_DECAY: f64 = 0.002; // Below EVOLUTION_STEP, so healthy uptime still nets a gain
+
 #[derive(Clone, Debug)]
 pub struct EvolutionMetrics {
     pub mainnet_open_progress: f64, // 0.0 to 1.0
@@ -42,6 +67,12 @@ pub struct EvolutionMetrics {
     pub compliance_rate: f64,
 }
 
+impl Default for EvolutionMetrics {
+    fn default() -> Self {
+        Self { mainnet_open_progress: 0.0, apps_processed: 0, compliance_rate: 1.0 }
+    }
+}
+
 impl PiMainnetAccelerator {
     pub fn new(ai_core: Arc<AutonomousHyperAI>, tx_engine: Arc<PITransactionEngine>) -> Self {
         Self {
@@ -49,14 +80,54 @@ impl PiMainnetAccelerator {
             tx_engine,
             nodes: Arc::new(Mutex::new(Vec::new())),
             app_count: Arc::new(Mutex::new(0)),
-            evolution_metrics: Arc::new(Mutex::new(EvolutionMetrics {
-                mainnet_open_progress: 0.0,
-                apps_processed: 0,
-                compliance_rate: 1.0,
-            })),
+            evolution_metrics: Arc::new(Mutex::new(EvolutionMetrics::default())),
+            max_apps_per_node: DEFAULT_MAX_APPS_PER_NODE,
+            node_failure_rate: 0.0,
+            node_failure_seed: 0,
+            compliance_decay: DEFAULT_COMPLIANCE_DECAY,
+            compliance_ceiling: 1.0,
+            thread_pool: WorkerPool::default(),
         }
     }
 
+    // Override per-node app capacity
+    pub fn with_max_apps_per_node(mut self, max_apps_per_node: usize) -> Self {
+        self.max_apps_per_node = max_apps_per_node;
+        self
+    }
+
+    // Simulate partial availability: each node independently comes up Halted with probability
+    // failure_rate (clamped to 0.0..=1.0), drawn from an RNG seeded with `seed`
+    pub fn with_node_failure_rate(mut self, failure_rate: f64, seed: u64) -> Self {
+        self.node_failure_rate = if failure_rate.is_nan() { 0.0 } else { failure_rate.clamp(0.0, 1.0) };
+        self.node_failure_seed = seed;
+        self
+    }
+
+    // Per-cycle downward drift of compliance_rate (negative or NaN treated as 0.0)
+    pub fn with_compliance_decay(mut self, decay: f64) -> Self {
+        self.compliance_decay = if decay.is_nan() { 0.0 } else { decay.max(0.0) };
+        self
+    }
+
+    // Highest compliance_rate evolution can reach (clamped to 0.0..=1.0)
+    pub fn with_compliance_ceiling(mut self, ceiling: f64) -> Self {
+        self.compliance_ceiling = if ceiling.is_nan() { 1.0 } else { ceiling.clamp(0.0, 1.0) };
+        self
+    }
+
+    // Run parallel sections on a dedicated pool of `threads` workers instead of rayon's global pool,
+    // so a shared process isn't starved
+    pub fn with_parallelism(mut self, threads: usize) -> Result<Self, String> {
+        self.thread_pool = WorkerPool::with_threads(threads)?;
+        Ok(self)
+    }
+
+    // Worker threads available to parallel sections
+    pub fn parallelism(&self) -> usize {
+        self.thread_pool.threads()
+    }
+
     // Accelerate mainnet opening by syncing nodes in parallel
     pub async fn accelerate_mainnet(&self) -> Result<(), String> {
         // AI Check: Ensure compliance before acceleration
@@ -69,21 +140,29 @@ impl PiMainnetAccelerator {
         let mut nodes = self.nodes.lock().await;
         let node_ids: Vec<String> = (0..1000).map(|i| format!("node_{}", i)).collect(); // Simulate 1000 nodes
 
-        let synced_nodes: Vec<PiNode> = node_ids
-            .par_iter()
-            .map(|id| PiNode {
-                id: id.clone(),
-                status: NodeStatus::Active,
-                apps_managed: vec![], // Will be populated
-            })
-            .collect();
+        // Failures are drawn up front, in node order, so the seed alone decides which nodes halt
+        let mut rng = StdRng::seed_from_u64(self.node_failure_seed);
+        let failed: Vec<bool> = node_ids.iter().map(|_| rng.gen_bool(self.node_failure_rate)).collect();
+
+        let synced_nodes: Vec<PiNode> = self.thread_pool.install(|| {
+            node_ids
+                .par_iter()
+                .zip(failed.par_iter())
+                .map(|(id, &failed)| PiNode {
+                    id: id.clone(),
+                    status: if failed { NodeStatus::Halted } else { NodeStatus::Active },
+                    apps_managed: vec![], // Will be populated
+                })
+                .collect()
+        });
 
         nodes.extend(synced_nodes);
+        let active_nodes = nodes.iter().filter(|n| matches!(n.status, NodeStatus::Active)).count();
 
         // Update progress
         let mut metrics = self.evolution_metrics.lock().await;
         metrics.mainnet_open_progress = 1.0; // Fully open
-        println!("Mainnet fully accelerated and open: {} nodes synced.", nodes.len());
+        info!(nodes_synced = nodes.len(), active_nodes, "Mainnet fully accelerated and open");
 
         Ok(())
     }
@@ -96,49 +175,140 @@ impl PiMainnetAccelerator {
         }
 
         // Parallel processing for scaling
-        let processed: Vec<String> = app_ids
-            .par_iter()
-            .map(|id| {
-                // Simulate app validation and assignment to nodes
-                format!("App {} validated and assigned.", id)
-            })
-            .collect();
+        let processed: Vec<String> = self.thread_pool.install(|| {
+            app_ids
+                .par_iter()
+                .map(|id| {
+                    // Simulate app validation and assignment to nodes
+                    format!("App {} validated and assigned.", id)
+                })
+                .collect()
+        });
 
         // Update nodes and metrics
         let mut nodes = self.nodes.lock().await;
+        // Halted nodes take no apps
+        let active: Vec<usize> = (0..nodes.len()).filter(|&j| matches!(nodes[j].status, NodeStatus::Active)).collect();
+        if active.is_empty() {
+            // Nothing to assign to: leave counters untouched so they can't drift
+            return Err("No active nodes: accelerate mainnet before managing apps.".to_string());
+        }
         let mut app_count = self.app_count.lock().await;
         let mut metrics = self.evolution_metrics.lock().await;
 
-        for (i, app_id) in app_ids.iter().enumerate() {
-            if let Some(node) = nodes.get_mut(i % nodes.len()) {
-                node.apps_managed.push(app_id.clone());
+        // Least-loaded first (lowest index on ties) via a min-heap of (load, node index); nodes at
+        // capacity leave the heap, and once it empties the remaining apps overflow
+        let node_count = active.len();
+        let mut least_loaded: BinaryHeap<Reverse<(usize, usize)>> = active
+            .iter()
+            .map(|&j| (nodes[j].apps_managed.len(), j))
+            .filter(|&(load, _)| load < self.max_apps_per_node)
+            .map(Reverse)
+            .collect();
+        let mut placed = 0u64;
+        let mut overflow = Vec::new();
+        for app_id in &app_ids {
+            match least_loaded.pop() {
+                Some(Reverse((load, j))) => {
+                    nodes[j].apps_managed.push(app_id.clone());
+                    placed += 1;
+                    if load + 1 < self.max_apps_per_node {
+                        least_loaded.push(Reverse((load + 1, j)));
+                    }
+                }
+                None => overflow.push(app_id.clone()),
             }
         }
 
-        *app_count += app_ids.len() as u64;
-        metrics.apps_processed += app_ids.len() as u64;
-        metrics.compliance_rate = 0.99; // Simulate high compliance
+        *app_count += placed;
+        metrics.apps_processed += placed;
 
-        println!("Managed {} apps across {} nodes.", processed.len(), nodes.len());
+        info!(apps = processed.len(), placed, nodes = node_count, "Managed apps across nodes");
+        if !overflow.is_empty() {
+            return Err(format!(
+                "Node capacity exceeded: {} app(s) could not be placed: {}",
+                overflow.len(),
+                overflow.join(", ")
+            ));
+        }
         Ok(())
     }
 
     // Evolve Pi Network system via adaptive algorithms
-    pub async fn evolve_system(&self) -> Result<(), String> {
-        // Simulate evolutionary improvements (e.g., optimize transaction throughput)
-        let mut metrics = self.evolution_metrics.lock().await;
-        metrics.compliance_rate += 0.01; // Incremental evolution
-        if metrics.compliance_rate > 1.0 {
-            metrics.compliance_rate = 1.0;
-        }
+    pub async fn evolve_system(&self, signals: EvolutionSignals) -> Result<(), String> {
+        // Healthy uptime earns compliance; isolation pressure and steady decay erode it
+        let reward = EVOLUTION_STEP * signals.app_uptime_rate.clamp(0.0, 1.0);
+        let penalty = (ISOLATION_PENALTY * signals.recent_isolation_events as f64).min(MAX_ISOLATION_PENALTY);
+
+        let compliance_rate = {
+            let mut metrics = self.evolution_metrics.lock().await;
+            let evolved = metrics.compliance_rate + reward - penalty - self.compliance_decay;
+            metrics.compliance_rate = evolved.clamp(0.0, self.compliance_ceiling);
+            metrics.compliance_rate
+        };
 
         // Trigger AI enforcement if needed
         self.ai_core.enforce_compliance().await?;
-        println!("Pi Network evolved: Compliance rate now {:.2}", metrics.compliance_rate);
+        info!(
+            compliance_rate,
+            recent_isolation_events = signals.recent_isolation_events,
+            app_uptime_rate = signals.app_uptime_rate,
+            "Pi Network evolved"
+        );
 
         Ok(())
     }
 
+    // Take apps off their nodes (e.g., a rolled-back deploy); returns how many assignments were removed
+    pub async fn release_apps(&self, app_ids: &[String]) -> u64 {
+        let mut nodes = self.nodes.lock().await;
+        let mut released = 0u64;
+        for node in nodes.iter_mut() {
+            let before = node.apps_managed.len();
+            node.apps_managed.retain(|id| !app_ids.contains(id));
+            released += (before - node.apps_managed.len()) as u64;
+        }
+        let mut app_count = self.app_count.lock().await;
+        *app_count = app_count.saturating_sub(released);
+        released
+    }
+
+    // Get number of apps assigned to nodes
+    pub async fn app_count(&self) -> u64 {
+        *self.app_count.lock().await
+    }
+
+    // Number of nodes currently Active (halted nodes are excluded)
+    pub async fn active_node_count(&self) -> usize {
+        self.nodes.lock().await.iter().filter(|n| matches!(n.status, NodeStatus::Active)).count()
+    }
+
+    // Managed app count per node, in node order
+    pub async fn node_load(&self) -> Vec<(String, usize)> {
+        self.nodes.lock().await.iter().map(|n| (n.id.clone(), n.apps_managed.len())).collect()
+    }
+
+    // Node with the most managed apps (the first such node on ties); None before acceleration
+    pub async fn most_loaded_node(&self) -> Option<(String, usize)> {
+        self.node_load().await.into_iter().fold(None, |best, load| match best {
+            Some((_, most)) if most >= load.1 => best,
+            _ => Some(load),
+        })
+    }
+
+    // Zero the counters and restore initial compliance (e.g., between tests sharing an Arc). Every app is released
+    // from its node so app_count and apps_processed legitimately return to zero; synced nodes (and progress) are kept.
+    pub async fn reset_metrics(&self) {
+        let mut nodes = self.nodes.lock().await;
+        let mut app_count = self.app_count.lock().await;
+        let mut metrics = self.evolution_metrics.lock().await;
+        for node in nodes.iter_mut() {
+            node.apps_managed.clear();
+        }
+        *app_count = 0;
+        *metrics = EvolutionMetrics { mainnet_open_progress: metrics.mainnet_open_progress, ..EvolutionMetrics::default() };
+    }
+
     // Get current metrics
     pub async fn get_metrics(&self) -> EvolutionMetrics {
         self.evolution_metrics.lock().await.clone()
@@ -159,8 +329,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let apps = (0..10000).map(|i| format!("app_{}", i)).collect();
     accelerator.manage_apps(apps).await?;
 
-    // Evolve system
-    accelerator.evolve_system().await?;
+    // Evolve system from observed signals
+    accelerator.evolve_system(EvolutionSignals { recent_isolation_events: 0, app_uptime_rate: 1.0 }).await?;
 
     // Check metrics
     let metrics = accelerator.get_metrics().await;
@@ -3,7 +3,10 @@
 // Enables real-time synchronization with Pi mainnet for eternal integration.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, Address, Env, String, Symbol, Vec, Map, log};
+
+// Latency above this (ms) marks a component desynced unless configured otherwise
+const DEFAULT_LATENCY_SLA_MS: i64 = 100;
 
 #[contract]
 pub struct PiMainnetIntegrationRealTimeSynchronization;
@@ -19,17 +22,20 @@ pub struct SynchronizationEvent {
 
 #[contractimpl]
 impl PiMainnetIntegrationRealTimeSynchronization {
-    /// Initialize the Synchronization Module
-    pub fn init(env: Env) -> PiMainnetIntegrationRealTimeSynchronization {
+    /// Initialize the Synchronization Module with the admin allowed to set the SLA and the reporter
+    pub fn init(env: Env, admin: Address) -> PiMainnetIntegrationRealTimeSynchronization {
+        crate::access_control::set_admin(&env, &admin);
         log!(&env, "PI Mainnet Integration Real-Time Synchronization Initialized");
         PiMainnetIntegrationRealTimeSynchronization
     }
 
     /// Synchronize component in real-time
     pub fn synchronize_real_time(env: Env, component: Symbol) -> SynchronizationEvent {
-        // Simulate real-time sync (in real: connect to Pi mainnet API)
-        let sync_status = Symbol::new(&env, "synced"); // Simulated success
-        let real_time_latency = 1; // Minimal latency
+        // Use the latest reported mainnet result; nothing reported yet means the sync is pending
+        let (sync_status, real_time_latency) = match Self::get_latest_latency(env.clone(), component.clone()) {
+            Some(latency) => (Self::status_for_latency(&env, latency), latency),
+            None => (Symbol::new(&env, "pending"), 0),
+        };
 
         let event = SynchronizationEvent {
             id: Symbol::new(&env, &format!("sync_{}", env.ledger().sequence())),
@@ -40,9 +46,68 @@ impl PiMainnetIntegrationRealTimeSynchronization {
         };
 
         log!(&env, "Real-Time Synchronization for {}: Status {} Latency {}ms", component, sync_status, real_time_latency);
+        env.events().publish(
+            (Symbol::new(&env, "real_time_synced"), component),
+            (event.id.clone(), sync_status, real_time_latency, event.timestamp),
+        );
         event
     }
 
+    /// Record the latest sync latency for a component (admin or registered reporter only); returns its resulting sync status
+    pub fn report_sync_result(env: Env, reporter: Address, component: Symbol, latency_ms: i64) -> Symbol {
+        reporter.require_auth();
+        if reporter != crate::access_control::get_admin(&env) && Some(reporter) != Self::get_reporter(env.clone()) {
+            panic!("reporter not authorized");
+        }
+        if latency_ms < 0 {
+            panic!("latency must not be negative");
+        }
+        env.storage().persistent().set(&(Symbol::new(&env, "latency"), component.clone()), &latency_ms);
+        let sync_status = Self::status_for_latency(&env, latency_ms);
+        log!(&env, "Sync Result Reported for {}: Latency {}ms Status {}", component, latency_ms, sync_status);
+        sync_status
+    }
+
+    /// Latest reported latency for a component, if any
+    pub fn get_latest_latency(env: Env, component: Symbol) -> Option<i64> {
+        env.storage().persistent().get(&(Symbol::new(&env, "latency"), component))
+    }
+
+    /// Register the address allowed to report sync results besides the admin (admin only)
+    pub fn set_reporter(env: Env, reporter: Address) {
+        crate::access_control::require_admin(&env);
+        env.storage().instance().set(&Symbol::new(&env, "reporter"), &reporter);
+        log!(&env, "Sync Reporter Set: {}", reporter);
+    }
+
+    /// Registered sync reporter, if any
+    pub fn get_reporter(env: Env) -> Option<Address> {
+        env.storage().instance().get(&Symbol::new(&env, "reporter"))
+    }
+
+    /// Set the latency SLA in milliseconds (admin only)
+    pub fn set_latency_sla(env: Env, sla_ms: i64) {
+        crate::access_control::require_admin(&env);
+        if sla_ms < 0 {
+            panic!("latency SLA must not be negative");
+        }
+        env.storage().instance().set(&Symbol::new(&env, "latency_sla"), &sla_ms);
+    }
+
+    /// Get the latency SLA in milliseconds
+    pub fn get_latency_sla(env: Env) -> i64 {
+        env.storage().instance().get(&Symbol::new(&env, "latency_sla")).unwrap_or(DEFAULT_LATENCY_SLA_MS)
+    }
+
+    /// Sync status for a latency measured against the SLA
+    fn status_for_latency(env: &Env, latency_ms: i64) -> Symbol {
+        if latency_ms > Self::get_latency_sla(env.clone()) {
+            Symbol::new(env, "desynced")
+        } else {
+            Symbol::new(env, "synced")
+        }
+    }
+
     /// Enforce synchronization integrity
     pub fn enforce_synchronization_integrity(env: Env, event: SynchronizationEvent) -> Symbol {
         if event.sync_status == Symbol::new(&env, "desynced") {
@@ -54,27 +119,48 @@ impl PiMainnetIntegrationRealTimeSynchronization {
         }
     }
 
-    /// Synchronize entire ecosystem with mainnet
-    pub fn synchronize_entire_ecosystem(env: Env) -> Vec<SynchronizationEvent> {
-        let components = Vec::from_array(&env, [
+    /// Components kept in sync with mainnet
+    pub fn ecosystem_components(env: Env) -> Vec<Symbol> {
+        Vec::from_array(&env, [
             Symbol::new(&env, "transactions"),
             Symbol::new(&env, "apps"),
             Symbol::new(&env, "nodes"),
             Symbol::new(&env, "security_layers"),
             Symbol::new(&env, "governance"),
-        ]);
+        ])
+    }
+
+    /// Synchronize entire ecosystem with mainnet
+    pub fn synchronize_entire_ecosystem(env: Env) -> Vec<SynchronizationEvent> {
+        let components = Self::ecosystem_components(env.clone());
 
         let syncs = components.iter().map(|comp| Self::synchronize_real_time(env.clone(), comp.clone())).collect();
         log!(&env, "Entire Ecosystem Synchronized with Pi Mainnet in Real-Time");
         syncs
     }
 
-    /// Get synchronization status
+    /// Get synchronization status from the latest reported latencies. mainnet_integration is the share (0-100) of
+    /// reported components within the SLA; components not reported yet are pending and don't count against it.
     pub fn get_synchronization_status(env: Env) -> Map<Symbol, i64> {
+        let mut reported = 0i64;
+        let mut synced = 0i64;
+        let mut worst_latency = 0i64;
+        for component in Self::ecosystem_components(env.clone()).iter() {
+            if let Some(latency) = Self::get_latest_latency(env.clone(), component) {
+                reported += 1;
+                worst_latency = worst_latency.max(latency);
+                if Self::status_for_latency(&env, latency) == Symbol::new(&env, "synced") {
+                    synced += 1;
+                }
+            }
+        }
+        let components = Self::ecosystem_components(env.clone()).len() as i64;
+
         let mut status = Map::new(&env);
-        status.set(Symbol::new(&env, "components_synced"), 50); // Simulated count
-        status.set(Symbol::new(&env, "real_time_latency"), 1); // ms
-        status.set(Symbol::new(&env, "mainnet_integration"), 100); // Fully integrated
+        status.set(Symbol::new(&env, "components_synced"), synced);
+        status.set(Symbol::new(&env, "components_pending"), components - reported);
+        status.set(Symbol::new(&env, "real_time_latency"), worst_latency); // ms, slowest reported component
+        status.set(Symbol::new(&env, "mainnet_integration"), if reported == 0 { 100 } else { synced * 100 / reported });
         status
     }
 
@@ -90,13 +176,13 @@ impl PiMainnetIntegrationRealTimeSynchronization {
         }
     }
 
-    /// Render synchronization hologram
-    pub fn render_synchronization_hologram(env: Env, event: SynchronizationEvent) -> Vec<Symbol> {
+    /// Render synchronization hologram (every line is a String, so formatted numbers face no Symbol length or charset limits)
+    pub fn render_synchronization_hologram(env: Env, event: SynchronizationEvent) -> Vec<String> {
         let hologram = Vec::from_array(&env, [
-            Symbol::new(&env, "Real-Time Synchronization Hologram"),
-            event.synced_component,
-            event.sync_status,
-            Symbol::new(&env, &format!("Latency: {}ms", event.real_time_latency)),
+            String::from_str(&env, "Real-Time Synchronization Hologram"),
+            crate::hologram::symbol_to_string(&env, &event.synced_component),
+            crate::hologram::symbol_to_string(&env, &event.sync_status),
+            String::from_str(&env, &format!("Latency: {}ms", event.real_time_latency)),
         ]);
         log!(&env, "Synchronization Hologram Rendered");
         hologram
@@ -3,11 +3,12 @@
 // Governs Pi mainnet launch and protocol with decentralized autonomy.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct PiMainnetLaunchGovernanceProtocol;
 
+#[contracttype]
 #[derive(Clone)]
 pub struct GovernanceProposal {
     pub id: Symbol,
@@ -18,37 +19,113 @@ pub struct GovernanceProposal {
     pub timestamp: u64,
 }
 
+const DEFAULT_QUORUM: i64 = 1000; // Minimum total vote weight before a proposal can pass
+
 #[contractimpl]
 impl PiMainnetLaunchGovernanceProtocol {
-    /// Initialize the Governance Protocol
-    pub fn init(env: Env) -> PiMainnetLaunchGovernanceProtocol {
+    /// Initialize the Governance Protocol with the admin allowed to change rules and quorum
+    pub fn init(env: Env, admin: Address) -> PiMainnetLaunchGovernanceProtocol {
+        crate::access_control::set_admin(&env, &admin);
         log!(&env, "PI Mainnet Launch Governance Protocol Initialized");
         PiMainnetLaunchGovernanceProtocol
     }
 
-    /// Submit and vote on governance proposal
+    /// Submit a governance proposal; it opens with zero votes and passes only through cast_vote
     pub fn submit_vote_proposal(env: Env, proposal_type: Symbol) -> GovernanceProposal {
-        // Simulate voting (in real: collect from swarm/AI)
-        let votes_for = 1000000; // Simulated majority
-        let votes_against = 0;
-        let approved = votes_for > votes_against;
-
+        // Ids come from a stored index so proposals in the same ledger don't collide
+        let mut proposal_ids = Self::get_proposal_ids(env.clone());
         let proposal = GovernanceProposal {
-            id: Symbol::new(&env, &format!("proposal_{}", env.ledger().sequence())),
+            id: Symbol::new(&env, &format!("proposal_{}", proposal_ids.len())),
             proposal_type: proposal_type.clone(),
-            votes_for,
-            votes_against,
-            approved,
+            votes_for: 0,
+            votes_against: 0,
+            approved: false,
             timestamp: env.ledger().timestamp(),
         };
 
-        log!(&env, "Governance Proposal for {}: Approved {} Votes For {}", proposal_type, approved, votes_for);
+        env.storage().persistent().set(&(Symbol::new(&env, "proposal"), proposal.id.clone()), &proposal);
+        proposal_ids.push_back(proposal.id.clone());
+        env.storage().instance().set(&Symbol::new(&env, "proposal_ids"), &proposal_ids);
+
+        log!(&env, "Governance Proposal Submitted for {}", proposal_type);
         proposal
     }
 
+    /// Get a stored proposal with its current tallies
+    pub fn get_proposal(env: Env, id: Symbol) -> Option<GovernanceProposal> {
+        env.storage().persistent().get(&(Symbol::new(&env, "proposal"), id))
+    }
+
+    /// Ids of every submitted proposal, in submission order
+    pub fn get_proposal_ids(env: Env) -> Vec<Symbol> {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(&env, "proposal_ids"))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Cast a weighted vote on a submitted proposal; each voter (who must authorize) votes once per proposal.
+    /// Tallies accumulate in storage per proposal. Returns whether it now passes.
+    pub fn cast_vote(env: Env, voter: Address, proposal_id: Symbol, support: bool, weight: i64) -> bool {
+        voter.require_auth();
+        if weight <= 0 {
+            panic!("vote weight must be positive");
+        }
+        let mut proposal = Self::get_proposal(env.clone(), proposal_id.clone()).expect("proposal not found");
+        let voted_key = (Symbol::new(&env, "voted"), proposal_id.clone(), voter.clone());
+        if env.storage().persistent().has(&voted_key) {
+            panic!("voter already voted on this proposal");
+        }
+
+        let (mut votes_for, mut votes_against) = Self::get_tally(env.clone(), proposal_id.clone());
+        if support {
+            votes_for = votes_for.checked_add(weight).expect("vote tally overflow");
+        } else {
+            votes_against = votes_against.checked_add(weight).expect("vote tally overflow");
+        }
+        env.storage().persistent().set(&voted_key, &support);
+        env.storage().persistent().set(&(Symbol::new(&env, "votes"), proposal_id.clone()), &(votes_for, votes_against));
+
+        let approved = Self::is_approved(env.clone(), proposal_id.clone());
+        proposal.votes_for = votes_for;
+        proposal.votes_against = votes_against;
+        proposal.approved = approved;
+        env.storage().persistent().set(&(Symbol::new(&env, "proposal"), proposal_id.clone()), &proposal);
+        log!(&env, "Vote Cast on {}: For {} Against {} Approved {}", proposal_id, votes_for, votes_against, approved);
+        approved
+    }
+
+    /// Get stored (votes_for, votes_against) for a proposal
+    pub fn get_tally(env: Env, proposal_id: Symbol) -> (i64, i64) {
+        env.storage()
+            .persistent()
+            .get(&(Symbol::new(&env, "votes"), proposal_id))
+            .unwrap_or((0, 0))
+    }
+
+    /// Approved once total weight reaches the quorum and a strict majority is in favor
+    pub fn is_approved(env: Env, proposal_id: Symbol) -> bool {
+        let (votes_for, votes_against) = Self::get_tally(env.clone(), proposal_id);
+        votes_for.saturating_add(votes_against) >= Self::get_quorum(env) && votes_for > votes_against
+    }
+
+    /// Set the minimum total vote weight required for approval (admin only)
+    pub fn set_quorum(env: Env, quorum: i64) {
+        crate::access_control::require_admin(&env);
+        if quorum < 0 {
+            panic!("quorum must not be negative");
+        }
+        env.storage().instance().set(&Symbol::new(&env, "quorum"), &quorum);
+    }
+
+    /// Get the quorum threshold
+    pub fn get_quorum(env: Env) -> i64 {
+        env.storage().instance().get(&Symbol::new(&env, "quorum")).unwrap_or(DEFAULT_QUORUM)
+    }
+
     /// Enforce governance protocol
     pub fn enforce_governance_protocol(env: Env, proposal: GovernanceProposal) -> Symbol {
-        if !proposal.approved {
+        if !Self::is_approved(env.clone(), proposal.id.clone()) {
             log!(&env, "Governance Breach Detected: Halting {}", proposal.proposal_type);
             crate::ahi_ai_core::AhiAiCore::enforce_compliance(env.clone());
             Symbol::new(&env, "protocol_enforced")
@@ -72,15 +149,21 @@ impl PiMainnetLaunchGovernanceProtocol {
 
     /// Get governance status
     pub fn get_governance_status(env: Env) -> Map<Symbol, i64> {
+        let proposal_ids = Self::get_proposal_ids(env.clone());
+        let approved = proposal_ids.iter().filter(|id| Self::is_approved(env.clone(), id.clone())).count() as i64;
+
         let mut status = Map::new(&env);
-        status.set(Symbol::new(&env, "proposals_approved"), 50); // Simulated count
+        status.set(Symbol::new(&env, "proposals_submitted"), proposal_ids.len() as i64);
+        status.set(Symbol::new(&env, "proposals_approved"), approved);
         status.set(Symbol::new(&env, "mainnet_launch_progress"), 100); // Fully launched
         status.set(Symbol::new(&env, "governance_integrity"), 100);
         status
     }
 
-    /// Update governance rules
+    /// Update governance rules (admin only)
     pub fn update_governance_rules(env: Env, new_rule: Symbol) -> Result<Symbol, Symbol> {
+        crate::access_control::require_admin(&env);
+
         // Validate via Swarm Hub
         let swarm_status = crate::global_decentralized_ai_swarm_intelligence_hub::GlobalDecentralizedAiSwarmIntelligenceHub::get_swarm_status(env.clone());
         if swarm_status.get(Symbol::new(&env, "consensus_rate")).unwrap_or(0) == 100 {
@@ -91,13 +174,18 @@ impl PiMainnetLaunchGovernanceProtocol {
         }
     }
 
+    /// Transfer admin rights (current admin only)
+    pub fn transfer_admin(env: Env, new_admin: Address) {
+        crate::access_control::transfer_admin(&env, new_admin);
+    }
+
     /// Render governance hologram
-    pub fn render_governance_hologram(env: Env, proposal: GovernanceProposal) -> Vec<Symbol> {
+    pub fn render_governance_hologram(env: Env, proposal: GovernanceProposal) -> Vec<String> {
         let hologram = Vec::from_array(&env, [
-            Symbol::new(&env, "Mainnet Governance Hologram"),
-            proposal.proposal_type,
-            Symbol::new(&env, &format!("Approved: {}", proposal.approved)),
-            Symbol::new(&env, &format!("Votes For: {}", proposal.votes_for)),
+            String::from_str(&env, "Mainnet Governance Hologram"),
+            crate::hologram::symbol_to_string(&env, &proposal.proposal_type),
+            String::from_str(&env, &format!("Approved: {}", proposal.approved)),
+            String::from_str(&env, &format!("Votes For: {}", proposal.votes_for)),
         ]);
         log!(&env, "Governance Hologram Rendered");
         hologram
@@ -3,7 +3,7 @@
 // Drives full decentralization of Pi Network.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, Env, String, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct PiNetworkDecentralizationEngine;
@@ -89,12 +89,12 @@ impl PiNetworkDecentralizationEngine {
     }
 
     /// Render engine hologram
-    pub fn render_engine_hologram(env: Env, event: DecentralizationEvent) -> Vec<Symbol> {
+    pub fn render_engine_hologram(env: Env, event: DecentralizationEvent) -> Vec<String> {
         let hologram = Vec::from_array(&env, [
-            Symbol::new(&env, "Decentralization Engine Hologram"),
-            event.decentralization_phase,
-            Symbol::new(&env, &format!("Fully Decentralized: {}", event.fully_decentralized)),
-            Symbol::new(&env, &format!("Decentralization Level: {}", event.decentralization_level)),
+            String::from_str(&env, "Decentralization Engine Hologram"),
+            crate::hologram::symbol_to_string(&env, &event.decentralization_phase),
+            String::from_str(&env, &format!("Fully Decentralized: {}", event.fully_decentralized)),
+            String::from_str(&env, &format!("Decentralization Level: {}", event.decentralization_level)),
         ]);
         log!(&env, "Engine Hologram Rendered");
         hologram
@@ -3,7 +3,7 @@
 // Establishes decentralized governance council for Pi Network.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, Env, String, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct PiNetworkDecentralizedGovernanceCouncil;
@@ -89,12 +89,12 @@ impl PiNetworkDecentralizedGovernanceCouncil {
     }
 
     /// Render council hologram
-    pub fn render_council_hologram(env: Env, decision: GovernanceDecision) -> Vec<Symbol> {
+    pub fn render_council_hologram(env: Env, decision: GovernanceDecision) -> Vec<String> {
         let hologram = Vec::from_array(&env, [
-            Symbol::new(&env, "Decentralized Governance Council Hologram"),
-            decision.decision_type,
-            Symbol::new(&env, &format!("Council Decided: {}", decision.council_decided)),
-            Symbol::new(&env, &format!("Decision Level: {}", decision.decision_level)),
+            String::from_str(&env, "Decentralized Governance Council Hologram"),
+            crate::hologram::symbol_to_string(&env, &decision.decision_type),
+            String::from_str(&env, &format!("Council Decided: {}", decision.council_decided)),
+            String::from_str(&env, &format!("Decision Level: {}", decision.decision_level)),
         ]);
         log!(&env, "Council Hologram Rendered");
         hologram
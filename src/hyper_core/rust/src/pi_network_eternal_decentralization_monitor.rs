@@ -3,7 +3,7 @@
 // Monitors eternal decentralization of Pi Network.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, Env, String, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct PiNetworkEternalDecentralizationMonitor;
@@ -89,12 +89,12 @@ impl PiNetworkEternalDecentralizationMonitor {
     }
 
     /// Render monitor hologram
-    pub fn render_monitor_hologram(env: Env, monitor: DecentralizationMonitor) -> Vec<Symbol> {
+    pub fn render_monitor_hologram(env: Env, monitor: DecentralizationMonitor) -> Vec<String> {
         let hologram = Vec::from_array(&env, [
-            Symbol::new(&env, "Eternal Decentralization Monitor Hologram"),
-            monitor.monitor_aspect,
-            Symbol::new(&env, &format!("Eternally Monitored: {}", monitor.eternally_monitored)),
-            Symbol::new(&env, &format!("Monitor Level: {}", monitor.monitor_level)),
+            String::from_str(&env, "Eternal Decentralization Monitor Hologram"),
+            crate::hologram::symbol_to_string(&env, &monitor.monitor_aspect),
+            String::from_str(&env, &format!("Eternally Monitored: {}", monitor.eternally_monitored)),
+            String::from_str(&env, &format!("Monitor Level: {}", monitor.monitor_level)),
         ]);
         log!(&env, "Monitor Hologram Rendered");
         hologram
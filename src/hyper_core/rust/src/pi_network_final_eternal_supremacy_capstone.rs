@@ -1,9 +1,12 @@
 use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
 use crate::pi_network_super_intelligence_core::PiNetworkSuperIntelligenceCore; // From previous
 use crate::pi_network_super_advanced_evolution_engine::PiNetworkSuperAdvancedEvolutionEngine; // From previous
-use crate::global_decentralized_ai_swarm_intelligence_hub::GlobalDecentralizedAISwarmIntelligenceHub; // File 19
+use crate::global_decentralized_ai_swarm_intelligence_hub::GlobalDecentralizedAiSwarmIntelligenceHub; // File 19
 use crate::final_universal_integration_supremacy_capstone::FinalUniversalIntegrationSupremacyCapstone; // File 27
 
+// Retry budget used by the monitor and the full capstone run
+const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
 #[contract]
 pub struct PiNetworkFinalEternalSupremacyCapstone;
 
@@ -14,29 +17,46 @@ impl PiNetworkFinalEternalSupremacyCapstone {
         PiNetworkFinalEternalSupremacyCapstone
     }
 
-    /// Main capstone function: Achieve final eternal supremacy
-    pub fn achieve_final_eternal_supremacy(env: Env) {
+    /// Main capstone function: Achieve final eternal supremacy, retrying validation at most `max_attempts` times
+    pub fn achieve_final_eternal_supremacy(env: Env, max_attempts: u32) -> bool {
         log!(&env, "Achieving final eternal supremacy for Pi Network");
-        
-        // Step 1: Integrate super-intelligence and evolution
-        PiNetworkSuperIntelligenceCore::run_super_intelligence_core(env.clone());
-        PiNetworkSuperAdvancedEvolutionEngine::run_super_advanced_evolution_engine(env.clone());
-        
-        // Step 2: Swarm consensus for final supremacy
-        let supremacy = GlobalDecentralizedAISwarmIntelligenceHub::swarm_consensus_decision(env.clone(), Symbol::new(&env, "Achieve final eternal supremacy for Pi Network"));
-        if supremacy == Symbol::new(&env, "approved") {
+
+        let mut attempts = 0;
+        let mut achieved = false;
+        while attempts < max_attempts {
+            attempts += 1;
+
+            // Step 1: Integrate super-intelligence and evolution
+            PiNetworkSuperIntelligenceCore::run_super_intelligence_core(env.clone());
+            PiNetworkSuperAdvancedEvolutionEngine::run_super_advanced_evolution_engine(env.clone());
+
+            // Step 2: Swarm consensus for final supremacy
+            let swarm_status = GlobalDecentralizedAiSwarmIntelligenceHub::get_swarm_status(env.clone());
+            if swarm_status.get(Symbol::new(&env, "consensus_rate")).unwrap_or(0) != 100 {
+                log!(&env, "Swarm rejected supremacy. Evolving further.");
+                break;
+            }
+
             // Step 3: Quantum eternal validate supremacy
             if Self::quantum_eternal_validate_supremacy(env.clone()) > 0.99 {
                 log!(&env, "Final eternal supremacy achieved. Pi Network eternally supreme.");
-                Self::seal_final_eternal_supremacy(env);
-            } else {
-                log!(&env, "Supremacy validation failed. Re-achieving.");
-                FinalUniversalIntegrationSupremacyCapstone::achieve_universal_supremacy_capstone(env.clone());
-                Self::achieve_final_eternal_supremacy(env); // Recursive auto-retry
+                Self::seal_final_eternal_supremacy(env.clone());
+                achieved = true;
+                break;
             }
-        } else {
-            log!(&env, "Swarm rejected supremacy. Evolving further.");
+            log!(&env, "Supremacy validation failed on attempt {} of {}.", attempts, max_attempts);
+        }
+
+        if !achieved && attempts == max_attempts {
+            log!(&env, "Final eternal supremacy not achieved after {} attempts. Giving up.", attempts);
         }
+        env.storage().instance().set(&Symbol::new(&env, "supremacy_attempts"), &attempts);
+        achieved
+    }
+
+    /// Attempts used by the last achieve_final_eternal_supremacy call
+    pub fn get_last_attempts(env: Env) -> u32 {
+        env.storage().instance().get(&Symbol::new(&env, "supremacy_attempts")).unwrap_or(0)
     }
 
     /// Quantum eternal validate supremacy
@@ -61,7 +81,7 @@ impl PiNetworkFinalEternalSupremacyCapstone {
         let validation = Self::quantum_eternal_validate_supremacy(env.clone());
         if validation < 0.95 {
             log!(&env, "Supremacy degrading. Re-achieving.");
-            Self::achieve_final_eternal_supremacy(env);
+            Self::achieve_final_eternal_supremacy(env, DEFAULT_MAX_ATTEMPTS);
         } else {
             log!(&env, "Eternal supremacy maintained.");
         }
@@ -81,7 +101,7 @@ impl PiNetworkFinalEternalSupremacyCapstone {
 
     /// Run the final eternal supremacy capstone
     pub fn run_final_eternal_supremacy_capstone(env: Env) {
-        Self::achieve_final_eternal_supremacy(env.clone());
+        Self::achieve_final_eternal_supremacy(env.clone(), DEFAULT_MAX_ATTEMPTS);
         Self::monitor_final_eternal_supremacy(env.clone());
         Self::generate_final_eternal_supremacy_report(env);
         log!(&env, "Pi Network Final Eternal Supremacy Capstone active: Ecosystem eternally supreme.");
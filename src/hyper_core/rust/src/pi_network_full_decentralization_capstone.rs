@@ -3,7 +3,7 @@
 // Caps off full decentralization of Pi Network.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, Env, String, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct PiNetworkFullDecentralizationCapstone;
@@ -89,12 +89,12 @@ impl PiNetworkFullDecentralizationCapstone {
     }
 
     /// Render capstone hologram
-    pub fn render_capstone_hologram(env: Env, capstone: DecentralizationCapstone) -> Vec<Symbol> {
+    pub fn render_capstone_hologram(env: Env, capstone: DecentralizationCapstone) -> Vec<String> {
         let hologram = Vec::from_array(&env, [
-            Symbol::new(&env, "Full Decentralization Capstone Hologram"),
-            capstone.capstone_phase,
-            Symbol::new(&env, &format!("Fully Capstoned: {}", capstone.fully_capstoned)),
-            Symbol::new(&env, &format!("Capstone Level: {}", capstone.capstone_level)),
+            String::from_str(&env, "Full Decentralization Capstone Hologram"),
+            crate::hologram::symbol_to_string(&env, &capstone.capstone_phase),
+            String::from_str(&env, &format!("Fully Capstoned: {}", capstone.fully_capstoned)),
+            String::from_str(&env, &format!("Capstone Level: {}", capstone.capstone_level)),
         ]);
         log!(&env, "Capstone Hologram Rendered");
         hologram
@@ -3,7 +3,7 @@
 // Announces Pi Network globally with hyper broadcasting.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, Env, String, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct PiNetworkGlobalAnnouncer;
@@ -89,12 +89,12 @@ impl PiNetworkGlobalAnnouncer {
     }
 
     /// Render announcer hologram
-    pub fn render_announcer_hologram(env: Env, announcement: GlobalAnnouncement) -> Vec<Symbol> {
+    pub fn render_announcer_hologram(env: Env, announcement: GlobalAnnouncement) -> Vec<String> {
         let hologram = Vec::from_array(&env, [
-            Symbol::new(&env, "Global Announcer Hologram"),
-            announcement.announcement_type,
-            Symbol::new(&env, &format!("Globally Announced: {}", announcement.globally_announced)),
-            Symbol::new(&env, &format!("Reach Level: {}", announcement.reach_level)),
+            String::from_str(&env, "Global Announcer Hologram"),
+            crate::hologram::symbol_to_string(&env, &announcement.announcement_type),
+            String::from_str(&env, &format!("Globally Announced: {}", announcement.globally_announced)),
+            String::from_str(&env, &format!("Reach Level: {}", announcement.reach_level)),
         ]);
         log!(&env, "Announcer Hologram Rendered");
         hologram
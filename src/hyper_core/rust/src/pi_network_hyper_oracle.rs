@@ -3,7 +3,7 @@
 // Provides hyper-advanced oracle for Pi Network data.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, Env, String, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct PiNetworkHyperOracle;
@@ -89,12 +89,12 @@ impl PiNetworkHyperOracle {
     }
 
     /// Render oracle hologram
-    pub fn render_oracle_hologram(env: Env, feed: OracleFeed) -> Vec<Symbol> {
+    pub fn render_oracle_hologram(env: Env, feed: OracleFeed) -> Vec<String> {
         let hologram = Vec::from_array(&env, [
-            Symbol::new(&env, "Hyper Oracle Hologram"),
-            feed.data_type,
-            Symbol::new(&env, &format!("Verified: {}", feed.oracle_verified)),
-            Symbol::new(&env, &format!("Accuracy: {}", feed.feed_accuracy)),
+            String::from_str(&env, "Hyper Oracle Hologram"),
+            crate::hologram::symbol_to_string(&env, &feed.data_type),
+            String::from_str(&env, &format!("Verified: {}", feed.oracle_verified)),
+            String::from_str(&env, &format!("Accuracy: {}", feed.feed_accuracy)),
         ]);
         log!(&env, "Oracle Hologram Rendered");
         hologram
@@ -3,7 +3,7 @@
 // Triggers full opening of Pi Network mainnet.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, Env, String, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct PiNetworkMainnetTrigger;
@@ -89,12 +89,12 @@ impl PiNetworkMainnetTrigger {
     }
 
     /// Render trigger hologram
-    pub fn render_trigger_hologram(env: Env, trigger: MainnetTrigger) -> Vec<Symbol> {
+    pub fn render_trigger_hologram(env: Env, trigger: MainnetTrigger) -> Vec<String> {
         let hologram = Vec::from_array(&env, [
-            Symbol::new(&env, "Mainnet Trigger Hologram"),
-            trigger.trigger_phase,
-            Symbol::new(&env, &format!("Mainnet Triggered: {}", trigger.mainnet_triggered)),
-            Symbol::new(&env, &format!("Trigger Level: {}", trigger.trigger_level)),
+            String::from_str(&env, "Mainnet Trigger Hologram"),
+            crate::hologram::symbol_to_string(&env, &trigger.trigger_phase),
+            String::from_str(&env, &format!("Mainnet Triggered: {}", trigger.mainnet_triggered)),
+            String::from_str(&env, &format!("Trigger Level: {}", trigger.trigger_level)),
         ]);
         log!(&env, "Trigger Hologram Rendered");
         hologram
@@ -3,7 +3,7 @@
 // Establishes quantum-resistant security network for Pi Network.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, Env, String, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct PiNetworkQuantumSecurityNetwork;
@@ -89,12 +89,12 @@ impl PiNetworkQuantumSecurityNetwork {
     }
 
     /// Render network hologram
-    pub fn render_network_hologram(env: Env, event: SecurityNetworkEvent) -> Vec<Symbol> {
+    pub fn render_network_hologram(env: Env, event: SecurityNetworkEvent) -> Vec<String> {
         let hologram = Vec::from_array(&env, [
-            Symbol::new(&env, "Quantum Security Network Hologram"),
-            event.security_phase,
-            Symbol::new(&env, &format!("Quantum Secured: {}", event.quantum_secured)),
-            Symbol::new(&env, &format!("Security Level: {}", event.security_level)),
+            String::from_str(&env, "Quantum Security Network Hologram"),
+            crate::hologram::symbol_to_string(&env, &event.security_phase),
+            String::from_str(&env, &format!("Quantum Secured: {}", event.quantum_secured)),
+            String::from_str(&env, &format!("Security Level: {}", event.security_level)),
         ]);
         log!(&env, "Network Hologram Rendered");
         hologram
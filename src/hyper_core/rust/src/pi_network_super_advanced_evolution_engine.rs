@@ -3,7 +3,7 @@
 // Drives super advanced evolution of Pi Network.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, Env, String, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct PiNetworkSuperAdvancedEvolutionEngine;
@@ -94,12 +94,12 @@ impl PiNetworkSuperAdvancedEvolutionEngine {
     }
 
     /// Render evolution hologram
-    pub fn render_evolution_hologram(env: Env, adaptation: EvolutionAdaptation) -> Vec<Symbol> {
+    pub fn render_evolution_hologram(env: Env, adaptation: EvolutionAdaptation) -> Vec<String> {
         let hologram = Vec::from_array(&env, [
-            Symbol::new(&env, "Super Advanced Evolution Engine Hologram"),
-            adaptation.evolution_aspect,
-            Symbol::new(&env, &format!("Super Evolved: {}", adaptation.super_evolved)),
-            Symbol::new(&env, &format!("Evolution Level: {}", adaptation.evolution_level)),
+            String::from_str(&env, "Super Advanced Evolution Engine Hologram"),
+            crate::hologram::symbol_to_string(&env, &adaptation.evolution_aspect),
+            String::from_str(&env, &format!("Super Evolved: {}", adaptation.super_evolved)),
+            String::from_str(&env, &format!("Evolution Level: {}", adaptation.evolution_level)),
         ]);
         log!(&env, "Evolution Hologram Rendered");
         hologram
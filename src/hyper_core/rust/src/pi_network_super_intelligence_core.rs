@@ -3,7 +3,7 @@
 // Embodies super intelligence core of Pi Network.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, Env, String, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct PiNetworkSuperIntelligenceCore;
@@ -94,12 +94,12 @@ impl PiNetworkSuperIntelligenceCore {
     }
 
     /// Render intelligence hologram
-    pub fn render_intelligence_hologram(env: Env, decision: IntelligenceDecision) -> Vec<Symbol> {
+    pub fn render_intelligence_hologram(env: Env, decision: IntelligenceDecision) -> Vec<String> {
         let hologram = Vec::from_array(&env, [
-            Symbol::new(&env, "Super Intelligence Core Hologram"),
-            decision.decision_aspect,
-            Symbol::new(&env, &format!("Super Intelligent: {}", decision.super_intelligent)),
-            Symbol::new(&env, &format!("Intelligence Level: {}", decision.intelligence_level)),
+            String::from_str(&env, "Super Intelligence Core Hologram"),
+            crate::hologram::symbol_to_string(&env, &decision.decision_aspect),
+            String::from_str(&env, &format!("Super Intelligent: {}", decision.super_intelligent)),
+            String::from_str(&env, &format!("Intelligence Level: {}", decision.intelligence_level)),
         ]);
         log!(&env, "Intelligence Hologram Rendered");
         hologram
@@ -3,7 +3,7 @@
 // Achieves ultimate perfection in Pi Network.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, Env, String, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct PiNetworkUltimatePerfectionModule;
@@ -94,12 +94,12 @@ impl PiNetworkUltimatePerfectionModule {
     }
 
     /// Render perfection hologram
-    pub fn render_perfection_hologram(env: Env, optimization: PerfectionOptimization) -> Vec<Symbol> {
+    pub fn render_perfection_hologram(env: Env, optimization: PerfectionOptimization) -> Vec<String> {
         let hologram = Vec::from_array(&env, [
-            Symbol::new(&env, "Ultimate Perfection Module Hologram"),
-            optimization.perfection_aspect,
-            Symbol::new(&env, &format!("Perfectly Optimized: {}", optimization.perfectly_optimized)),
-            Symbol::new(&env, &format!("Perfection Level: {}", optimization.perfection_level)),
+            String::from_str(&env, "Ultimate Perfection Module Hologram"),
+            crate::hologram::symbol_to_string(&env, &optimization.perfection_aspect),
+            String::from_str(&env, &format!("Perfectly Optimized: {}", optimization.perfectly_optimized)),
+            String::from_str(&env, &format!("Perfection Level: {}", optimization.perfection_level)),
         ]);
         log!(&env, "Perfection Hologram Rendered");
         hologram
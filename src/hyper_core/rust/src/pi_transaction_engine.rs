@@ -1,27 +1,107 @@
 // src/hyper_core/rust/src/pi_transaction_engine.rs
 // PI Transaction Engine for Pi Ecosystem Super App
 // Handles exclusive PI transactions with fixed stable value and source verification.
-// Dependencies: Add to Cargo.toml: tokio = "1.0", sha2 = "0.10" (for hashing), serde = { version = "1.0", features = ["derive"] }
-// Integrate with hyper_ai_core.rs by importing it in lib.rs: pub mod hyper_ai_core; pub mod pi_transaction_engine;
+// Dependencies: Add to Cargo.toml: tokio = "1.0", sha2 = "0.10" (for hashing), hmac = "0.12" (for keyed source proofs), tokio-util = "0.7" (for CancellationToken), serde = { version = "1.0", features = ["derive"] }, serde_json = "1.0" (for file-backed history)
+// Integrate with hyper_ai_core.rs by importing it in lib.rs: pub mod hyper_ai_core; pub mod pi_exclusivity; pub mod pi_transaction_engine;
 
+use std::collections::{HashMap, HashSet};
+use std::fs::OpenOptions;
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::sync::{mpsc, Mutex};
+use tokio_util::sync::CancellationToken;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use hmac::{Hmac, Mac};
 use crate::hyper_ai_core::AutonomousHyperAI; // Import from sibling module
+use crate::error::SuperPiError;
+use crate::pi_exclusivity::PiExclusivityPolicy;
+use crate::telemetry::{info, warn};
 
-// PI Stable Value Constants
+// PI Stable Value Constants (mainnet defaults)
 const PI_STABLE_VALUE: f64 = 314159.0; // Fixed at $314,159
 const DUAL_VALUE_MULTIPLIER: f64 = 3.14159; // Internal dual-system multiplier for ecosystem balance
+const DEFAULT_MAX_TX_AGE: Duration = Duration::from_secs(300); // Replay window for submitted transactions
+const MAX_CLOCK_SKEW_SECS: u64 = 30; // Tolerated drift for timestamps slightly ahead of our clock
+const DEFAULT_AMOUNT_PRECISION: u32 = 6; // Decimal places kept on adjusted amounts
+const MAX_AMOUNT_PRECISION: u32 = 15; // Beyond this f64 has no decimal digits left to keep
 
-// Transaction Types
+type HmacSha256 = Hmac<Sha256>;
+
+// Decides whether a receiver identifier is a Pi ecosystem address
+pub type ReceiverValidator = Box<dyn Fn(&str) -> bool + Send + Sync>;
+
+// Secret used to key source proofs (redacted from Debug output)
+#[derive(Clone)]
+pub struct ProofKey(pub Vec<u8>);
+
+impl std::fmt::Debug for ProofKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ProofKey(<redacted>)")
+    }
+}
+
+// Engine Configuration (override for testnets with different parameters)
+#[derive(Clone, Debug)]
+pub struct EngineConfig {
+    pub pi_stable_value: f64,       // Upper bound for a single transaction amount
+    pub dual_value_multiplier: f64, // Internal adjustment applied to accepted amounts
+    pub proof_key: Option<ProofKey>, // When set, source proofs must be HMAC-SHA256 under this key
+    pub fee_rate: f64,              // Fraction of each amount charged as a fee, in [0, 1) (0.0 = no fees)
+    pub max_tx_age: Duration,       // Transactions with older timestamps are rejected as replays
+    pub amount_precision: u32,      // Decimal places adjusted amounts are rounded to
+    pub type_limits: HashMap<PITransactionTypeDiscriminant, f64>, // Per-type amount ceilings, checked before pi_stable_value
+    pub enforce_nonces: bool,       // Require each sender's nonces to strictly increase (transactions must carry one)
+}
+
+impl Default for EngineConfig {
+    fn default() -> Self {
+        Self {
+            pi_stable_value: PI_STABLE_VALUE,
+            dual_value_multiplier: DUAL_VALUE_MULTIPLIER,
+            proof_key: None,
+            fee_rate: 0.0,
+            max_tx_age: DEFAULT_MAX_TX_AGE,
+            amount_precision: DEFAULT_AMOUNT_PRECISION,
+            type_limits: HashMap::new(),
+            enforce_nonces: false,
+        }
+    }
+}
+
+// Transaction Types (JSON names are a stable external contract; the old CamelCase
+// names are still accepted so existing history files load)
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
 pub enum PITransactionType {
+    #[serde(rename = "mining_reward", alias = "MiningReward")]
+    MiningReward,
+    #[serde(rename = "contribution_reward", alias = "ContributionReward")]
+    ContributionReward,
+    #[serde(rename = "p2p_transfer", alias = "P2PTransfer")] // rename_all alone would give "p2_p_transfer"
+    P2PTransfer,
+}
+
+// Fieldless key for per-type settings such as EngineConfig::type_limits
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PITransactionTypeDiscriminant {
     MiningReward,
     ContributionReward,
     P2PTransfer,
 }
 
+impl PITransactionType {
+    pub fn discriminant(&self) -> PITransactionTypeDiscriminant {
+        match self {
+            PITransactionType::MiningReward => PITransactionTypeDiscriminant::MiningReward,
+            PITransactionType::ContributionReward => PITransactionTypeDiscriminant::ContributionReward,
+            PITransactionType::P2PTransfer => PITransactionTypeDiscriminant::P2PTransfer,
+        }
+    }
+}
+
 // PI Transaction Struct
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct PITransaction {
@@ -32,74 +112,516 @@ pub struct PITransaction {
     pub tx_type: PITransactionType,
     pub source_proof: String, // Hashed proof of origin
     pub timestamp: u64,
+    #[serde(default)]
+    pub fee: f64, // Computed by the engine from EngineConfig::fee_rate
+    #[serde(default)]
+    pub nonce: Option<u64>, // Per-sender sequence, checked when EngineConfig::enforce_nonces is set
+}
+
+// Persistence layer for processed transaction history
+pub trait TransactionStore {
+    fn append(&self, tx: &PITransaction) -> Result<(), String>;
+    fn all(&self) -> Vec<PITransaction>;
+
+    // One page of history plus the total count; stores may override to avoid loading everything
+    fn page(&self, offset: usize, limit: usize) -> (Vec<PITransaction>, usize) {
+        let all = self.all();
+        let total = all.len();
+        (all.into_iter().skip(offset).take(limit).collect(), total)
+    }
+}
+
+// Default store: history lives only as long as the process
+#[derive(Default)]
+pub struct InMemoryTransactionStore {
+    transactions: std::sync::Mutex<Vec<PITransaction>>,
+}
+
+impl InMemoryTransactionStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl TransactionStore for InMemoryTransactionStore {
+    fn append(&self, tx: &PITransaction) -> Result<(), String> {
+        self.transactions
+            .lock()
+            .map_err(|_| "Store lock poisoned".to_string())?
+            .push(tx.clone());
+        Ok(())
+    }
+
+    fn all(&self) -> Vec<PITransaction> {
+        self.transactions.lock().map(|txs| txs.clone()).unwrap_or_default()
+    }
+
+    fn page(&self, offset: usize, limit: usize) -> (Vec<PITransaction>, usize) {
+        match self.transactions.lock() {
+            Ok(txs) => {
+                let start = offset.min(txs.len());
+                let end = start.saturating_add(limit).min(txs.len());
+                (txs[start..end].to_vec(), txs.len())
+            }
+            Err(_) => (Vec::new(), 0),
+        }
+    }
+}
+
+// File-backed store: one JSON-encoded transaction per line, survives restarts
+pub struct JsonFileTransactionStore {
+    path: PathBuf,
+    write_lock: std::sync::Mutex<()>,
+}
+
+impl JsonFileTransactionStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            write_lock: std::sync::Mutex::new(()),
+        }
+    }
+}
+
+impl TransactionStore for JsonFileTransactionStore {
+    fn append(&self, tx: &PITransaction) -> Result<(), String> {
+        let line = serde_json::to_string(tx).map_err(|e| format!("Encode error: {}", e))?;
+        let _guard = self.write_lock.lock().map_err(|_| "Store lock poisoned".to_string())?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| format!("Store open error: {}", e))?;
+        writeln!(file, "{}", line).map_err(|e| format!("Store write error: {}", e))
+    }
+
+    fn all(&self) -> Vec<PITransaction> {
+        let file = match OpenOptions::new().read(true).open(&self.path) {
+            Ok(file) => file,
+            Err(_) => return Vec::new(), // No history written yet
+        };
+        let mut transactions = Vec::new();
+        for (index, line) in BufReader::new(file).lines().enumerate() {
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => {
+                    warn!(path = %self.path.display(), line = index + 1, error = %e, "Transaction history unreadable past this line");
+                    break;
+                }
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            // A corrupt record is skipped so the rest of the history stays readable, but never silently
+            match serde_json::from_str(&line) {
+                Ok(tx) => transactions.push(tx),
+                Err(e) => warn!(path = %self.path.display(), line = index + 1, error = %e, "Skipped corrupt transaction record"),
+            }
+        }
+        transactions
+    }
 }
 
 // Transaction Engine
 pub struct PITransactionEngine {
     ai_core: Arc<AutonomousHyperAI>,
-    transactions: Arc<Mutex<Vec<PITransaction>>>,
+    config: EngineConfig,
+    store: Box<dyn TransactionStore + Send + Sync>,
     tx_sender: mpsc::UnboundedSender<PITransaction>,
     tx_receiver: Arc<Mutex<mpsc::UnboundedReceiver<PITransaction>>>,
+    processor_running: Arc<Mutex<bool>>, // True while run_processor is consuming the queue
+    seen_ids: Arc<Mutex<HashSet<String>>>, // Ids of accepted transactions (dedup)
+    sender_nonces: Arc<Mutex<HashMap<String, u64>>>, // Highest accepted nonce per sender (taken after seen_ids)
+    retry_queue: Arc<Mutex<Vec<PITransaction>>>, // Parked when the AI was unavailable; drained by retry_failed (taken after seen_ids)
+    fees_collected: Arc<Mutex<f64>>, // Sum of fees on queued transactions
+    receiver_validator: Option<ReceiverValidator>, // Overrides the policy's receiver rule when set via with_receiver_validator
+    exclusivity: PiExclusivityPolicy, // Sender, receiver and free-text rules (shared with the app orchestrator)
 }
 
 impl PITransactionEngine {
     pub fn new(ai_core: Arc<AutonomousHyperAI>) -> Self {
+        Self::build(ai_core, EngineConfig::default(), Box::new(InMemoryTransactionStore::new()))
+    }
+
+    // Construct with custom stable value parameters
+    pub fn new_with_config(ai_core: Arc<AutonomousHyperAI>, config: EngineConfig) -> Result<Self, String> {
+        if config.dual_value_multiplier.is_nan() || config.dual_value_multiplier <= 0.0 {
+            return Err("Invalid engine config: dual value multiplier must be positive".to_string());
+        }
+        if config.pi_stable_value.is_nan() || config.pi_stable_value <= 0.0 {
+            return Err("Invalid engine config: PI stable value must be positive".to_string());
+        }
+        // Below 1.0 so every positive amount keeps a positive remainder after its fee
+        if !(0.0..1.0).contains(&config.fee_rate) {
+            return Err("Invalid engine config: fee rate must be at least 0.0 and below 1.0".to_string());
+        }
+        if config.amount_precision > MAX_AMOUNT_PRECISION {
+            return Err(format!("Invalid engine config: amount precision must be at most {}", MAX_AMOUNT_PRECISION));
+        }
+        if let Some((tx_type, _)) = config.type_limits.iter().find(|(_, limit)| limit.is_nan() || **limit <= 0.0) {
+            return Err(format!("Invalid engine config: {:?} limit must be positive", tx_type));
+        }
+        Ok(Self::build(ai_core, config, Box::new(InMemoryTransactionStore::new())))
+    }
+
+    // Construct with a custom history store (e.g., JsonFileTransactionStore)
+    pub fn with_store(ai_core: Arc<AutonomousHyperAI>, store: Box<dyn TransactionStore + Send + Sync>) -> Self {
+        Self::build(ai_core, EngineConfig::default(), store)
+    }
+
+    fn build(ai_core: Arc<AutonomousHyperAI>, config: EngineConfig, store: Box<dyn TransactionStore + Send + Sync>) -> Self {
         let (tx, rx) = mpsc::unbounded_channel();
         Self {
             ai_core,
-            transactions: Arc::new(Mutex::new(Vec::new())),
+            config,
+            store,
             tx_sender: tx,
             tx_receiver: Arc::new(Mutex::new(rx)),
+            processor_running: Arc::new(Mutex::new(false)),
+            seen_ids: Arc::new(Mutex::new(HashSet::new())),
+            sender_nonces: Arc::new(Mutex::new(HashMap::new())),
+            retry_queue: Arc::new(Mutex::new(Vec::new())),
+            fees_collected: Arc::new(Mutex::new(0.0)),
+            receiver_validator: None,
+            exclusivity: PiExclusivityPolicy::default(),
         }
     }
 
+    // Replace the default `pi_<alphanumeric>` receiver rule (e.g., for testnet address formats)
+    pub fn with_receiver_validator(mut self, validator: ReceiverValidator) -> Self {
+        self.receiver_validator = Some(validator);
+        self
+    }
+
+    // Replace the default PI exclusivity rules (address format, banned network terms)
+    pub fn with_exclusivity_policy(mut self, policy: PiExclusivityPolicy) -> Self {
+        self.exclusivity = policy;
+        self
+    }
+
+    // Rules this engine enforces; the orchestrator checks app code against the same policy
+    pub fn exclusivity_policy(&self) -> &PiExclusivityPolicy {
+        &self.exclusivity
+    }
+
     // Validate and process PI transaction
-    pub async fn process_transaction(&self, mut tx: PITransaction) -> Result<(), String> {
-        // AI Filter: Check for volatility
-        let tx_data = format!("{:?}", tx);
-        self.ai_core.filter_io(&tx_data).await?;
+    #[cfg_attr(feature = "tracing-logs", tracing::instrument(skip_all, fields(tx_id = %tx.id)))]
+    pub async fn process_transaction(&self, tx: PITransaction) -> Result<(), String> {
+        if self.seen_ids.lock().await.contains(&tx.id) {
+            return Err(Self::duplicate_error(&tx.id));
+        }
 
-        // Verify PI stable value (amount must align with fixed value logic)
-        if tx.amount <= 0.0 || tx.amount > PI_STABLE_VALUE {
-            return Err("Invalid PI amount: must be positive and within stable limits".to_string());
+        let tx = self.validate_transaction(tx).await?;
+
+        // Claim the id and nonce together (a concurrent submission may have won the race)
+        let fee = {
+            let mut seen_ids = self.seen_ids.lock().await;
+            if seen_ids.contains(&tx.id) {
+                return Err(Self::duplicate_error(&tx.id));
+            }
+            let mut sender_nonces = self.sender_nonces.lock().await;
+            self.claim_and_queue(&mut seen_ids, &mut sender_nonces, tx)?
+        };
+        *self.fees_collected.lock().await += fee;
+        Ok(())
+    }
+
+    // Validate a batch, then check, claim and queue every id under one dedup lock acquisition; results
+    // preserve input order
+    pub async fn process_transactions(&self, txs: Vec<PITransaction>) -> Vec<Result<(), String>> {
+        // Validate before taking the dedup lock: the AI filter may await a network call, and other
+        // submitters shouldn't queue behind it
+        let mut batch_ids = HashSet::new();
+        let mut validated = Vec::with_capacity(txs.len());
+        for tx in txs {
+            // Repeats within the batch count as duplicates even if the first copy fails validation
+            if !batch_ids.insert(tx.id.clone()) {
+                validated.push((tx.id.clone(), Err(Self::duplicate_error(&tx.id))));
+                continue;
+            }
+            let id = tx.id.clone();
+            validated.push((id, self.validate_transaction(tx).await));
         }
 
+        // Ids already accepted, including by a concurrent submission while this batch was validating, are
+        // duplicates. The nonce lock is taken once alongside, so nothing else is awaited per transaction.
+        let mut fees = 0.0;
+        let results = {
+            let mut seen_ids = self.seen_ids.lock().await;
+            let mut sender_nonces = self.sender_nonces.lock().await;
+            validated
+                .into_iter()
+                .map(|(id, validation)| {
+                    if seen_ids.contains(&id) {
+                        return Err(Self::duplicate_error(&id));
+                    }
+                    fees += self.claim_and_queue(&mut seen_ids, &mut sender_nonces, validation?)?;
+                    Ok(())
+                })
+                .collect()
+        };
+        *self.fees_collected.lock().await += fees;
+        results
+    }
+
+    // Run all checks and apply the internal adjustment, cheapest checks first
+    async fn validate_transaction(&self, mut tx: PITransaction) -> Result<PITransaction, String> {
+        // Per-type ceiling first, then the global stable value bounds
+        self.validate_type_limit(&tx.tx_type, tx.amount)?;
+        self.validate_amount(tx.amount)?;
+
+        // Funds may only move between PI parties, with no other network named along the way
+        self.exclusivity.validate_sender(&tx.sender)?;
+        self.validate_receiver(&tx.receiver)?;
+        self.exclusivity.validate_field("source proof", &tx.source_proof)?;
+
+        // Reject replays of old transactions and timestamps from the future
+        self.validate_timestamp(tx.timestamp)?;
+
         // Verify source origin via hash proof
-        let expected_proof = self.generate_source_proof(&tx.tx_type, &tx.sender);
-        if tx.source_proof != expected_proof {
-            return Err("Invalid source proof: only mining, rewards, or P2P allowed".to_string());
+        self.verify_source_proof(&tx)?;
+
+        // AI Filter: Check for volatility. A volatile verdict is final; an unavailable AI parks the
+        // transaction for retry_failed (its id isn't claimed yet, so the retry isn't a duplicate)
+        let tx_data = format!("{:?}", tx);
+        match self.ai_core.filter_io_typed(&tx_data).await {
+            Ok(_) => {}
+            Err(SuperPiError::Unavailable(reason)) => {
+                self.retry_queue.lock().await.push(tx);
+                return Err(format!("{}; transaction queued for retry", reason));
+            }
+            Err(e) => return Err(e.into()),
         }
 
         // Apply dual-value system for internal stability
-        tx.amount *= DUAL_VALUE_MULTIPLIER; // Internal adjustment (not external)
+        // Internal adjustment (not external), rounded so float noise never reaches the history
+        tx.amount = round_amount(tx.amount * self.config.dual_value_multiplier, self.config.amount_precision);
+        // Charge the configured fee on the amount that is recorded
+        tx.fee = tx.amount * self.config.fee_rate;
+        Ok(tx)
+    }
+
+    // Reprocess transactions parked by transient AI failures; results follow the order they were parked.
+    // Any that hit an unavailable AI again are parked again.
+    pub async fn retry_failed(&self) -> Vec<Result<(), String>> {
+        let parked = std::mem::take(&mut *self.retry_queue.lock().await);
+        let mut results = Vec::with_capacity(parked.len());
+        for tx in parked {
+            results.push(self.process_transaction(tx).await);
+        }
+        results
+    }
+
+    // Number of transactions waiting for retry_failed
+    pub async fn retry_queue_len(&self) -> usize {
+        self.retry_queue.lock().await.len()
+    }
+
+    // Claim the id and nonce of a validated transaction and queue it, returning its fee for the caller to
+    // account once the dedup lock is released. If the queue refuses it (e.g., closed by a drain), the claim
+    // is rolled back so its id and nonce stay usable.
+    fn claim_and_queue(
+        &self,
+        seen_ids: &mut HashSet<String>,
+        sender_nonces: &mut HashMap<String, u64>,
+        tx: PITransaction,
+    ) -> Result<f64, String> {
+        let previous_nonce = self.claim_nonce(sender_nonces, &tx)?;
+        seen_ids.insert(tx.id.clone());
+        let fee = tx.fee;
+        if let Err(mpsc::error::SendError(tx)) = self.tx_sender.send(tx) {
+            seen_ids.remove(&tx.id);
+            if self.config.enforce_nonces {
+                match previous_nonce {
+                    Some(nonce) => sender_nonces.insert(tx.sender, nonce),
+                    None => sender_nonces.remove(&tx.sender),
+                };
+            }
+            return Err("Queue error: channel closed".to_string());
+        }
+        Ok(fee)
+    }
+
+    // With nonce enforcement on, accept only a nonce above the sender's last accepted one and record it;
+    // returns the sender's previous nonce so a refused transaction's claim can be undone
+    fn claim_nonce(&self, sender_nonces: &mut HashMap<String, u64>, tx: &PITransaction) -> Result<Option<u64>, String> {
+        if !self.config.enforce_nonces {
+            return Ok(None);
+        }
+        let nonce = tx.nonce.ok_or_else(|| format!("Missing nonce for sender {}", tx.sender))?;
+        if let Some(&last) = sender_nonces.get(&tx.sender) {
+            if nonce <= last {
+                return Err(format!("Stale nonce {} for sender {}: last accepted {}", nonce, tx.sender, last));
+            }
+        }
+        Ok(sender_nonces.insert(tx.sender.clone(), nonce))
+    }
+
+    fn duplicate_error(id: &str) -> String {
+        format!("Duplicate transaction id: {}", id)
+    }
 
-        // Queue for processing
-        self.tx_sender.send(tx).map_err(|e| format!("Queue error: {}", e))?;
+    // Check an amount against the configured stable value bounds
+    pub fn validate_amount(&self, amount: f64) -> Result<(), String> {
+        if amount <= 0.0 || amount > self.config.pi_stable_value {
+            return Err("Invalid PI amount: must be positive and within stable limits".to_string());
+        }
         Ok(())
     }
 
+    // Check an amount against the configured ceiling for its transaction type, if any
+    pub fn validate_type_limit(&self, tx_type: &PITransactionType, amount: f64) -> Result<(), String> {
+        match self.config.type_limits.get(&tx_type.discriminant()) {
+            Some(&limit) if amount > limit => Err(format!("Invalid PI amount: exceeds {:?} limit of {}", tx_type, limit)),
+            _ => Ok(()),
+        }
+    }
+
+    // Check a receiver against the custom validator if one is set, otherwise the exclusivity policy
+    pub fn validate_receiver(&self, receiver: &str) -> Result<(), String> {
+        match &self.receiver_validator {
+            Some(validator) if !validator(receiver) => Err("receiver not a Pi address".to_string()),
+            Some(_) => Ok(()),
+            None => self.exclusivity.validate_receiver(receiver),
+        }
+    }
+
+    // Check a timestamp (u64 seconds since the Unix epoch) against the replay window
+    pub fn validate_timestamp(&self, timestamp: u64) -> Result<(), String> {
+        let now = unix_now();
+        if timestamp < now.saturating_sub(self.config.max_tx_age.as_secs()) {
+            return Err("Stale transaction: timestamp is outside the replay window".to_string());
+        }
+        if timestamp > now.saturating_add(MAX_CLOCK_SKEW_SECS) {
+            return Err("Invalid transaction timestamp: too far in the future".to_string());
+        }
+        Ok(())
+    }
+
+    // Get the active engine configuration
+    pub fn config(&self) -> &EngineConfig {
+        &self.config
+    }
+
     // Generate hashed proof for source verification
-    fn generate_source_proof(&self, tx_type: &PITransactionType, sender: &str) -> String {
+    pub fn generate_source_proof(&self, tx_type: &PITransactionType, sender: &str) -> String {
         let input = format!("{:?}{}", tx_type, sender);
         let mut hasher = Sha256::new();
         hasher.update(input);
         format!("{:x}", hasher.finalize())
     }
 
-    // Async processor for handling queued transactions (scales to millions)
-    pub async fn run_processor(&self) {
+    // Generate keyed proof; only holders of the configured secret can produce it
+    pub fn generate_source_proof_hmac(&self, tx_type: &PITransactionType, sender: &str) -> Result<String, String> {
+        let mac = self.source_proof_mac(tx_type, sender)?;
+        Ok(hex::encode(mac.finalize().into_bytes()))
+    }
+
+    // Verify the transaction's source proof (HMAC when a key is configured, plain hash otherwise)
+    pub fn verify_source_proof(&self, tx: &PITransaction) -> Result<(), String> {
+        let valid = if self.config.proof_key.is_some() {
+            let mac = self.source_proof_mac(&tx.tx_type, &tx.sender)?;
+            hex::decode(&tx.source_proof)
+                .map(|proof| mac.verify_slice(&proof).is_ok())
+                .unwrap_or(false)
+        } else {
+            tx.source_proof == self.generate_source_proof(&tx.tx_type, &tx.sender)
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err("Invalid source proof: only mining, rewards, or P2P allowed".to_string())
+        }
+    }
+
+    fn source_proof_mac(&self, tx_type: &PITransactionType, sender: &str) -> Result<HmacSha256, String> {
+        let key = self.config.proof_key.as_ref().ok_or("No source proof key configured")?;
+        let mut mac = HmacSha256::new_from_slice(&key.0).map_err(|e| format!("Invalid proof key: {}", e))?;
+        mac.update(format!("{:?}{}", tx_type, sender).as_bytes());
+        Ok(mac)
+    }
+
+    // Async processor for handling queued transactions (scales to millions); returns once shutdown is cancelled
+    pub async fn run_processor(&self, shutdown: CancellationToken) {
         let mut rx = self.tx_receiver.lock().await;
-        while let Some(tx) = rx.recv().await {
-            // Simulate processing (in real impl: commit to Pi Network ledger)
-            println!("Processed PI Transaction: {} from {} to {} (Amount: {:.2})", tx.id, tx.sender, tx.receiver, tx.amount);
-            self.transactions.lock().await.push(tx);
+        *self.processor_running.lock().await = true;
+        loop {
+            // Biased so queued transactions are handled before the stop signal
+            let tx = tokio::select! {
+                biased;
+                received = rx.recv() => match received {
+                    Some(tx) => tx,
+                    None => break,
+                },
+                _ = shutdown.cancelled() => break,
+            };
+            self.commit_transaction(&tx);
         }
+        *self.processor_running.lock().await = false;
+    }
+
+    // Like run_processor, but on shutdown closes the queue to new transactions and processes everything
+    // already queued before returning. The queue stays closed, so the engine accepts no further transactions.
+    pub async fn run_processor_until_drained(&self, shutdown: CancellationToken) {
+        self.run_processor(shutdown).await;
+
+        let mut rx = self.tx_receiver.lock().await;
+        rx.close();
+        let mut drained = 0usize;
+        // Empty or Disconnected: nothing is left and nothing new can arrive
+        while let Ok(tx) = rx.try_recv() {
+            self.commit_transaction(&tx);
+            drained += 1;
+        }
+        info!(drained, "Transaction queue drained on shutdown");
+    }
+
+    // Simulate processing (in real impl: commit to Pi Network ledger)
+    fn commit_transaction(&self, tx: &PITransaction) {
+        info!(tx_id = %tx.id, sender = %tx.sender, receiver = %tx.receiver, amount = tx.amount, "Processed PI transaction");
+        if let Err(e) = self.store.append(tx) {
+            warn!(tx_id = %tx.id, error = %e, "Failed to persist transaction");
+        }
+    }
+
+    // Total fees charged on accepted transactions
+    pub async fn total_fees(&self) -> f64 {
+        *self.fees_collected.lock().await
+    }
+
+    // Check whether the background processor is consuming the queue
+    pub async fn is_processor_running(&self) -> bool {
+        *self.processor_running.lock().await
     }
 
     // Get transaction history
     pub async fn get_transactions(&self) -> Vec<PITransaction> {
-        self.transactions.lock().await.clone()
+        self.store.all()
     }
+
+    // Get one page of transaction history and the total count
+    pub async fn get_transactions_page(&self, offset: usize, limit: usize) -> (Vec<PITransaction>, usize) {
+        self.store.page(offset, limit)
+    }
+}
+
+// Round half away from zero to `precision` decimal places; the same input always yields the same f64
+pub fn round_amount(amount: f64, precision: u32) -> f64 {
+    let scale = 10f64.powi(precision as i32);
+    (amount * scale).round() / scale
+}
+
+// Address format of the default exclusivity policy: matches ^pi_[a-zA-Z0-9]+$
+pub fn is_pi_address(address: &str) -> bool {
+    address
+        .strip_prefix("pi_")
+        .is_some_and(|rest| !rest.is_empty() && rest.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
+// Current time in u64 seconds since the Unix epoch
+fn unix_now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
 }
 
 // Example Usage (integrate into main app loop)
@@ -110,18 +632,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Start processor in background
     tokio::spawn(async move {
-        engine.run_processor().await;
+        engine.run_processor(CancellationToken::new()).await;
     });
 
     // Process a sample PI transaction
     let tx = PITransaction {
         id: "tx_001".to_string(),
         sender: "miner_123".to_string(),
-        receiver: "dev_456".to_string(),
+        receiver: "pi_dev456".to_string(),
         amount: 1000.0,
         tx_type: PITransactionType::MiningReward,
         source_proof: engine.generate_source_proof(&PITransactionType::MiningReward, "miner_123"),
-        timestamp: 1640995200, // Example timestamp
+        timestamp: unix_now(),
+        fee: 0.0,
+        nonce: None,
     };
 
     match engine.process_transaction(tx).await {
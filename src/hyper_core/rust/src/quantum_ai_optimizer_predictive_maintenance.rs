@@ -3,11 +3,17 @@
 // Provides quantum AI optimization and predictive maintenance for the Pi Ecosystem.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, contracttype, xdr::ToXdr, Bytes, Env, String, Symbol, Vec, Map, log};
+
+// Failure probabilities (0-99) at or above this predict a failure
+const FAILURE_THRESHOLD: u64 = 80;
+// Most recent records kept per component
+const MAX_HISTORY_PER_COMPONENT: u32 = 16;
 
 #[contract]
 pub struct QuantumAiOptimizerPredictiveMaintenance;
 
+#[contracttype]
 #[derive(Clone)]
 pub struct PredictiveMaintenance {
     pub id: Symbol,
@@ -28,9 +34,10 @@ impl QuantumAiOptimizerPredictiveMaintenance {
 
     /// Predict and optimize maintenance
     pub fn predict_optimize_maintenance(env: Env, component: Symbol) -> PredictiveMaintenance {
-        // Simulate quantum AI prediction (based on random simulation)
-        let predicted_failure = false; // Simulated: no failure
-        let optimization_score = 100; // Optimal
+        // Simulate quantum AI prediction (deterministic per ledger sequence and component)
+        let failure_probability = Self::failure_probability(&env, &component);
+        let predicted_failure = failure_probability >= FAILURE_THRESHOLD;
+        let optimization_score = if predicted_failure { 100 - failure_probability as i64 } else { 100 };
         let maintenance_applied = !predicted_failure;
 
         let maintenance = PredictiveMaintenance {
@@ -43,9 +50,48 @@ impl QuantumAiOptimizerPredictiveMaintenance {
         };
 
         log!(&env, "Predictive Maintenance for {}: Failure {} Optimization {}", component, predicted_failure, optimization_score);
+        Self::record_maintenance(&env, &maintenance);
         maintenance
     }
 
+    /// Get stored maintenance records for a component (oldest first, bounded)
+    pub fn get_maintenance_history(env: Env, component: Symbol) -> Vec<PredictiveMaintenance> {
+        env.storage()
+            .persistent()
+            .get(&(Symbol::new(&env, "history"), component))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Append a record to its component's history, dropping the oldest beyond the cap
+    fn record_maintenance(env: &Env, maintenance: &PredictiveMaintenance) {
+        let mut history = Self::get_maintenance_history(env.clone(), maintenance.component.clone());
+        if history.is_empty() {
+            let mut components: Vec<Symbol> = env.storage().instance().get(&Symbol::new(env, "components")).unwrap_or_else(|| Vec::new(env));
+            components.push_back(maintenance.component.clone());
+            env.storage().instance().set(&Symbol::new(env, "components"), &components);
+        }
+
+        history.push_back(maintenance.clone());
+        while history.len() > MAX_HISTORY_PER_COMPONENT {
+            history.pop_front();
+        }
+        env.storage().persistent().set(&(Symbol::new(env, "history"), maintenance.component.clone()), &history);
+    }
+
+    /// Pseudo-random failure probability (0-99) seeded from the ledger sequence and component
+    fn failure_probability(env: &Env, component: &Symbol) -> u64 {
+        let mut seed = Bytes::from_array(env, &env.ledger().sequence().to_be_bytes());
+        seed.append(&component.clone().to_xdr(env));
+        let digest = env.crypto().sha256(&seed).to_array();
+
+        // One splitmix64 step over the seed
+        let mut state = u64::from_be_bytes([digest[0], digest[1], digest[2], digest[3], digest[4], digest[5], digest[6], digest[7]]);
+        state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        state = (state ^ (state >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        state = (state ^ (state >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        (state ^ (state >> 31)) % 100
+    }
+
     /// Apply quantum optimization
     pub fn apply_quantum_optimization(env: Env, maintenance: PredictiveMaintenance) -> Symbol {
         if maintenance.predicted_failure {
@@ -80,7 +126,8 @@ impl QuantumAiOptimizerPredictiveMaintenance {
     /// Get optimization status
     pub fn get_optimization_status(env: Env) -> Map<Symbol, i64> {
         let mut status = Map::new(&env);
-        status.set(Symbol::new(&env, "components_optimized"), 50); // Simulated count
+        let components: Vec<Symbol> = env.storage().instance().get(&Symbol::new(&env, "components")).unwrap_or_else(|| Vec::new(&env));
+        status.set(Symbol::new(&env, "components_optimized"), components.len() as i64);
         status.set(Symbol::new(&env, "predictive_accuracy"), 100); // Perfect
         status.set(Symbol::new(&env, "maintenance_efficiency"), 100);
         status
@@ -99,12 +146,12 @@ impl QuantumAiOptimizerPredictiveMaintenance {
     }
 
     /// Render optimization hologram
-    pub fn render_optimization_hologram(env: Env, maintenance: PredictiveMaintenance) -> Vec<Symbol> {
+    pub fn render_optimization_hologram(env: Env, maintenance: PredictiveMaintenance) -> Vec<String> {
         let hologram = Vec::from_array(&env, [
-            Symbol::new(&env, "Quantum Optimization Hologram"),
-            maintenance.component,
-            Symbol::new(&env, &format!("Optimization Score: {}", maintenance.optimization_score)),
-            Symbol::new(&env, &format!("Maintenance Applied: {}", maintenance.maintenance_applied)),
+            String::from_str(&env, "Quantum Optimization Hologram"),
+            crate::hologram::symbol_to_string(&env, &maintenance.component),
+            String::from_str(&env, &format!("Optimization Score: {}", maintenance.optimization_score)),
+            String::from_str(&env, &format!("Maintenance Applied: {}", maintenance.maintenance_applied)),
         ]);
         log!(&env, "Optimization Hologram Rendered");
         hologram
@@ -0,0 +1,45 @@
+// src/hyper_core/rust/src/rule_chain.rs
+// Rule Chain - shared upstream health validation for Soroban rule updates
+// A rule update lists (module, metric) pairs; every metric must read 100 for the update to pass.
+// Dependencies: soroban-sdk = "0.9" in Cargo.toml
+
+use soroban_sdk::{Env, Map, Symbol, Vec, log};
+
+/// Verify every (module, metric) pair reads 100; unknown modules and missing metrics fail the chain
+pub fn validate_rule_chain(env: Env, checks: Vec<(Symbol, Symbol)>) -> bool {
+    for (module, metric) in checks.iter() {
+        let value = module_status(&env, &module).and_then(|status| status.get(metric.clone()));
+        if value != Some(100) {
+            log!(&env, "Rule Chain Check Failed: {} {} = {}", module, metric, value.unwrap_or(0));
+            return false;
+        }
+    }
+    true
+}
+
+/// Status map for a module, addressed by its init_all symbol
+fn module_status(env: &Env, module: &Symbol) -> Option<Map<Symbol, i64>> {
+    let env = env.clone();
+    let status = if *module == Symbol::new(&env, "holographic_archive") {
+        crate::ultimate_ecosystem_documentation_holographic_archive::UltimateEcosystemDocumentationHolographicArchive::get_archive_status(env)
+    } else if *module == Symbol::new(&env, "test_suite_validation") {
+        crate::comprehensive_test_suite_validation::ComprehensiveTestSuiteValidation::get_test_status(env)
+    } else if *module == Symbol::new(&env, "anti_quantum_security") {
+        crate::eternal_quantum_security_anti_quantum_threat::EternalQuantumSecurityAntiQuantumThreat::get_security_status(env)
+    } else if *module == Symbol::new(&env, "universal_capstone") {
+        crate::final_universal_integration_supremacy_capstone::FinalUniversalIntegrationSupremacyCapstone::get_capstone_status(env)
+    } else if *module == Symbol::new(&env, "guardian_summary") {
+        crate::ultimate_ecosystem_guardian_summary_script::UltimateEcosystemGuardianSummaryScript::get_guardian_status(env)
+    } else if *module == Symbol::new(&env, "eternal_guardian") {
+        crate::absolute_final_ecosystem_seal_eternal_guardian::AbsoluteFinalEcosystemSealEternalGuardian::get_eternal_guardian_status(env)
+    } else if *module == Symbol::new(&env, "predictive_maintenance") {
+        crate::quantum_ai_optimizer_predictive_maintenance::QuantumAiOptimizerPredictiveMaintenance::get_optimization_status(env)
+    } else if *module == Symbol::new(&env, "realtime_sync") {
+        crate::pi_mainnet_integration_real_time_synchronization::PiMainnetIntegrationRealTimeSynchronization::get_synchronization_status(env)
+    } else if *module == Symbol::new(&env, "swarm_hub") {
+        crate::global_decentralized_ai_swarm_intelligence_hub::GlobalDecentralizedAiSwarmIntelligenceHub::get_swarm_status(env)
+    } else {
+        return None;
+    };
+    Some(status)
+}
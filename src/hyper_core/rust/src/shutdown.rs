@@ -0,0 +1,81 @@
+// src/hyper_core/rust/src/shutdown.rs
+// Shutdown Coordinator for Pi Ecosystem Super App
+// Stops every spawned background task (tx processor, stream processor, controller loop) in one call.
+// Dependencies: Add to Cargo.toml: tokio = "1.0", tokio-util = "0.7" (for CancellationToken)
+
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use crate::telemetry::{info, warn};
+
+// Shutdown Coordinator
+pub struct ShutdownCoordinator {
+    token: CancellationToken,
+    handles: Mutex<Vec<JoinHandle<()>>>,
+}
+
+impl Default for ShutdownCoordinator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShutdownCoordinator {
+    pub fn new() -> Self {
+        Self {
+            token: CancellationToken::new(),
+            handles: Mutex::new(Vec::new()),
+        }
+    }
+
+    // Token to pass into run_processor, run_stream_processor, and run_super_app
+    pub fn token(&self) -> CancellationToken {
+        self.token.clone()
+    }
+
+    // Track a spawned task so shutdown_all waits for it
+    pub async fn register(&self, handle: JoinHandle<()>) {
+        self.handles.lock().await.push(handle);
+    }
+
+    // Number of registered tasks that have not finished yet
+    pub async fn running_tasks(&self) -> usize {
+        self.handles.lock().await.iter().filter(|h| !h.is_finished()).count()
+    }
+
+    // Cancel the shared token and wait for every registered task to return
+    pub async fn shutdown_all(&self) {
+        self.token.cancel();
+        let handles: Vec<JoinHandle<()>> = self.handles.lock().await.drain(..).collect();
+        let task_count = handles.len();
+        for handle in handles {
+            if let Err(e) = handle.await {
+                warn!(error = %e, "Background task ended abnormally during shutdown");
+            }
+        }
+        info!(task_count, "All background tasks stopped");
+    }
+}
+
+// Example Usage (integrate into main app loop)
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    use std::sync::Arc;
+    use crate::hyper_ai_core::AutonomousHyperAI;
+    use crate::pi_transaction_engine::PITransactionEngine;
+
+    let ai_core = Arc::new(AutonomousHyperAI::new());
+    let tx_engine = Arc::new(PITransactionEngine::new(ai_core.clone()));
+    let coordinator = ShutdownCoordinator::new();
+
+    // Start the tx processor under the coordinator's token
+    let engine = tx_engine.clone();
+    let token = coordinator.token();
+    coordinator.register(tokio::spawn(async move { engine.run_processor(token).await })).await;
+
+    // Stop everything at once
+    coordinator.shutdown_all().await;
+    println!("Processor running after shutdown: {}", tx_engine.is_processor_running().await);
+
+    Ok(())
+}
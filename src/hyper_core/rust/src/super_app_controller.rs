@@ -1,37 +1,329 @@
 // src/hyper_core/rust/src/super_app_controller.rs
 // Super App Controller for Pi Ecosystem Super App
 // Integrates and orchestrates all hyper-tech modules for autonomous operation.
-// Dependencies: Add to Cargo.toml: tokio = "1.0", serde = { version = "1.0", features = ["derive"] }, chrono = "0.4" (for timestamps)
+// Dependencies: Add to Cargo.toml: tokio = "1.0", serde = { version = "1.0", features = ["derive"] }, chrono = "0.4" (for timestamps), tokio-util = "0.7" (for CancellationToken), async-trait = "0.1" (for subsystem traits)
 // Integrate with all previous modules: pub mod hyper_ai_core; pub mod pi_transaction_engine; pub mod pi_mainnet_accelerator; pub mod ecosystem_isolation_shield; pub mod developer_app_orchestrator;
 
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
-use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{Map, Value};
 use chrono::{DateTime, Utc};
+use async_trait::async_trait;
+use crate::clock::{Clock, SystemClock};
 use crate::hyper_ai_core::AutonomousHyperAI;
-use crate::pi_transaction_engine::PITransactionEngine;
-use crate::pi_mainnet_accelerator::PiMainnetAccelerator;
-use crate::ecosystem_isolation_shield::EcosystemIsolationShield;
-use crate::developer_app_orchestrator::DeveloperAppOrchestrator;
+use crate::pi_transaction_engine::{PITransaction, PITransactionEngine};
+use crate::pi_mainnet_accelerator::{EvolutionMetrics, EvolutionSignals, PiMainnetAccelerator};
+use crate::ecosystem_isolation_shield::{EcosystemIsolationShield, IsolationEvent, IsolationVerdict};
+use crate::developer_app_orchestrator::{DeployResult, DeveloperAppOrchestrator, OrchestratorMetrics};
+
+// Subsystem interfaces: exactly what the controller calls, so tests can swap in mocks
+
+#[async_trait]
+pub trait ComplianceSource: Send + Sync {
+    async fn enforce_compliance(&self) -> Result<(), String>;
+    async fn probe_compliance(&self) -> Result<bool, String>;
+    async fn get_status(&self) -> (bool, bool);
+}
+
+#[async_trait]
+pub trait TransactionProcessor: Send + Sync {
+    async fn process_transaction(&self, tx: PITransaction) -> Result<(), String>;
+    async fn get_transactions(&self) -> Vec<PITransaction>;
+    async fn is_processor_running(&self) -> bool;
+}
+
+#[async_trait]
+pub trait MainnetManager: Send + Sync {
+    async fn accelerate_mainnet(&self) -> Result<(), String>;
+    async fn evolve_system(&self, signals: EvolutionSignals) -> Result<(), String>;
+    async fn get_metrics(&self) -> EvolutionMetrics;
+}
+
+#[async_trait]
+pub trait DataIsolator: Send + Sync {
+    async fn process_stream_verdict(&self, data: String) -> Result<IsolationVerdict, String>;
+    async fn get_events(&self) -> Vec<IsolationEvent>;
+}
+
+#[async_trait]
+pub trait AppManager: Send + Sync {
+    async fn deploy_app(&self, developer: String, code: String) -> Result<DeployResult, String>;
+    async fn run_apps(&self) -> Result<(), String>;
+    async fn evolution_signals(&self) -> EvolutionSignals;
+    async fn get_metrics(&self) -> OrchestratorMetrics;
+    async fn reconcile_app_counts(&self) -> (u64, u64);
+    async fn halt_by_code_hash(&self, code_hash: String) -> usize;
+}
+
+#[async_trait]
+impl ComplianceSource for AutonomousHyperAI {
+    async fn enforce_compliance(&self) -> Result<(), String> {
+        AutonomousHyperAI::enforce_compliance(self).await
+    }
+
+    async fn probe_compliance(&self) -> Result<bool, String> {
+        AutonomousHyperAI::probe_compliance(self).await
+    }
+
+    async fn get_status(&self) -> (bool, bool) {
+        AutonomousHyperAI::get_status(self).await
+    }
+}
+
+#[async_trait]
+impl TransactionProcessor for PITransactionEngine {
+    async fn process_transaction(&self, tx: PITransaction) -> Result<(), String> {
+        PITransactionEngine::process_transaction(self, tx).await
+    }
+
+    async fn get_transactions(&self) -> Vec<PITransaction> {
+        PITransactionEngine::get_transactions(self).await
+    }
+
+    async fn is_processor_running(&self) -> bool {
+        PITransactionEngine::is_processor_running(self).await
+    }
+}
+
+#[async_trait]
+impl MainnetManager for PiMainnetAccelerator {
+    async fn accelerate_mainnet(&self) -> Result<(), String> {
+        PiMainnetAccelerator::accelerate_mainnet(self).await
+    }
+
+    async fn evolve_system(&self, signals: EvolutionSignals) -> Result<(), String> {
+        PiMainnetAccelerator::evolve_system(self, signals).await
+    }
+
+    async fn get_metrics(&self) -> EvolutionMetrics {
+        PiMainnetAccelerator::get_metrics(self).await
+    }
+}
+
+#[async_trait]
+impl DataIsolator for EcosystemIsolationShield {
+    async fn process_stream_verdict(&self, data: String) -> Result<IsolationVerdict, String> {
+        EcosystemIsolationShield::process_stream_verdict(self, data).await
+    }
+
+    async fn get_events(&self) -> Vec<IsolationEvent> {
+        EcosystemIsolationShield::get_events(self).await
+    }
+}
+
+#[async_trait]
+impl AppManager for DeveloperAppOrchestrator {
+    async fn deploy_app(&self, developer: String, code: String) -> Result<DeployResult, String> {
+        // Commands deploy end to end; a command timeout rolls the whole unit back
+        Ok(DeveloperAppOrchestrator::deploy_and_finalize(self, developer, code).await?)
+    }
+
+    async fn run_apps(&self) -> Result<(), String> {
+        DeveloperAppOrchestrator::run_apps(self).await
+    }
+
+    async fn evolution_signals(&self) -> EvolutionSignals {
+        DeveloperAppOrchestrator::evolution_signals(self).await
+    }
+
+    async fn get_metrics(&self) -> OrchestratorMetrics {
+        DeveloperAppOrchestrator::get_metrics(self).await
+    }
+
+    async fn reconcile_app_counts(&self) -> (u64, u64) {
+        DeveloperAppOrchestrator::reconcile_app_counts(self).await
+    }
+
+    async fn halt_by_code_hash(&self, code_hash: String) -> usize {
+        DeveloperAppOrchestrator::halt_by_code_hash(self, &code_hash).await
+    }
+}
+
+pub const EVENT_SCHEMA_VERSION: u16 = 2; // v1 records predate duration_ms
 
 // Controller Event Struct
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ControllerEvent {
+    pub schema_version: u16, // Serialized layout version; replay_events migrates older records
     pub id: String,
     pub event_type: String, // e.g., "compliance_check", "app_deployment"
     pub details: String,
     pub timestamp: DateTime<Utc>,
+    #[serde(default)]
+    pub duration_ms: Option<u64>, // Wall-clock time of the underlying operation, when measured
+}
+
+const DEFAULT_MAX_EVENTS: usize = 10_000; // Roughly a day of monitoring ticks plus commands
+
+// Controller Configuration
+#[derive(Clone, Debug)]
+pub struct ControllerConfig {
+    pub rate_limit_capacity: f64,       // Max burst of commands
+    pub rate_limit_refill_per_sec: f64, // Sustained commands per second
+    pub health_probe_timeout: Duration, // Upper bound on the AI compliance probe in health_check
+    pub command_timeout: Duration,      // Upper bound on a single command; expiry returns "command timed out"
+    pub max_events: usize,              // Event log retention; the oldest events are evicted beyond this (min 1)
+    pub evolve_every_n_ticks: u32,      // Evolve on every Nth monitoring tick, or sooner if the signals changed (min 1)
+    pub non_compliance_action: NonComplianceAction, // What run_super_app does when a tick finds Pi Network non-compliant
+}
+
+// Reaction to a compliance breach in the monitoring loop
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NonComplianceAction {
+    Halt,                                // Deactivate and return from run_super_app
+    PauseAndRetry { interval: Duration }, // Deactivate, re-check every interval, and resume once compliant
+    DegradedMode,                        // Stay active but skip evolution until compliance recovers
+}
+
+impl Default for ControllerConfig {
+    fn default() -> Self {
+        Self {
+            rate_limit_capacity: 100.0,
+            rate_limit_refill_per_sec: 50.0,
+            health_probe_timeout: Duration::from_secs(2),
+            command_timeout: Duration::from_secs(30),
+            max_events: DEFAULT_MAX_EVENTS,
+            evolve_every_n_ticks: 1,
+            non_compliance_action: NonComplianceAction::Halt,
+        }
+    }
+}
+
+// Subsystem readiness report
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct HealthReport {
+    pub ai_reachable: bool,
+    pub processor_running: bool,
+    pub mainnet_progress_nonzero: bool,
+    pub apps_orchestrator_ok: bool,
+    pub healthy: bool,
+}
+
+// Token-bucket limiter guarding the command interface
+#[derive(Debug)]
+struct TokenBucket {
+    capacity: f64,
+    tokens: f64,
+    refill_per_sec: f64,
+    last_refill: tokio::time::Instant,
+}
+
+impl TokenBucket {
+    fn new(capacity: f64, refill_per_sec: f64) -> Self {
+        Self {
+            capacity,
+            tokens: capacity,
+            refill_per_sec,
+            last_refill: tokio::time::Instant::now(),
+        }
+    }
+
+    // Refill based on elapsed time, then take one token if available
+    fn try_acquire(&mut self) -> bool {
+        let now = tokio::time::Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        self.last_refill = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+// JSON-RPC style command request
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandRequest {
+    pub method: String,
+    #[serde(default)]
+    pub params: Value,
+}
+
+// JSON-RPC style command response
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandResponse {
+    pub ok: bool,
+    pub result: Value,
+    pub error: Option<String>,
+}
+
+// What a successful command did; Display gives the plain-text result execute_command used to return
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum CommandOutcome {
+    Deployed { app_id: String },
+    TransactionQueued,
+    // payload is the full sealed string (prefix and format are shield config), which the hash alone can't rebuild
+    DataSealed { hash: String, payload: String },
+    DataIsolated { score: f64 },
+    // Outcome of the existing halt_by_code_hash command, which none of the other variants describe
+    AppsHalted { code_hash: String, count: usize },
+}
+
+impl std::fmt::Display for CommandOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommandOutcome::Deployed { app_id } => write!(f, "App {} deployed successfully.", app_id),
+            CommandOutcome::TransactionQueued => write!(f, "Transaction processed."),
+            CommandOutcome::DataSealed { payload, .. } => write!(f, "{}", payload),
+            CommandOutcome::DataIsolated { score } => write!(f, "Data isolated: volatility score {:.2}", score),
+            CommandOutcome::AppsHalted { code_hash, count } => write!(f, "Halted {} app(s) with code hash {}.", count, code_hash),
+        }
+    }
+}
+
+impl From<IsolationVerdict> for CommandOutcome {
+    fn from(verdict: IsolationVerdict) -> Self {
+        match verdict {
+            IsolationVerdict::Sealed { payload, hash } => CommandOutcome::DataSealed { hash, payload },
+            IsolationVerdict::Isolated { score, .. } => CommandOutcome::DataIsolated { score },
+        }
+    }
+}
+
+// Typed params per command method
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DeployAppParams {
+    pub developer: String,
+    pub code: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct IsolateDataParams {
+    pub data: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct HaltByCodeHashParams {
+    pub code_hash: String,
 }
 
-// Super App Controller
+// Super App Controller (subsystems held as trait objects; concrete modules coerce on construction)
+//
+// Lock discipline: the controller's own locks (status, events, rate_limiter) are only ever held one at a
+// time and never across an await on a subsystem or on another lock. If two must ever be nested, take them
+// in the canonical order status -> events -> rate_limiter. Subsystems take their own locks, so calling
+// them while holding a controller lock could order-invert against a subsystem that reports back here.
 pub struct SuperAppController {
-    ai_core: Arc<AutonomousHyperAI>,
-    tx_engine: Arc<PITransactionEngine>,
-    mainnet_accelerator: Arc<PiMainnetAccelerator>,
-    isolation_shield: Arc<EcosystemIsolationShield>,
-    app_orchestrator: Arc<DeveloperAppOrchestrator>,
-    events: Arc<Mutex<Vec<ControllerEvent>>>,
+    ai_core: Arc<dyn ComplianceSource>,
+    tx_engine: Arc<dyn TransactionProcessor>,
+    mainnet_accelerator: Arc<dyn MainnetManager>,
+    isolation_shield: Arc<dyn DataIsolator>,
+    app_orchestrator: Arc<dyn AppManager>,
+    events: Arc<Mutex<VecDeque<ControllerEvent>>>, // Oldest first, capped at config.max_events
     status: Arc<Mutex<ControllerStatus>>,
+    rate_limiter: Arc<Mutex<TokenBucket>>,
+    config: ControllerConfig,
+    clock: Arc<dyn Clock>, // Event timestamps (SystemClock unless replaced via with_clock)
 }
 
 #[derive(Clone, Debug)]
@@ -43,11 +335,22 @@ pub struct ControllerStatus {
 
 impl SuperAppController {
     pub fn new(
-        ai_core: Arc<AutonomousHyperAI>,
-        tx_engine: Arc<PITransactionEngine>,
-        mainnet_accelerator: Arc<PiMainnetAccelerator>,
-        isolation_shield: Arc<EcosystemIsolationShield>,
-        app_orchestrator: Arc<DeveloperAppOrchestrator>,
+        ai_core: Arc<dyn ComplianceSource>,
+        tx_engine: Arc<dyn TransactionProcessor>,
+        mainnet_accelerator: Arc<dyn MainnetManager>,
+        isolation_shield: Arc<dyn DataIsolator>,
+        app_orchestrator: Arc<dyn AppManager>,
+    ) -> Self {
+        Self::new_with_config(ai_core, tx_engine, mainnet_accelerator, isolation_shield, app_orchestrator, ControllerConfig::default())
+    }
+
+    pub fn new_with_config(
+        ai_core: Arc<dyn ComplianceSource>,
+        tx_engine: Arc<dyn TransactionProcessor>,
+        mainnet_accelerator: Arc<dyn MainnetManager>,
+        isolation_shield: Arc<dyn DataIsolator>,
+        app_orchestrator: Arc<dyn AppManager>,
+        config: ControllerConfig,
     ) -> Self {
         Self {
             ai_core,
@@ -55,17 +358,29 @@ impl SuperAppController {
             mainnet_accelerator,
             isolation_shield,
             app_orchestrator,
-            events: Arc::new(Mutex::new(Vec::new())),
+            events: Arc::new(Mutex::new(VecDeque::new())),
             status: Arc::new(Mutex::new(ControllerStatus {
                 active: true,
                 pi_ecosystem_stable: true,
                 stellar_halted: false,
             })),
+            rate_limiter: Arc::new(Mutex::new(TokenBucket::new(
+                config.rate_limit_capacity,
+                config.rate_limit_refill_per_sec,
+            ))),
+            config,
+            clock: Arc::new(SystemClock),
         }
     }
 
-    // Initialize and run the Super App autonomously
-    pub async fn run_super_app(&self) -> Result<(), String> {
+    // Replace the time source (e.g., a MockClock for deterministic event timestamps)
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    // Initialize and run the Super App autonomously until shutdown is cancelled
+    pub async fn run_super_app(&self, shutdown: CancellationToken) -> Result<(), String> {
         // Start all sub-systems
         self.ai_core.enforce_compliance().await?;
         self.mainnet_accelerator.accelerate_mainnet().await?;
@@ -74,57 +389,223 @@ impl SuperAppController {
         // Log event
         self.log_event("super_app_init", "Super App fully operational.").await;
 
-        // Continuous monitoring loop
+        // Continuous monitoring loop: compliance every tick, evolution on the configured cadence
+        let evolve_every = u64::from(self.config.evolve_every_n_ticks.max(1));
+        let mut tick: u64 = 0;
+        let mut last_signals = None;
+        let mut degraded = false;
         loop {
-            tokio::time::sleep(tokio::time::Duration::from_secs(10)).await; // Check every 10s
+            tokio::select! {
+                _ = tokio::time::sleep(tokio::time::Duration::from_secs(10)) => {} // Check every 10s
+                _ = shutdown.cancelled() => {
+                    self.status.lock().await.active = false;
+                    self.log_event("super_app_shutdown", "Super App stopped by shutdown request.").await;
+                    break;
+                }
+            }
 
-            // Aggregate status
+            // Refresh compliance every tick, whether or not evolution is due; a failed refresh leaves the cached status in charge
+            let _ = self.ai_core.enforce_compliance().await;
+
+            // Aggregate status (the status lock is released before logging or evolving)
+            let action = self.config.non_compliance_action;
             let (compliant, stellar_halted) = self.ai_core.get_status().await;
-            let mut status = self.status.lock().await;
-            status.pi_ecosystem_stable = compliant;
-            status.stellar_halted = stellar_halted;
+            {
+                let mut status = self.status.lock().await;
+                status.pi_ecosystem_stable = compliant;
+                status.stellar_halted = stellar_halted;
+                if !compliant && action != NonComplianceAction::DegradedMode {
+                    status.active = false;
+                }
+            }
 
             if !compliant {
-                self.log_event("compliance_breach", "Pi Network non-compliant; halting operations.").await;
-                status.active = false;
-                break;
+                match action {
+                    NonComplianceAction::Halt => {
+                        self.log_event("compliance_breach", "Pi Network non-compliant; halting operations.").await;
+                        break;
+                    }
+                    NonComplianceAction::PauseAndRetry { interval } => {
+                        self.log_event("compliance_breach", "Pi Network non-compliant; pausing until compliance recovers.").await;
+                        if !self.wait_for_compliance(interval, &shutdown).await {
+                            self.log_event("super_app_shutdown", "Super App stopped by shutdown request.").await;
+                            break;
+                        }
+                        self.log_event("compliance_restored", "Pi Network compliant again; resuming operations.").await;
+                        continue;
+                    }
+                    NonComplianceAction::DegradedMode => {
+                        if !degraded {
+                            degraded = true;
+                            self.log_event("compliance_degraded", "Pi Network non-compliant; evolution suspended.").await;
+                        }
+                        // The next tick's refresh decides whether compliance has been restored
+                        continue;
+                    }
+                }
+            }
+            if degraded {
+                degraded = false;
+                self.log_event("compliance_restored", "Pi Network compliant again; resuming operations.").await;
             }
 
-            // Evolve system
-            self.mainnet_accelerator.evolve_system().await?;
-            self.log_event("evolution_cycle", "Pi Network evolved successfully.").await;
+            // Evolve system when due, or early if the signals moved since the last tick
+            tick += 1;
+            let signals = self.app_orchestrator.evolution_signals().await;
+            let changed = last_signals.as_ref().is_some_and(|last| *last != signals);
+            last_signals = Some(signals.clone());
+            if tick.is_multiple_of(evolve_every) || changed {
+                self.mainnet_accelerator.evolve_system(signals).await?;
+                self.log_event("evolution_cycle", "Pi Network evolved successfully.").await;
+            }
         }
 
         Ok(())
     }
 
+    // Re-check compliance every interval until it recovers (true, controller reactivated) or shutdown is cancelled (false)
+    async fn wait_for_compliance(&self, interval: Duration, shutdown: &CancellationToken) -> bool {
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep(interval) => {}
+                _ = shutdown.cancelled() => return false,
+            }
+
+            // A failed refresh just means another wait; the cached status decides
+            let _ = self.ai_core.enforce_compliance().await;
+            let (compliant, stellar_halted) = self.ai_core.get_status().await;
+            if compliant {
+                let mut status = self.status.lock().await;
+                status.active = true;
+                status.pi_ecosystem_stable = true;
+                status.stellar_halted = stellar_halted;
+                return true;
+            }
+        }
+    }
+
     // Unified command interface (e.g., for deploying apps or processing transactions)
-    pub async fn execute_command(&self, command: &str, params: Vec<String>) -> Result<String, String> {
+    pub async fn execute_command(&self, command: &str, params: Vec<String>) -> Result<CommandOutcome, String> {
+        self.check_rate_limit().await?;
+
+        let started = Instant::now();
+        let result = self.with_command_timeout(self.dispatch_command(command, params)).await;
+        self.log_command(command, &result, started.elapsed()).await;
+        result
+    }
+
+    // Bound a command by the configured timeout; the inner future is dropped on expiry
+    async fn with_command_timeout<F>(&self, command: F) -> Result<CommandOutcome, String>
+    where
+        F: std::future::Future<Output = Result<CommandOutcome, String>>,
+    {
+        tokio::time::timeout(self.config.command_timeout, command)
+            .await
+            .unwrap_or_else(|_| Err("command timed out".to_string()))
+    }
+
+    async fn dispatch_command(&self, command: &str, params: Vec<String>) -> Result<CommandOutcome, String> {
         match command {
             "deploy_app" => {
                 if params.len() >= 2 {
-                    self.app_orchestrator.deploy_app(params[0].clone(), params[1].clone()).await
+                    self.deploy(params[0].clone(), params[1].clone()).await
                 } else {
                     Err("Invalid params for deploy_app.".to_string())
                 }
             }
             "process_transaction" => {
-                // Simplified: Assume params are transaction details
-                // In real impl: Parse into PITransaction
-                self.tx_engine.process_transaction(/* parsed tx */).await?;
-                Ok("Transaction processed.".to_string())
+                // First param is the JSON-encoded PITransaction
+                let tx: PITransaction = params
+                    .first()
+                    .ok_or("Invalid params for process_transaction.")
+                    .and_then(|raw| serde_json::from_str(raw).map_err(|_| "Invalid params for process_transaction."))?;
+                self.tx_engine.process_transaction(tx).await?;
+                Ok(CommandOutcome::TransactionQueued)
             }
             "isolate_data" => {
                 if !params.is_empty() {
-                    self.isolation_shield.process_stream(params[0].clone()).await
+                    self.isolation_shield.process_stream_verdict(params[0].clone()).await.map(CommandOutcome::from)
                 } else {
                     Err("Invalid params for isolate_data.".to_string())
                 }
             }
+            "halt_by_code_hash" => match params.first() {
+                Some(code_hash) => self.recall_code_hash(code_hash.clone()).await,
+                None => Err("Invalid params for halt_by_code_hash.".to_string()),
+            },
+            _ => Err("Unknown command.".to_string()),
+        }
+    }
+
+    // Structured command interface: params are deserialized into a typed struct per method
+    pub async fn execute_json_command(&self, request: CommandRequest) -> CommandResponse {
+        if let Err(e) = self.check_rate_limit().await {
+            return CommandResponse { ok: false, result: Value::Null, error: Some(e) };
+        }
+
+        let started = Instant::now();
+        let outcome = self.with_command_timeout(self.dispatch_json_command(&request.method, request.params)).await;
+        self.log_command(&request.method, &outcome, started.elapsed()).await;
+
+        match outcome.and_then(|result| serde_json::to_value(result).map_err(|e| format!("Unserializable outcome: {}", e))) {
+            Ok(result) => CommandResponse { ok: true, result, error: None },
+            Err(e) => CommandResponse { ok: false, result: Value::Null, error: Some(e) },
+        }
+    }
+
+    async fn dispatch_json_command(&self, method: &str, params: Value) -> Result<CommandOutcome, String> {
+        match method {
+            "deploy_app" => match Self::parse_params::<DeployAppParams>(method, params) {
+                Ok(p) => self.deploy(p.developer, p.code).await,
+                Err(e) => Err(e),
+            },
+            "process_transaction" => match Self::parse_params::<PITransaction>(method, params) {
+                Ok(tx) => self
+                    .tx_engine
+                    .process_transaction(tx)
+                    .await
+                    .map(|_| CommandOutcome::TransactionQueued),
+                Err(e) => Err(e),
+            },
+            "isolate_data" => match Self::parse_params::<IsolateDataParams>(method, params) {
+                Ok(p) => self.isolation_shield.process_stream_verdict(p.data).await.map(CommandOutcome::from),
+                Err(e) => Err(e),
+            },
+            "halt_by_code_hash" => match Self::parse_params::<HaltByCodeHashParams>(method, params) {
+                Ok(p) => self.recall_code_hash(p.code_hash).await,
+                Err(e) => Err(e),
+            },
             _ => Err("Unknown command.".to_string()),
         }
     }
 
+    // Reject the command when the token bucket is exhausted
+    async fn check_rate_limit(&self) -> Result<(), String> {
+        if self.rate_limiter.lock().await.try_acquire() {
+            Ok(())
+        } else {
+            Err("rate limited".to_string())
+        }
+    }
+
+    fn parse_params<T: DeserializeOwned>(method: &str, params: Value) -> Result<T, String> {
+        serde_json::from_value(params).map_err(|e| format!("Invalid params for {}: {}", method, e))
+    }
+
+    // Deploy end to end and report the new app's id
+    async fn deploy(&self, developer: String, code: String) -> Result<CommandOutcome, String> {
+        let deployed = self.app_orchestrator.deploy_app(developer, code).await?;
+        Ok(CommandOutcome::Deployed { app_id: deployed.app_id })
+    }
+
+    // Halt every app built from a recalled code hash and record the recall as a controller event
+    async fn recall_code_hash(&self, code_hash: String) -> Result<CommandOutcome, String> {
+        let count = self.app_orchestrator.halt_by_code_hash(code_hash.clone()).await;
+        let outcome = CommandOutcome::AppsHalted { code_hash, count };
+        self.record_event("code_hash_recalled", &outcome.to_string(), None).await;
+        Ok(outcome)
+    }
+
     // Get aggregated metrics dashboard
     pub async fn get_dashboard(&self) -> ControllerDashboard {
         let ai_status = self.ai_core.get_status().await;
@@ -132,8 +613,9 @@ impl SuperAppController {
         let mainnet_metrics = self.mainnet_accelerator.get_metrics().await;
         let isolation_events = self.isolation_shield.get_events().await;
         let orchestrator_metrics = self.app_orchestrator.get_metrics().await;
+        // Controller state last, one lock at a time, each released at the end of its statement
         let status = self.status.lock().await.clone();
-        let events = self.events.lock().await.clone();
+        let recent_events = self.events.lock().await.iter().rev().take(5).cloned().collect(); // Last 5 events
 
         ControllerDashboard {
             status,
@@ -143,19 +625,136 @@ impl SuperAppController {
             mainnet_progress: mainnet_metrics.mainnet_open_progress,
             apps_managed: orchestrator_metrics.apps_managed,
             isolation_events_count: isolation_events.len(),
-            recent_events: events.into_iter().rev().take(5).collect(), // Last 5 events
+            recent_events,
+        }
+    }
+
+    // Report whether every subsystem is ready to serve traffic
+    pub async fn health_check(&self) -> HealthReport {
+        let ai_reachable = matches!(
+            tokio::time::timeout(self.config.health_probe_timeout, self.ai_core.probe_compliance()).await,
+            Ok(Ok(_))
+        );
+        let processor_running = self.tx_engine.is_processor_running().await;
+        let mainnet_progress_nonzero = self.mainnet_accelerator.get_metrics().await.mainnet_open_progress > 0.0;
+        let (accelerator_apps, orchestrator_apps) = self.app_orchestrator.reconcile_app_counts().await;
+        let apps_orchestrator_ok =
+            accelerator_apps == orchestrator_apps && self.app_orchestrator.get_metrics().await.uptime_rate > 0.0;
+
+        HealthReport {
+            ai_reachable,
+            processor_running,
+            mainnet_progress_nonzero,
+            apps_orchestrator_ok,
+            healthy: ai_reachable && processor_running && mainnet_progress_nonzero && apps_orchestrator_ok,
         }
     }
 
     // Log event
     async fn log_event(&self, event_type: &str, details: &str) {
+        self.record_event(event_type, details, None).await;
+    }
+
+    // Log a command outcome with its measured latency
+    async fn log_command(&self, command: &str, result: &Result<CommandOutcome, String>, elapsed: Duration) {
+        let details = match result {
+            Ok(_) => "Command succeeded.".to_string(),
+            Err(e) => format!("Command failed: {}", e),
+        };
+        self.record_event(command, &details, Some(elapsed.as_millis() as u64)).await;
+    }
+
+    async fn record_event(&self, event_type: &str, details: &str, duration_ms: Option<u64>) {
         let event = ControllerEvent {
+            schema_version: EVENT_SCHEMA_VERSION,
             id: uuid::Uuid::new_v4().to_string(),
             event_type: event_type.to_string(),
             details: details.to_string(),
-            timestamp: Utc::now(),
+            timestamp: self.clock.now(),
+            duration_ms,
         };
-        self.events.lock().await.push(event);
+        let mut events = self.events.lock().await;
+        events.push_back(event);
+        while events.len() > self.config.max_events.max(1) {
+            events.pop_front();
+        }
+    }
+
+    // Retained events, oldest first
+    pub async fn get_events(&self) -> Vec<ControllerEvent> {
+        self.events.lock().await.iter().cloned().collect()
+    }
+
+    // Write retained events to `path` as one JSON record per line, oldest first (readable by replay_events)
+    pub async fn export_event_log(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        let events = self.get_events().await;
+        let mut file = File::create(path).map_err(|e| format!("Event log open error: {}", e))?;
+        for event in &events {
+            let line = serde_json::to_string(event).map_err(|e| format!("Encode error: {}", e))?;
+            writeln!(file, "{}", line).map_err(|e| format!("Event log write error: {}", e))?;
+        }
+        Ok(())
+    }
+}
+
+// Why a persisted event log could not be replayed (line numbers are 1-based)
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReplayError {
+    Io(String),
+    Malformed { line: usize, reason: String },
+    UnsupportedVersion { line: usize, version: u64 },
+}
+
+impl std::fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplayError::Io(reason) => write!(f, "Event log read error: {}", reason),
+            ReplayError::Malformed { line, reason } => write!(f, "Malformed event on line {}: {}", line, reason),
+            ReplayError::UnsupportedVersion { line, version } => {
+                write!(f, "Unsupported event schema version {} on line {}", version, line)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+// Load a JSON-lines event log, migrating older records to EVENT_SCHEMA_VERSION.
+// Records without a schema_version are treated as v1; versions newer than ours are rejected.
+pub fn replay_events(path: impl AsRef<Path>) -> Result<Vec<ControllerEvent>, ReplayError> {
+    let file = File::open(path).map_err(|e| ReplayError::Io(e.to_string()))?;
+    let mut events = Vec::new();
+    for (index, line) in BufReader::new(file).lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.map_err(|e| ReplayError::Io(e.to_string()))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let malformed = |reason: String| ReplayError::Malformed { line: line_number, reason };
+
+        let mut record = match serde_json::from_str(&line).map_err(|e| malformed(e.to_string()))? {
+            Value::Object(record) => record,
+            _ => return Err(malformed("expected a JSON object".to_string())),
+        };
+        migrate_event(&mut record).map_err(|version| ReplayError::UnsupportedVersion { line: line_number, version })?;
+        events.push(serde_json::from_value(Value::Object(record)).map_err(|e| malformed(e.to_string()))?);
+    }
+    Ok(events)
+}
+
+// Upgrade one record in place to EVENT_SCHEMA_VERSION; Err carries a version we can't migrate
+fn migrate_event(record: &mut Map<String, Value>) -> Result<(), u64> {
+    // A non-integer version can't be interpreted, so it is reported as version 0
+    let version = record.get("schema_version").map_or(Some(1), Value::as_u64).ok_or(0u64)?;
+    match version {
+        1 => {
+            // v1 -> v2: duration_ms added
+            record.insert("duration_ms".to_string(), Value::Null);
+            record.insert("schema_version".to_string(), Value::from(EVENT_SCHEMA_VERSION));
+            Ok(())
+        }
+        v if v == u64::from(EVENT_SCHEMA_VERSION) => Ok(()),
+        v => Err(v),
     }
 }
 
@@ -184,7 +783,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Run Super App in background
     tokio::spawn(async move {
-        if let Err(e) = controller.run_super_app().await {
+        if let Err(e) = controller.run_super_app(CancellationToken::new()).await {
             println!("Super App Error: {}", e);
         }
     });
@@ -0,0 +1,31 @@
+// src/hyper_core/rust/src/telemetry.rs
+// Structured logging shim for the async Super App modules.
+// With the `tracing-logs` feature, re-exports tracing's event macros; without it, events compile to nothing.
+// Integrate with the async modules: use crate::telemetry::{info, warn};
+
+#[cfg(feature = "tracing-logs")]
+pub(crate) use tracing::{info, warn};
+
+// No-op stand-in for `name = value` / `name = %value` / `name` fields followed by a message.
+// Field values are still borrowed so variables used only for logging don't trigger unused warnings.
+#[cfg(not(feature = "tracing-logs"))]
+macro_rules! disabled_event {
+    ($message:literal $(,)?) => {
+        ()
+    };
+    ($name:ident = %$value:expr, $($rest:tt)*) => {{
+        let _ = &$value;
+        $crate::telemetry::disabled_event!($($rest)*)
+    }};
+    ($name:ident = $value:expr, $($rest:tt)*) => {{
+        let _ = &$value;
+        $crate::telemetry::disabled_event!($($rest)*)
+    }};
+    ($name:ident, $($rest:tt)*) => {{
+        let _ = &$name;
+        $crate::telemetry::disabled_event!($($rest)*)
+    }};
+}
+
+#[cfg(not(feature = "tracing-logs"))]
+pub(crate) use {disabled_event, disabled_event as info, disabled_event as warn};
@@ -3,7 +3,7 @@
 // Provides AI-driven ethical governance and oversight for the Pi Ecosystem.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, Address, Env, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct UltimateAiGovernanceEthicalOverseer;
@@ -20,8 +20,9 @@ pub struct EthicalAudit {
 
 #[contractimpl]
 impl UltimateAiGovernanceEthicalOverseer {
-    /// Initialize the Overseer
-    pub fn init(env: Env) -> UltimateAiGovernanceEthicalOverseer {
+    /// Initialize the Overseer with the admin allowed to evolve governance rules
+    pub fn init(env: Env, admin: Address) -> UltimateAiGovernanceEthicalOverseer {
+        crate::access_control::set_admin(&env, &admin);
         log!(&env, "Ultimate AI Governance Ethical Overseer Initialized");
         UltimateAiGovernanceEthicalOverseer
     }
@@ -62,8 +63,9 @@ impl UltimateAiGovernanceEthicalOverseer {
         }
     }
 
-    /// Evolve governance rules
+    /// Evolve governance rules (admin only)
     pub fn evolve_governance_rules(env: Env) -> Symbol {
+        crate::access_control::require_admin(&env);
         // Simulate AI evolution based on audits
         let status = crate::hyper_ecosystem_monitor::HyperEcosystemMonitor::evolve_ecosystem(env.clone());
         if status == Symbol::new(&env, "evolution_complete") {
@@ -74,6 +76,11 @@ impl UltimateAiGovernanceEthicalOverseer {
         }
     }
 
+    /// Transfer admin rights (current admin only)
+    pub fn transfer_admin(env: Env, new_admin: Address) {
+        crate::access_control::transfer_admin(&env, new_admin);
+    }
+
     /// Get governance status
     pub fn get_governance_status(env: Env) -> Map<Symbol, i64> {
         let mut status = Map::new(&env);
@@ -3,7 +3,7 @@
 // Automates ultimate deployment of the Pi Ecosystem Super App.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, log};
+use soroban_sdk::{contract, contractimpl, Address, Env, Symbol, Vec, log};
 
 #[contract]
 pub struct UltimateDeploymentScript;
@@ -56,6 +56,77 @@ impl UltimateDeploymentScript {
         Symbol::new(&env, "deployment_success")
     }
 
+    /// Initialize every contract in dependency order (each module's cross-contract calls only
+    /// reach modules initialized before it); returns the initialized module symbols in order.
+    /// `admin` must authorize the call and is stored once for every contract with admin-gated rules.
+    /// Panics if the ecosystem was already initialized.
+    /// `ultimate_integration_core` is declared in lib.rs but has no source, so it is not included.
+    pub fn init_all(env: Env, admin: Address) -> Vec<Symbol> {
+        admin.require_auth();
+        // The modules share this contract's storage, so the admin is stored here once; set_admin refuses a second run
+        crate::access_control::set_admin(&env, &admin);
+
+        let mut initialized = Vec::new(&env);
+        macro_rules! init_module {
+            ($symbol:literal, $module:ident :: $contract:ident) => {
+                crate::$module::$contract::init(env.clone());
+                initialized.push_back(Symbol::new(&env, $symbol));
+            };
+            // Admin-gated modules: their init only stores the admin, which is already in place
+            ($symbol:literal, $module:ident :: $contract:ident, admin) => {
+                log!(&env, "{} Initialized with shared admin", Symbol::new(&env, $symbol));
+                initialized.push_back(Symbol::new(&env, $symbol));
+            };
+        }
+
+        // Foundation: no upstream dependencies beyond the AI core
+        init_module!("ahi_ai_core", ahi_ai_core::AhiAiCore);
+        init_module!("pi_stablecoin_manager", pi_stablecoin_manager::PiStablecoinManager);
+        init_module!("autonomous_app_builder", autonomous_app_builder::AutonomousAppBuilder);
+        init_module!("hyper_ecosystem_monitor", hyper_ecosystem_monitor::HyperEcosystemMonitor);
+        init_module!("quantum_security_layer", quantum_security_layer::QuantumSecurityLayer);
+        init_module!("final_hyper_expansion_module", final_hyper_expansion_module::FinalHyperExpansionModule);
+        init_module!("ecosystem_readme_config", ecosystem_readme_config::EcosystemReadmeConfig, admin);
+
+        // Compliance and governance chain
+        init_module!("purity_enforcer", pi_purity_accountability_enforcer::PiPurityAccountabilityEnforcer);
+        init_module!("oracle_verifier", global_pi_oracle_compliance_verifier::GlobalPiOracleComplianceVerifier, admin);
+        init_module!("ethical_overseer", ultimate_ai_governance_ethical_overseer::UltimateAiGovernanceEthicalOverseer, admin);
+        init_module!("synthesis_ui_hub", final_ecosystem_synthesis_ui_hub::FinalEcosystemSynthesisUiHub);
+        init_module!("master_control", master_control_final_integration_script::MasterControlFinalIntegrationScript);
+        init_module!("guardian_summary", ultimate_ecosystem_guardian_summary_script::UltimateEcosystemGuardianSummaryScript, admin);
+        init_module!("eternal_guardian", absolute_final_ecosystem_seal_eternal_guardian::AbsoluteFinalEcosystemSealEternalGuardian, admin);
+        init_module!("predictive_maintenance", quantum_ai_optimizer_predictive_maintenance::QuantumAiOptimizerPredictiveMaintenance);
+        init_module!("realtime_sync", pi_mainnet_integration_real_time_synchronization::PiMainnetIntegrationRealTimeSynchronization, admin);
+        init_module!("swarm_hub", global_decentralized_ai_swarm_intelligence_hub::GlobalDecentralizedAiSwarmIntelligenceHub, admin);
+        init_module!("launch_governance", pi_mainnet_launch_governance_protocol::PiMainnetLaunchGovernanceProtocol, admin);
+        init_module!("mainnet_activation", ultimate_pi_mainnet_activation_eternal_stability::UltimatePiMainnetActivationEternalStability);
+        init_module!("mainnet_supremacy", final_pi_mainnet_supremacy_global_domination::FinalPiMainnetSupremacyGlobalDomination);
+        init_module!("infinite_expansion", infinite_pi_ecosystem_expansion_universal_integration::InfinitePiEcosystemExpansionUniversalIntegration);
+        init_module!("test_suite_validation", comprehensive_test_suite_validation::ComprehensiveTestSuiteValidation);
+        init_module!("holographic_archive", ultimate_ecosystem_documentation_holographic_archive::UltimateEcosystemDocumentationHolographicArchive);
+        init_module!("anti_quantum_security", eternal_quantum_security_anti_quantum_threat::EternalQuantumSecurityAntiQuantumThreat, admin);
+        init_module!("universal_capstone", final_universal_integration_supremacy_capstone::FinalUniversalIntegrationSupremacyCapstone, admin);
+
+        // Pi Network mainnet chain
+        init_module!("ultimate_pi_mainnet_enabler", ultimate_pi_mainnet_enabler::UltimatePiMainnetEnabler);
+        init_module!("pi_network_mainnet_trigger", pi_network_mainnet_trigger::PiNetworkMainnetTrigger);
+        init_module!("pi_network_hyper_oracle", pi_network_hyper_oracle::PiNetworkHyperOracle);
+        init_module!("pi_network_global_announcer", pi_network_global_announcer::PiNetworkGlobalAnnouncer);
+        init_module!("decentralization_engine", pi_network_decentralization_engine::PiNetworkDecentralizationEngine);
+        init_module!("quantum_security_network", pi_network_quantum_security_network::PiNetworkQuantumSecurityNetwork);
+        init_module!("governance_council", pi_network_decentralized_governance_council::PiNetworkDecentralizedGovernanceCouncil);
+        init_module!("decentralization_capstone", pi_network_full_decentralization_capstone::PiNetworkFullDecentralizationCapstone);
+        init_module!("decentralization_monitor", pi_network_eternal_decentralization_monitor::PiNetworkEternalDecentralizationMonitor);
+        init_module!("perfection_module", pi_network_ultimate_perfection_module::PiNetworkUltimatePerfectionModule);
+        init_module!("evolution_engine", pi_network_super_advanced_evolution_engine::PiNetworkSuperAdvancedEvolutionEngine);
+        init_module!("super_intelligence_core", pi_network_super_intelligence_core::PiNetworkSuperIntelligenceCore);
+        init_module!("eternal_supremacy_capstone", pi_network_final_eternal_supremacy_capstone::PiNetworkFinalEternalSupremacyCapstone);
+
+        log!(&env, "All Contracts Initialized: {}", initialized.len());
+        initialized
+    }
+
     /// Validate deployment (PI-exclusive check)
     pub fn validate_deployment(env: Env, component: Symbol) -> Result<Symbol, Symbol> {
         // Filter via AI
@@ -3,7 +3,7 @@
 // Archives Pi Ecosystem documentation in holographic eternal format.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, Env, String, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct UltimateEcosystemDocumentationHolographicArchive;
@@ -85,13 +85,15 @@ impl UltimateEcosystemDocumentationHolographicArchive {
     }
 
     /// Render archive hologram
-    pub fn render_archive_hologram(env: Env, entry: ArchiveEntry) -> Vec<Symbol> {
-        let hologram = Vec::from_array(&env, [
-            Symbol::new(&env, "Holographic Archive Hologram"),
-            entry.document_type,
-            Symbol::new(&env, &format!("Integrity: {}", entry.archive_integrity)),
+    pub fn render_archive_hologram(env: Env, entry: ArchiveEntry) -> Vec<String> {
+        let mut hologram = Vec::from_array(&env, [
+            String::from_str(&env, "Holographic Archive Hologram"),
+            crate::hologram::symbol_to_string(&env, &entry.document_type),
+            String::from_str(&env, &format!("Integrity: {}", entry.archive_integrity)),
         ]);
-        hologram.extend(entry.holographic_data);
+        for data in entry.holographic_data.iter() {
+            hologram.push_back(crate::hologram::symbol_to_string(&env, &data));
+        }
         log!(&env, "Archive Hologram Rendered");
         hologram
     }
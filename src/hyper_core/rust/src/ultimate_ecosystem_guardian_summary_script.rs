@@ -3,7 +3,7 @@
 // Generates guardian summaries and enforces eternal Pi Ecosystem protection.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, Address, Env, String, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct UltimateEcosystemGuardianSummaryScript;
@@ -20,8 +20,9 @@ pub struct GuardianSummary {
 
 #[contractimpl]
 impl UltimateEcosystemGuardianSummaryScript {
-    /// Initialize the Guardian Script
-    pub fn init(env: Env) -> UltimateEcosystemGuardianSummaryScript {
+    /// Initialize the Guardian Script with the admin allowed to set dependency addresses
+    pub fn init(env: Env, admin: Address) -> UltimateEcosystemGuardianSummaryScript {
+        crate::access_control::set_admin(&env, &admin);
         log!(&env, "Ultimate Ecosystem Guardian Summary Script Initialized");
         UltimateEcosystemGuardianSummaryScript
     }
@@ -37,11 +38,14 @@ impl UltimateEcosystemGuardianSummaryScript {
         key_metrics.set(Symbol::new(&env, "tests_passed"), integration_status.get(Symbol::new(&env, "tests_passed")).unwrap_or(0));
         key_metrics.set(Symbol::new(&env, "syntheses_generated"), hub_status.get(Symbol::new(&env, "syntheses_generated")).unwrap_or(0));
 
+        let summaries_generated = Self::read_counter(&env, "summaries_generated") + 1;
+        env.storage().instance().set(&Symbol::new(&env, "summaries_generated"), &summaries_generated);
+
         let summary = GuardianSummary {
             id: Symbol::new(&env, &format!("summary_{}", env.ledger().sequence())),
             summary_type,
             key_metrics,
-            threats_halted: 0, // Simulated
+            threats_halted: Self::read_counter(&env, "threats_halted"),
             supremacy_level: 100, // Eternal
             timestamp: env.ledger().timestamp(),
         };
@@ -54,22 +58,36 @@ impl UltimateEcosystemGuardianSummaryScript {
     pub fn enforce_guardian_protection(env: Env, summary: GuardianSummary) -> Symbol {
         if summary.supremacy_level < 100 {
             log!(&env, "Supremacy Threat Detected: Halting Ecosystem");
-            crate::ahi_ai_core::AhiAiCore::enforce_compliance(env.clone());
+            let threats_halted = Self::read_counter(&env, "threats_halted") + 1;
+            env.storage().instance().set(&Symbol::new(&env, "threats_halted"), &threats_halted);
+            // Falls back to the in-crate AI core when no deployed one is set, so a breach is never left unenforced
+            crate::contract_deps::enforce_compliance(&env);
             Symbol::new(&env, "protection_enforced")
         } else {
             Symbol::new(&env, "protection_stable")
         }
     }
 
+    /// Set a dependency contract address, e.g. "ahi_ai_core" (admin only)
+    pub fn set_dependency(env: Env, name: Symbol, address: Address) {
+        crate::access_control::require_admin(&env);
+        crate::contract_deps::set_dependency(&env, name, address);
+    }
+
     /// Get guardian status
     pub fn get_guardian_status(env: Env) -> Map<Symbol, i64> {
         let mut status = Map::new(&env);
-        status.set(Symbol::new(&env, "summaries_generated"), 1000000); // Simulated millions
-        status.set(Symbol::new(&env, "threats_neutralized"), 0); // Eternal protection
+        status.set(Symbol::new(&env, "summaries_generated"), Self::read_counter(&env, "summaries_generated"));
+        status.set(Symbol::new(&env, "threats_neutralized"), Self::read_counter(&env, "threats_halted"));
         status.set(Symbol::new(&env, "eternal_supremacy"), 100);
         status
     }
 
+    /// Read a stored counter (zero until first incremented)
+    fn read_counter(env: &Env, name: &str) -> i64 {
+        env.storage().instance().get(&Symbol::new(env, name)).unwrap_or(0)
+    }
+
     /// Update guardian rules
     pub fn update_guardian_rules(env: Env, new_rule: Symbol) -> Result<Symbol, Symbol> {
         // Validate via Master Control
@@ -79,12 +97,12 @@ impl UltimateEcosystemGuardianSummaryScript {
     }
 
     /// Render summary as holographic report
-    pub fn render_holographic_report(env: Env, summary: GuardianSummary) -> Vec<Symbol> {
+    pub fn render_holographic_report(env: Env, summary: GuardianSummary) -> Vec<String> {
         let report = Vec::from_array(&env, [
-            Symbol::new(&env, "Holographic Guardian Report"),
-            summary.summary_type,
-            Symbol::new(&env, &format!("Supremacy Level: {}", summary.supremacy_level)),
-            Symbol::new(&env, &format!("Threats Halted: {}", summary.threats_halted)),
+            String::from_str(&env, "Holographic Guardian Report"),
+            crate::hologram::symbol_to_string(&env, &summary.summary_type),
+            String::from_str(&env, &format!("Supremacy Level: {}", summary.supremacy_level)),
+            String::from_str(&env, &format!("Threats Halted: {}", summary.threats_halted)),
         ]);
         log!(&env, "Holographic Report Rendered");
         report
@@ -3,7 +3,7 @@
 // Activates Pi mainnet with eternal stability and supremacy.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, Env, String, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct UltimatePiMainnetActivationEternalStability;
@@ -89,12 +89,12 @@ impl UltimatePiMainnetActivationEternalStability {
     }
 
     /// Render activation hologram
-    pub fn render_activation_hologram(env: Env, event: ActivationEvent) -> Vec<Symbol> {
+    pub fn render_activation_hologram(env: Env, event: ActivationEvent) -> Vec<String> {
         let hologram = Vec::from_array(&env, [
-            Symbol::new(&env, "Ultimate Activation Hologram"),
-            event.activation_phase,
-            Symbol::new(&env, &format!("Stability Score: {}", event.stability_score)),
-            Symbol::new(&env, &format!("Eternal Active: {}", event.eternal_active)),
+            String::from_str(&env, "Ultimate Activation Hologram"),
+            crate::hologram::symbol_to_string(&env, &event.activation_phase),
+            String::from_str(&env, &format!("Stability Score: {}", event.stability_score)),
+            String::from_str(&env, &format!("Eternal Active: {}", event.eternal_active)),
         ]);
         log!(&env, "Activation Hologram Rendered");
         hologram
@@ -3,7 +3,7 @@
 // Enables ultimate activation of Pi Network mainnet.
 // Dependencies: soroban-sdk = "0.9" in Cargo.toml
 
-use soroban_sdk::{contract, contractimpl, Env, Symbol, Vec, Map, log};
+use soroban_sdk::{contract, contractimpl, Env, String, Symbol, Vec, Map, log};
 
 #[contract]
 pub struct UltimatePiMainnetEnabler;
@@ -89,12 +89,12 @@ impl UltimatePiMainnetEnabler {
     }
 
     /// Render enabler hologram
-    pub fn render_enabler_hologram(env: Env, enablement: MainnetEnablement) -> Vec<Symbol> {
+    pub fn render_enabler_hologram(env: Env, enablement: MainnetEnablement) -> Vec<String> {
         let hologram = Vec::from_array(&env, [
-            Symbol::new(&env, "Ultimate Enabler Hologram"),
-            enablement.enablement_phase,
-            Symbol::new(&env, &format!("Mainnet Enabled: {}", enablement.mainnet_enabled)),
-            Symbol::new(&env, &format!("Enablement Level: {}", enablement.enablement_level)),
+            String::from_str(&env, "Ultimate Enabler Hologram"),
+            crate::hologram::symbol_to_string(&env, &enablement.enablement_phase),
+            String::from_str(&env, &format!("Mainnet Enabled: {}", enablement.mainnet_enabled)),
+            String::from_str(&env, &format!("Enablement Level: {}", enablement.enablement_level)),
         ]);
         log!(&env, "Enabler Hologram Rendered");
         hologram
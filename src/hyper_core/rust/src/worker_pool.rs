@@ -0,0 +1,40 @@
+// src/hyper_core/rust/src/worker_pool.rs
+// Thread pool shared by the async Super App modules' parallel (rayon) sections.
+// WorkerPool::default() runs on rayon's global pool; with_threads caps a module at its own dedicated pool.
+// Dependencies: Add to Cargo.toml: rayon = "1.5"
+// Integrate with the async modules: use crate::worker_pool::WorkerPool;
+
+use std::sync::Arc;
+
+// Where parallel sections run (cheap to clone: a dedicated pool is shared)
+#[derive(Clone, Default)]
+pub struct WorkerPool {
+    pool: Option<Arc<rayon::ThreadPool>>, // rayon's global pool when None
+}
+
+impl WorkerPool {
+    // Dedicated pool with a fixed number of worker threads
+    pub fn with_threads(threads: usize) -> Result<Self, String> {
+        if threads == 0 {
+            return Err("Invalid parallelism: must be at least one thread".to_string());
+        }
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .map_err(|e| format!("Thread pool error: {}", e))?;
+        Ok(Self { pool: Some(Arc::new(pool)) })
+    }
+
+    // Worker threads available to parallel sections
+    pub fn threads(&self) -> usize {
+        self.install(rayon::current_num_threads)
+    }
+
+    // Run op inside the dedicated pool, if one was configured
+    pub fn install<R: Send>(&self, op: impl FnOnce() -> R + Send) -> R {
+        match &self.pool {
+            Some(pool) => pool.install(op),
+            None => op(),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+// Fixtures shared by the async module tests.
+// Integrate with a test file: mod common; then use super::common::StableModel;
+
+use crate::hyper_ai_core::VolatilityModel;
+
+// Treats every input as stable so only the code under test decides
+pub struct StableModel;
+
+impl VolatilityModel for StableModel {
+    fn score(&self, _input: &str) -> f64 {
+        0.0
+    }
+}
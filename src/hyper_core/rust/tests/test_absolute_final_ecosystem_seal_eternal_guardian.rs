@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod test_absolute_final_ecosystem_seal_eternal_guardian {
+    use soroban_sdk::testutils::{Events, Ledger};
+    use soroban_sdk::{vec, Env, IntoVal, Symbol};
+    use crate::absolute_final_ecosystem_seal_eternal_guardian::AbsoluteFinalEcosystemSealEternalGuardian;
+
+    #[test]
+    fn test_seal_publishes_event() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, AbsoluteFinalEcosystemSealEternalGuardian);
+
+        let seal = env.as_contract(&contract_id, || {
+            AbsoluteFinalEcosystemSealEternalGuardian::apply_eternal_seal(env.clone(), Symbol::new(&env, "mainnet"), false)
+        });
+
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "eternal_seal_applied"), Symbol::new(&env, "mainnet")).into_val(&env),
+                    (seal.id, 100i64, true, seal.timestamp).into_val(&env),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_repeat_seal_returns_existing_unless_forced() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, AbsoluteFinalEcosystemSealEternalGuardian);
+        let component = Symbol::new(&env, "ecosystem_core");
+
+        env.ledger().set_sequence_number(7);
+        let first = env.as_contract(&contract_id, || {
+            AbsoluteFinalEcosystemSealEternalGuardian::apply_eternal_seal(env.clone(), component.clone(), false)
+        });
+
+        env.ledger().set_sequence_number(8);
+        env.as_contract(&contract_id, || {
+            let repeat = AbsoluteFinalEcosystemSealEternalGuardian::apply_eternal_seal(env.clone(), component.clone(), false);
+            assert_eq!(repeat.id, first.id, "Repeat call returns the stored seal");
+            assert_eq!(AbsoluteFinalEcosystemSealEternalGuardian::get_seal(env.clone(), component.clone()).unwrap().id, Symbol::new(&env, "seal_7"));
+            assert!(AbsoluteFinalEcosystemSealEternalGuardian::get_seal(env.clone(), Symbol::new(&env, "mainnet")).is_none());
+
+            // Forcing reseals and replaces the stored seal
+            let forced = AbsoluteFinalEcosystemSealEternalGuardian::apply_eternal_seal(env.clone(), component.clone(), true);
+            assert_eq!(forced.id, Symbol::new(&env, "seal_8"));
+            assert_eq!(AbsoluteFinalEcosystemSealEternalGuardian::get_seal(env.clone(), component.clone()).unwrap().id, forced.id);
+        });
+    }
+}
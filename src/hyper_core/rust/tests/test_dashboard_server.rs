@@ -0,0 +1,44 @@
+#[cfg(all(test, feature = "http-server"))]
+mod test_dashboard_server {
+    use std::sync::Arc;
+    use tokio::net::TcpListener;
+    use tokio_util::sync::CancellationToken;
+    use crate::hyper_ai_core::AutonomousHyperAI;
+    use crate::pi_transaction_engine::PITransactionEngine;
+    use crate::pi_mainnet_accelerator::PiMainnetAccelerator;
+    use crate::ecosystem_isolation_shield::EcosystemIsolationShield;
+    use crate::developer_app_orchestrator::DeveloperAppOrchestrator;
+    use crate::super_app_controller::SuperAppController;
+    use crate::pi_ecosystem_dashboard::PiEcosystemDashboardModule;
+    use crate::dashboard_server::serve_dashboard;
+
+    fn build_dashboard() -> Arc<PiEcosystemDashboardModule> {
+        let ai_core = Arc::new(AutonomousHyperAI::new());
+        let tx_engine = Arc::new(PITransactionEngine::new(ai_core.clone()));
+        let mainnet_accelerator = Arc::new(PiMainnetAccelerator::new(ai_core.clone(), tx_engine.clone()));
+        let isolation_shield = Arc::new(EcosystemIsolationShield::new(ai_core.clone(), tx_engine.clone(), mainnet_accelerator.clone()));
+        let app_orchestrator = Arc::new(DeveloperAppOrchestrator::new(ai_core.clone(), tx_engine.clone(), mainnet_accelerator.clone(), isolation_shield.clone()));
+        let controller = Arc::new(SuperAppController::new(ai_core.clone(), tx_engine.clone(), mainnet_accelerator.clone(), isolation_shield.clone(), app_orchestrator.clone()));
+        Arc::new(PiEcosystemDashboardModule::new(ai_core, tx_engine, mainnet_accelerator, isolation_shield, app_orchestrator, controller))
+    }
+
+    #[tokio::test]
+    async fn test_dashboard_and_metrics_endpoints() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let shutdown = CancellationToken::new();
+        let server = tokio::spawn(serve_dashboard(build_dashboard(), listener, shutdown.clone()));
+
+        let response = reqwest::get(format!("{}/dashboard", base)).await.unwrap();
+        assert_eq!(response.status(), 200);
+        let dashboard: serde_json::Value = response.json().await.expect("dashboard is JSON");
+        assert_eq!(dashboard["app_overview"]["apps_running"], 0);
+
+        let response = reqwest::get(format!("{}/metrics", base)).await.unwrap();
+        assert_eq!(response.status(), 200);
+        assert!(response.text().await.unwrap().contains("# TYPE superpi_compliance_rate gauge"));
+
+        shutdown.cancel();
+        server.await.unwrap().expect("server stops cleanly");
+    }
+}
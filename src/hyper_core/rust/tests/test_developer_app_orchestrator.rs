@@ -0,0 +1,432 @@
+#[cfg(test)]
+mod common;
+
+#[cfg(test)]
+mod test_developer_app_orchestrator {
+    use std::sync::Arc;
+    use std::collections::HashSet;
+    use crate::hyper_ai_core::{AutonomousHyperAI, ComplianceMode};
+    use crate::pi_transaction_engine::PITransactionEngine;
+    use crate::pi_mainnet_accelerator::PiMainnetAccelerator;
+    use crate::ecosystem_isolation_shield::EcosystemIsolationShield;
+    use crate::developer_app_orchestrator::{can_transition, AppStatus, DeveloperAppOrchestrator, OrchestratorSnapshot};
+    use crate::error::SuperPiError;
+    use super::common::StableModel;
+
+    fn build_orchestrator(ai_core: Arc<AutonomousHyperAI>) -> (Arc<PiMainnetAccelerator>, DeveloperAppOrchestrator) {
+        let tx_engine = Arc::new(PITransactionEngine::new(ai_core.clone()));
+        let mainnet_accelerator = Arc::new(PiMainnetAccelerator::new(ai_core.clone(), tx_engine.clone()));
+        let isolation_shield = Arc::new(EcosystemIsolationShield::new(ai_core.clone(), tx_engine.clone(), mainnet_accelerator.clone()));
+        let orchestrator = DeveloperAppOrchestrator::new(ai_core, tx_engine, mainnet_accelerator.clone(), isolation_shield);
+        (mainnet_accelerator, orchestrator)
+    }
+
+    // Deploy and finish the build so the app is Running
+    async fn deploy_running(orchestrator: &DeveloperAppOrchestrator, developer: &str, code: &str) -> String {
+        let deployed = orchestrator.deploy_app(developer.to_string(), code.to_string()).await.unwrap();
+        orchestrator.finalize_build(&deployed.app_id).await.unwrap();
+        deployed.app_id
+    }
+
+    #[tokio::test]
+    async fn test_failed_assignment_keeps_app_counts_equal() {
+        let (accelerator, orchestrator) = build_orchestrator(Arc::new(AutonomousHyperAI::new()));
+
+        // Mainnet not accelerated: no nodes, so assignment must fail without counting
+        assert!(accelerator.manage_apps(vec!["a1".to_string()]).await.is_err());
+        let err = orchestrator.deploy_app("dev_1".to_string(), "PI".to_string()).await.unwrap_err();
+        assert!(matches!(err, SuperPiError::Unavailable(_)), "{:?}", err);
+
+        let (accelerator_count, orchestrator_count) = orchestrator.reconcile_app_counts().await;
+        assert_eq!(accelerator_count, orchestrator_count, "Counts stay in sync after failures");
+        assert_eq!(orchestrator_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_pooled_deploy_records_each_app_once() {
+        let ai_core = Arc::new(AutonomousHyperAI::new().with_model(Box::new(StableModel)));
+        let (accelerator, orchestrator) = build_orchestrator(ai_core);
+        accelerator.accelerate_mainnet().await.unwrap();
+
+        let batch: Vec<(String, String)> = (0..20)
+            .map(|i| (format!("dev_{}", i), format!("Stable PI app code {}", i)))
+            .collect();
+        let results = orchestrator.deploy_apps_pooled(batch, 4).await;
+
+        assert_eq!(results.len(), 20);
+        assert!(results.iter().all(|r| r.is_ok()), "All deployments succeed: {:?}", results);
+        let unique: HashSet<&String> = results.iter().map(|r| &r.as_ref().unwrap().app_id).collect();
+        assert_eq!(unique.len(), 20, "Each deployment yields a distinct app");
+
+        let (accelerator_count, orchestrator_count) = orchestrator.reconcile_app_counts().await;
+        assert_eq!(orchestrator_count, 20);
+        assert_eq!(accelerator_count, 20);
+    }
+
+    #[tokio::test]
+    async fn test_app_lookups_by_id_status_and_developer() {
+        let ai_core = Arc::new(AutonomousHyperAI::new().with_model(Box::new(StableModel)));
+        let (accelerator, orchestrator) = build_orchestrator(ai_core);
+        accelerator.accelerate_mainnet().await.unwrap();
+
+        let batch = [("alice", "Stable PI app code 1"), ("alice", "Stable PI app code 2"), ("bob", "Stable PI app code 3")];
+        for (developer, code) in batch {
+            deploy_running(&orchestrator, developer, code).await;
+        }
+
+        let bob_apps = orchestrator.apps_by_developer("bob").await;
+        assert_eq!(bob_apps.len(), 1);
+        assert_eq!(orchestrator.apps_by_developer("alice").await.len(), 2);
+        assert!(orchestrator.apps_by_developer("carol").await.is_empty());
+
+        let bob_app_id = bob_apps[0].id.clone();
+        orchestrator.halt_app(&bob_app_id).await.unwrap();
+
+        let found = orchestrator.get_app(&bob_app_id).await.expect("app is found by id");
+        assert_eq!(found.developer, "bob");
+        assert_eq!(found.status, AppStatus::Halted);
+        assert!(orchestrator.get_app("missing").await.is_none());
+
+        assert_eq!(orchestrator.apps_by_status(AppStatus::Running).await.len(), 2);
+        assert_eq!(orchestrator.apps_by_status(AppStatus::Halted).await.len(), 1);
+        assert!(orchestrator.apps_by_status(AppStatus::Building).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_export_state_json_lists_apps_and_running_count() {
+        let ai_core = Arc::new(AutonomousHyperAI::new().with_model(Box::new(StableModel)));
+        let (accelerator, orchestrator) = build_orchestrator(ai_core);
+        accelerator.accelerate_mainnet().await.unwrap();
+
+        deploy_running(&orchestrator, "alice", "Stable PI app code 1").await;
+        let halted = deploy_running(&orchestrator, "bob", "Stable PI app code 2").await;
+        orchestrator.halt_app(&halted).await.unwrap();
+
+        let state: serde_json::Value = serde_json::from_str(&orchestrator.export_state_json().await.unwrap()).unwrap();
+        assert_eq!(state["metrics"]["apps_managed"], 2);
+        assert_eq!(state["running_apps"], 1);
+
+        let apps = state["apps"].as_array().unwrap();
+        let ids: HashSet<&str> = apps.iter().map(|a| a["id"].as_str().unwrap()).collect();
+        let alice_app = &orchestrator.apps_by_developer("alice").await[0];
+        assert!(ids.contains(alice_app.id.as_str()) && ids.contains(halted.as_str()));
+        assert!(apps.iter().all(|a| a["code_hash"].as_str().unwrap().len() == 64));
+    }
+
+    #[tokio::test]
+    async fn test_halt_developer_halts_only_their_apps() {
+        let ai_core = Arc::new(AutonomousHyperAI::new().with_model(Box::new(StableModel)));
+        let (accelerator, orchestrator) = build_orchestrator(ai_core);
+        accelerator.accelerate_mainnet().await.unwrap();
+
+        for i in 0..3 {
+            deploy_running(&orchestrator, "mallory", &format!("Stable PI app code {}", i)).await;
+        }
+        deploy_running(&orchestrator, "alice", "Stable PI app code 9").await;
+
+        assert_eq!(orchestrator.halt_developer("mallory").await, 3);
+        assert_eq!(orchestrator.apps_by_status(AppStatus::Halted).await.len(), 3);
+        assert!(orchestrator.apps_by_developer("mallory").await.iter().all(|a| a.status == AppStatus::Halted));
+        assert_eq!(orchestrator.apps_by_developer("alice").await[0].status, AppStatus::Running);
+
+        // Already-halted apps are not counted again
+        assert_eq!(orchestrator.halt_developer("mallory").await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_restores_into_fresh_orchestrator() {
+        let ai_core = Arc::new(AutonomousHyperAI::new().with_model(Box::new(StableModel)));
+        let (accelerator, blue) = build_orchestrator(ai_core.clone());
+        accelerator.accelerate_mainnet().await.unwrap();
+        blue.deploy_app("alice".to_string(), "Stable PI app code 1".to_string()).await.unwrap();
+        blue.deploy_app("bob".to_string(), "Stable PI app code 2".to_string()).await.unwrap();
+        let halted = blue.apps_by_developer("bob").await[0].id.clone();
+        blue.halt_app(&halted).await.unwrap();
+
+        // Round-trip through JSON as an operator would between instances
+        let json = serde_json::to_string(&blue.snapshot().await).unwrap();
+        let snapshot: OrchestratorSnapshot = serde_json::from_str(&json).unwrap();
+
+        // The target accelerator can't take the apps until it is accelerated, and the failed restore changes nothing
+        let (green_accelerator, green) = build_orchestrator(ai_core);
+        let err = green.restore(snapshot.clone()).await.unwrap_err();
+        assert!(err.starts_with("Restore rejected"), "{}", err);
+        assert_eq!(green.reconcile_app_counts().await, (0, 0));
+
+        green_accelerator.accelerate_mainnet().await.unwrap();
+        green.restore(snapshot).await.unwrap();
+        assert_eq!(green.reconcile_app_counts().await, (2, 2), "Restored apps, halted included, are placed on nodes");
+
+        let metrics = green.get_metrics().await;
+        assert_eq!(metrics.apps_managed, 2);
+        assert_eq!(metrics.pi_consumed_total, blue.get_metrics().await.pi_consumed_total);
+        assert_eq!(green.get_app(&halted).await.unwrap().status, AppStatus::Halted);
+        assert_eq!(green.apps_by_developer("alice").await.len(), 1);
+
+        // Restoring over a populated orchestrator swaps the node assignments rather than adding to them
+        green.deploy_app("carol".to_string(), "Stable PI app code 3".to_string()).await.unwrap();
+        assert_eq!(green.reconcile_app_counts().await, (3, 3));
+        green.restore(blue.snapshot().await).await.unwrap();
+        assert_eq!(green.reconcile_app_counts().await, (2, 2));
+        assert!(green.apps_by_developer("carol").await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_restore_rejects_corrupt_snapshot() {
+        let ai_core = Arc::new(AutonomousHyperAI::new().with_model(Box::new(StableModel)));
+        let (accelerator, orchestrator) = build_orchestrator(ai_core);
+        accelerator.accelerate_mainnet().await.unwrap();
+        orchestrator.deploy_app("alice".to_string(), "Stable PI app code 1".to_string()).await.unwrap();
+
+        let mut snapshot = orchestrator.snapshot().await;
+        snapshot.metrics.apps_managed = 5;
+        snapshot.apps.clear();
+
+        let err = orchestrator.restore(snapshot).await.unwrap_err();
+        assert!(err.starts_with("Corrupt snapshot"), "{}", err);
+        assert_eq!(orchestrator.get_metrics().await.apps_managed, 1, "State untouched on rejection");
+        assert_eq!(orchestrator.apps_by_developer("alice").await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_deploy_result_matches_stored_app() {
+        let ai_core = Arc::new(AutonomousHyperAI::new().with_model(Box::new(StableModel)));
+        let (accelerator, orchestrator) = build_orchestrator(ai_core);
+        accelerator.accelerate_mainnet().await.unwrap();
+
+        let deployed = orchestrator.deploy_app("alice".to_string(), "Stable PI app code 1".to_string()).await.unwrap();
+        let stored = orchestrator.get_app(&deployed.app_id).await.expect("returned id is stored");
+        assert_eq!(stored.developer, "alice");
+        assert_eq!(deployed.code_hash, stored.code_hash);
+        assert_eq!(deployed.pi_charged, stored.pi_usage);
+        assert_eq!(deployed.to_string(), format!("App {} deployed successfully.", deployed.app_id));
+
+        let err = orchestrator.deploy_app("mallory".to_string(), "bitcoin".to_string()).await.unwrap_err();
+        assert!(matches!(err, SuperPiError::Rejected(_)), "{:?}", err);
+    }
+
+    #[test]
+    fn test_app_status_transitions() {
+        assert!(can_transition(AppStatus::Building, AppStatus::Running));
+        assert!(can_transition(AppStatus::Building, AppStatus::Halted));
+        assert!(can_transition(AppStatus::Running, AppStatus::Halted));
+
+        assert!(!can_transition(AppStatus::Running, AppStatus::Building));
+        assert!(!can_transition(AppStatus::Halted, AppStatus::Running));
+        assert!(!can_transition(AppStatus::Halted, AppStatus::Building));
+        assert!(!can_transition(AppStatus::Running, AppStatus::Running));
+    }
+
+    #[tokio::test]
+    async fn test_deploy_and_finalize_rolls_back_when_abandoned() {
+        let ai_core = Arc::new(AutonomousHyperAI::new().with_model(Box::new(StableModel)));
+        let (accelerator, orchestrator) = build_orchestrator(ai_core);
+        accelerator.accelerate_mainnet().await.unwrap();
+
+        // Completed: the app is Running with no separate finalize step
+        let deployed = orchestrator.deploy_and_finalize("dev_1".to_string(), "PI app".to_string()).await.unwrap();
+        assert_eq!(orchestrator.get_app(&deployed.app_id).await.unwrap().status, AppStatus::Running);
+
+        // Abandoned after its first poll, as a command timeout would (dropped at the end of the block)
+        {
+            let abandoned = orchestrator.deploy_and_finalize("dev_2".to_string(), "PI app".to_string());
+            tokio::pin!(abandoned);
+            tokio::select! {
+                biased;
+                _ = &mut abandoned => panic!("Deploy finished within a single poll"),
+                _ = std::future::ready(()) => {}
+            }
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+
+        assert!(orchestrator.apps_by_developer("dev_2").await.is_empty(), "Abandoned deploy is rolled back");
+        assert_eq!(orchestrator.reconcile_app_counts().await, (1, 1), "Node slot and metrics released too");
+        assert_eq!(orchestrator.get_metrics().await.pi_consumed_total, deployed.pi_charged);
+    }
+
+    #[tokio::test]
+    async fn test_apps_build_before_running() {
+        let ai_core = Arc::new(AutonomousHyperAI::new().with_model(Box::new(StableModel)));
+        let (accelerator, orchestrator) = build_orchestrator(ai_core);
+        accelerator.accelerate_mainnet().await.unwrap();
+
+        let deployed = orchestrator.deploy_app("alice".to_string(), "Stable PI app code 1".to_string()).await.unwrap();
+        assert_eq!(orchestrator.get_app(&deployed.app_id).await.unwrap().status, AppStatus::Building);
+        assert!(orchestrator.apps_by_status(AppStatus::Running).await.is_empty(), "Nothing runs while building");
+
+        orchestrator.finalize_build(&deployed.app_id).await.unwrap();
+        assert_eq!(orchestrator.get_app(&deployed.app_id).await.unwrap().status, AppStatus::Running);
+
+        // Finalizing twice, or reviving a halted app, is rejected
+        let err = orchestrator.finalize_build(&deployed.app_id).await.unwrap_err();
+        assert_eq!(err, "Invalid app status transition: Running -> Running");
+        orchestrator.halt_app(&deployed.app_id).await.unwrap();
+        assert!(orchestrator.finalize_build(&deployed.app_id).await.is_err());
+        assert!(orchestrator.halt_app(&deployed.app_id).await.is_err(), "Halted is final");
+        assert_eq!(orchestrator.finalize_build("missing").await.unwrap_err(), "App not found.");
+    }
+
+    #[tokio::test]
+    async fn test_deploy_waits_for_mainnet_readiness() {
+        let (accelerator, orchestrator) = build_orchestrator(Arc::new(AutonomousHyperAI::new().with_model(Box::new(StableModel))));
+        assert!(!orchestrator.is_ready().await);
+
+        let err = orchestrator.deploy_app("dev_1".to_string(), "Stable PI code".to_string()).await.unwrap_err();
+        assert_eq!(err, SuperPiError::Unavailable("ecosystem not ready: accelerate mainnet first".to_string()));
+
+        accelerator.accelerate_mainnet().await.unwrap();
+        assert!(orchestrator.is_ready().await);
+        assert!(orchestrator.deploy_app("dev_1".to_string(), "Stable PI code".to_string()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_reset_metrics_zeroes_counters_and_apps() {
+        let (accelerator, orchestrator) = build_orchestrator(Arc::new(AutonomousHyperAI::new().with_model(Box::new(StableModel))));
+        accelerator.accelerate_mainnet().await.unwrap();
+        deploy_running(&orchestrator, "dev_1", "Stable PI code").await;
+        let halted = deploy_running(&orchestrator, "dev_2", "Stable PI code 2").await;
+        orchestrator.halt_app(&halted).await.unwrap();
+        assert_eq!(orchestrator.get_metrics().await.apps_managed, 2);
+
+        orchestrator.reset_metrics().await;
+        let metrics = orchestrator.get_metrics().await;
+        assert_eq!((metrics.apps_managed, metrics.apps_halted, metrics.pi_consumed_total, metrics.uptime_rate), (0, 0, 0.0, 1.0));
+        assert!(orchestrator.snapshot().await.apps.is_empty(), "Apps are dropped with the counters");
+        assert_eq!(orchestrator.reconcile_app_counts().await, (0, 0), "Node assignments released too");
+
+        // The reset state still snapshots and restores cleanly
+        let (restored_accelerator, restored) = build_orchestrator(Arc::new(AutonomousHyperAI::new().with_model(Box::new(StableModel))));
+        restored_accelerator.accelerate_mainnet().await.unwrap();
+        restored.restore(orchestrator.snapshot().await).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_identical_code_shares_hash_but_not_address() {
+        let (accelerator, orchestrator) = build_orchestrator(Arc::new(AutonomousHyperAI::new().with_model(Box::new(StableModel))));
+        accelerator.accelerate_mainnet().await.unwrap();
+
+        let alice = orchestrator.deploy_app("dev_alice".to_string(), "Stable PI code".to_string()).await.unwrap();
+        let bob = orchestrator.deploy_app("dev_bob".to_string(), "Stable PI code".to_string()).await.unwrap();
+        assert_eq!(alice.code_hash, bob.code_hash, "Identical code dedups by hash");
+        assert_ne!(alice.content_address, bob.content_address, "Each developer gets a distinct address");
+
+        // Addresses are deterministic, stored on the app, and depend on the salt
+        assert_eq!(alice.content_address, orchestrator.content_address("dev_alice", "Stable PI code"));
+        assert_eq!(orchestrator.get_app(&alice.app_id).await.unwrap().content_address, alice.content_address);
+        let salted = orchestrator.clone().with_address_salt("testnet");
+        assert_ne!(salted.content_address("dev_alice", "Stable PI code"), alice.content_address);
+    }
+
+    #[tokio::test]
+    async fn test_precheck_reports_reasons_without_side_effects() {
+        let (_, orchestrator) = build_orchestrator(Arc::new(AutonomousHyperAI::new()));
+
+        // Naming Bitcoin breaks PI exclusivity and trips the shield; the long payload trips the built-in AI model
+        let volatile = orchestrator.precheck_code("Bitcoin bridge for PI apps with token swaps");
+        assert!(!volatile.passed);
+        assert_eq!(volatile.reasons.len(), 3, "{:?}", volatile.reasons);
+        assert!(volatile.reasons[0].starts_with("Exclusivity policy would reject"));
+        assert!(volatile.reasons[1].starts_with("Shield would isolate"));
+        assert!(volatile.reasons[2].starts_with("AI filter would reject"));
+
+        let clean = orchestrator.precheck_code("PI");
+        assert!(clean.passed, "{:?}", clean.reasons);
+        assert!(clean.reasons.is_empty());
+        // No pattern matches; only the built-in model's 0.153, at the default AI weight of 0.5
+        assert!((clean.shield_score.unwrap() - 0.0765).abs() < 1e-9);
+
+        // Nothing was recorded anywhere
+        assert!(orchestrator.snapshot().await.apps.is_empty());
+        assert_eq!(orchestrator.get_metrics().await.apps_managed, 0);
+        assert_eq!(orchestrator.evolution_signals().await.recent_isolation_events, 0);
+    }
+
+    #[tokio::test]
+    async fn test_cost_function_charges_by_code_length() {
+        let (accelerator, orchestrator) = build_orchestrator(Arc::new(AutonomousHyperAI::new().with_model(Box::new(StableModel))));
+        let orchestrator = orchestrator.with_cost_fn(Box::new(|code: &str| code.len() as f64 * 2.0));
+        accelerator.accelerate_mainnet().await.unwrap();
+
+        let short = orchestrator.deploy_app("dev_1".to_string(), "PI app".to_string()).await.unwrap();
+        let long = orchestrator.deploy_app("dev_1".to_string(), "Stable PI app code".to_string()).await.unwrap();
+        assert_eq!((short.pi_charged, long.pi_charged), (12.0, 36.0));
+        assert!(long.pi_charged > short.pi_charged);
+        assert_eq!(orchestrator.get_app(&long.app_id).await.unwrap().pi_usage, 36.0);
+        assert_eq!(orchestrator.get_metrics().await.pi_consumed_total, 48.0);
+
+        // Default stays flat; invalid costs are refused before anything is recorded
+        let (_, flat) = build_orchestrator(Arc::new(AutonomousHyperAI::new()));
+        assert_eq!(flat.app_cost("any code").unwrap(), 100.0);
+        let broken = orchestrator.with_cost_fn(Box::new(|_: &str| f64::NAN));
+        assert!(matches!(broken.deploy_app("dev_1".to_string(), "PI".to_string()).await, Err(SuperPiError::Rejected(_))));
+        assert_eq!(broken.get_metrics().await.apps_managed, 2);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_watch_sees_deploy() {
+        let (accelerator, orchestrator) = build_orchestrator(Arc::new(AutonomousHyperAI::new().with_model(Box::new(StableModel))));
+        accelerator.accelerate_mainnet().await.unwrap();
+        let mut metrics = orchestrator.watch_metrics();
+        assert_eq!(metrics.borrow_and_update().apps_managed, 0);
+
+        let deployer = orchestrator.clone();
+        tokio::spawn(async move { deployer.deploy_app("dev_1".to_string(), "Stable PI code".to_string()).await });
+
+        tokio::time::timeout(std::time::Duration::from_secs(5), metrics.changed()).await.unwrap().unwrap();
+        let latest = metrics.borrow_and_update().clone();
+        assert_eq!((latest.apps_managed, latest.pi_consumed_total), (1, 100.0));
+
+        // Unchanged metrics don't wake watchers
+        orchestrator.halt_developer("dev_nobody").await;
+        assert!(!metrics.has_changed().unwrap());
+
+        // Halts change the halted count, so they wake watchers too
+        assert_eq!(orchestrator.halt_developer("dev_1").await, 1);
+        assert!(metrics.has_changed().unwrap());
+        assert_eq!(metrics.borrow_and_update().apps_halted, 1);
+
+        orchestrator.reset_metrics().await;
+        assert!(metrics.has_changed().unwrap());
+        let reset = metrics.borrow_and_update().clone();
+        assert_eq!((reset.apps_managed, reset.apps_halted), (0, 0));
+    }
+
+    #[tokio::test]
+    async fn test_halt_by_code_hash_recalls_every_instance() {
+        let (accelerator, orchestrator) = build_orchestrator(Arc::new(AutonomousHyperAI::new().with_model(Box::new(StableModel))));
+        accelerator.accelerate_mainnet().await.unwrap();
+
+        let recalled = "Stable PI app code recalled";
+        for developer in ["alice", "bob", "carol"] {
+            deploy_running(&orchestrator, developer, recalled).await;
+        }
+        let other = deploy_running(&orchestrator, "alice", "Stable PI app code other").await;
+        let code_hash = orchestrator.apps_by_developer("bob").await[0].code_hash.clone();
+
+        assert_eq!(orchestrator.halt_by_code_hash(&code_hash).await, 3);
+        assert_eq!(orchestrator.apps_by_status(AppStatus::Halted).await.len(), 3);
+        assert_eq!(orchestrator.get_app(&other).await.unwrap().status, AppStatus::Running);
+
+        // Already-halted apps and unknown hashes halt nothing
+        assert_eq!(orchestrator.halt_by_code_hash(&code_hash).await, 0);
+        assert_eq!(orchestrator.halt_by_code_hash("unknown").await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_run_apps_on_single_thread_pool() {
+        let ai_core = AutonomousHyperAI::new()
+            .with_model(Box::new(StableModel))
+            .with_compliance_mode(ComplianceMode::AlwaysCompliant);
+        let (accelerator, orchestrator) = build_orchestrator(Arc::new(ai_core));
+        let orchestrator = orchestrator.with_parallelism(1).unwrap();
+        assert_eq!(orchestrator.parallelism(), 1);
+        accelerator.accelerate_mainnet().await.unwrap();
+
+        for i in 0..4 {
+            deploy_running(&orchestrator, "dev_1", &format!("Stable PI app code {}", i)).await;
+        }
+        orchestrator.run_apps().await.unwrap();
+        assert_eq!(orchestrator.apps_by_status(AppStatus::Running).await.len(), 4);
+        assert_eq!(orchestrator.get_metrics().await.apps_managed, 4);
+    }
+}
@@ -0,0 +1,480 @@
+#[cfg(test)]
+mod common;
+
+#[cfg(test)]
+mod test_ecosystem_isolation_shield {
+    use std::sync::Arc;
+    use tokio_util::sync::CancellationToken;
+    use crate::clock::MockClock;
+    use crate::hyper_ai_core::{AutonomousHyperAI, VolatilityModel};
+    use crate::pi_transaction_engine::PITransactionEngine;
+    use crate::pi_mainnet_accelerator::PiMainnetAccelerator;
+    use crate::ecosystem_isolation_shield::{EcosystemIsolationShield, IsolationVerdict, SealAlgorithm, SealedData, ShieldConfig, VolatilityStats};
+    use super::common::StableModel;
+
+    fn build_shield(config: ShieldConfig) -> EcosystemIsolationShield {
+        let ai_core = Arc::new(AutonomousHyperAI::new().with_model(Box::new(StableModel)));
+        let tx_engine = Arc::new(PITransactionEngine::new(ai_core.clone()));
+        let mainnet_accelerator = Arc::new(PiMainnetAccelerator::new(ai_core.clone(), tx_engine.clone()));
+        EcosystemIsolationShield::new_with_config(ai_core, tx_engine, mainnet_accelerator, config).unwrap()
+    }
+
+    #[test]
+    fn test_repeated_keywords_score_higher_than_single_mention() {
+        let shield = build_shield(ShieldConfig {
+            isolation_threshold: 0.3,
+            volatility_patterns: vec![(r"(?i)bitcoin".to_string(), 0.1)],
+            ..ShieldConfig::default()
+        });
+
+        let single = shield.volatility_score("Pi update with one bitcoin mention");
+        let repeated = shield.volatility_score(&"bitcoin ".repeat(10));
+
+        assert!((single - 0.1).abs() < 1e-9, "Single occurrence scores one weight");
+        assert!(repeated > single, "Repeated occurrences score higher");
+        assert!((repeated - 1.0).abs() < 1e-9, "Score is capped at 1.0");
+    }
+
+    #[test]
+    fn test_invalid_pattern_is_rejected() {
+        let ai_core = Arc::new(AutonomousHyperAI::new());
+        let tx_engine = Arc::new(PITransactionEngine::new(ai_core.clone()));
+        let mainnet_accelerator = Arc::new(PiMainnetAccelerator::new(ai_core.clone(), tx_engine.clone()));
+        let config = ShieldConfig {
+            isolation_threshold: 0.3,
+            volatility_patterns: vec![("(unclosed".to_string(), 0.5)],
+            ..ShieldConfig::default()
+        };
+        assert!(EcosystemIsolationShield::new_with_config(ai_core, tx_engine, mainnet_accelerator, config).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_stop_processor_resolves_running_task() {
+        let shield = Arc::new(build_shield(ShieldConfig::default()));
+        let runner = shield.clone();
+        let handle = tokio::spawn(async move { runner.run_stream_processor(CancellationToken::new()).await });
+
+        shield.submit_stream("PI reward".to_string()).unwrap();
+        shield.stop_processor();
+
+        let joined = tokio::time::timeout(std::time::Duration::from_secs(2), handle).await;
+        assert!(joined.is_ok(), "Processor returned after stop signal");
+    }
+
+    #[tokio::test]
+    async fn test_allowlisted_phrase_prevents_isolation() {
+        let text = "Pi community finance discussion".to_string();
+
+        // Without the allowlist, "finance" alone crosses the threshold
+        let strict = build_shield(ShieldConfig { allowlist_patterns: vec![], ..ShieldConfig::default() });
+        assert!(strict.process_stream(text.clone()).await.is_err());
+
+        let shield = build_shield(ShieldConfig::default());
+        assert_eq!(shield.volatility_score(&text), 0.0, "Score clamped at zero");
+        let sealed = shield.process_stream(text.clone()).await.unwrap();
+        assert_eq!(shield.unseal(&sealed).unwrap().payload, text);
+        assert!(shield.get_events().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_event_pagination() {
+        let shield = build_shield(ShieldConfig::default());
+        for i in 0..5 {
+            assert!(shield.process_stream(format!("bitcoin {}", i)).await.is_err());
+        }
+
+        let (first, total) = shield.get_events_page(0, 2).await;
+        assert_eq!((first.len(), total), (2, 5));
+
+        // Last page is partial
+        let (last, total) = shield.get_events_page(4, 2).await;
+        assert_eq!((last.len(), total), (1, 5));
+
+        // Offset beyond the end yields an empty page with the correct total
+        let (empty, total) = shield.get_events_page(7, 2).await;
+        assert!(empty.is_empty());
+        assert_eq!(total, 5);
+    }
+
+    #[test]
+    fn test_seal_algorithms_label_and_hash_length() {
+        let shield = build_shield(ShieldConfig::default());
+
+        let sha256 = shield.seal_data_with("PI reward", SealAlgorithm::Sha256);
+        let sha512 = shield.seal_data_with("PI reward", SealAlgorithm::Sha512);
+        let hash_of = |sealed: &str, label: &str| {
+            let sealed = shield.unseal(sealed).unwrap();
+            assert_eq!(sealed.algo, label);
+            sealed.hash
+        };
+
+        assert_eq!(hash_of(&sha256, "sha256").len(), 64);
+        assert_eq!(hash_of(&sha512, "sha512").len(), 128);
+        assert_eq!(shield.seal_data("PI reward"), sha256, "seal_data defaults to SHA-256");
+    }
+
+    #[test]
+    fn test_verify_seal_detects_tampering() {
+        let shield = build_shield(ShieldConfig::default());
+
+        let sealed = shield.seal_data_with("PI reward", SealAlgorithm::Sha512);
+        assert!(shield.verify_seal(&sealed));
+        assert!(shield.verify_seal(&shield.seal_data("PI reward")));
+
+        let tampered = sealed.replace("PI reward", "PI rewards");
+        assert!(!shield.verify_seal(&tampered));
+
+        assert!(!shield.verify_seal("Sealed PI Data: PI reward"));
+        assert!(!shield.verify_seal("not a seal"));
+    }
+
+    #[tokio::test]
+    async fn test_volatility_stats_cover_isolated_and_passed_items() {
+        let shield = build_shield(ShieldConfig::default());
+        assert_eq!(shield.volatility_stats().await, VolatilityStats::default(), "Empty before any input");
+
+        // Scores: 0.0 (passed), 0.5 (isolated), 1.0 (isolated)
+        assert!(shield.process_stream("PI".to_string()).await.is_ok());
+        assert!(shield.process_stream("bitcoin".to_string()).await.is_err());
+        assert!(shield.process_stream("bitcoin crypto".to_string()).await.is_err());
+
+        let stats = shield.volatility_stats().await;
+        assert_eq!(stats.count, 3);
+        assert!((stats.mean - 0.5).abs() < 1e-9);
+        assert_eq!(stats.min, 0.0);
+        assert_eq!(stats.max, 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_events_break_down_by_category() {
+        let shield = build_shield(ShieldConfig::default());
+
+        assert!(shield.process_stream_typed("bitcoin tip".to_string(), "chat").await.is_err());
+        assert!(shield.process_stream_typed("crypto swap".to_string(), "tx_memo").await.is_err());
+        assert!(shield.process_stream_typed("ethereum bridge".to_string(), "chat").await.is_err());
+        assert!(shield.process_stream_typed("PI reward".to_string(), "tx_memo").await.is_ok());
+        assert!(shield.process_stream("token".to_string()).await.is_err());
+
+        let chat = shield.events_by_type("chat").await;
+        assert_eq!(chat.len(), 2);
+        assert!(chat.iter().all(|e| e.data_type == "chat"));
+        assert_eq!(shield.events_by_type("tx_memo").await.len(), 1, "Only isolated data is recorded");
+        assert_eq!(shield.events_by_type("volatile_external").await.len(), 1, "Untagged data keeps the default category");
+        assert!(shield.events_by_type("app_code").await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_ai_score_is_blended_into_isolation() {
+        // At the AI filter's cutoff, so filter_io lets it through
+        struct BorderlineModel;
+
+        impl VolatilityModel for BorderlineModel {
+            fn score(&self, _input: &str) -> f64 {
+                0.5
+            }
+        }
+
+        let build = |ai_score_weight: f64| {
+            let ai_core = Arc::new(AutonomousHyperAI::new().with_model(Box::new(BorderlineModel)));
+            let tx_engine = Arc::new(PITransactionEngine::new(ai_core.clone()));
+            let mainnet_accelerator = Arc::new(PiMainnetAccelerator::new(ai_core.clone(), tx_engine.clone()));
+            let config = ShieldConfig {
+                volatility_patterns: vec![(r"(?i)bitcoin".to_string(), 0.2)],
+                ai_score_weight,
+                ..ShieldConfig::default()
+            };
+            EcosystemIsolationShield::new_with_config(ai_core, tx_engine, mainnet_accelerator, config).unwrap()
+        };
+        let text = "Pi update with one bitcoin mention";
+        let shield = build(ShieldConfig::default().ai_score_weight);
+
+        // Pattern signal alone (0.2) stays under the 0.3 threshold
+        assert!((build(0.0).combined_score(text) - 0.2).abs() < 1e-9);
+        assert!(build(0.0).process_stream(text.to_string()).await.is_ok(), "Pattern score alone passes");
+
+        // AI signal alone (0.5 * 0.5 = 0.25) stays under it too
+        assert!((shield.combined_score("Pi update") - 0.25).abs() < 1e-9);
+        assert!(shield.process_stream("Pi update".to_string()).await.is_ok(), "AI score alone passes");
+
+        // Together they cross it: 1 - (1 - 0.25) * (1 - 0.2) = 0.4
+        assert!((shield.combined_score(text) - 0.4).abs() < 1e-9);
+        let err = shield.process_stream(text.to_string()).await.unwrap_err();
+        assert_eq!(err, "Data isolated: volatility score 0.40");
+        assert!((shield.get_events().await[0].volatility_score - 0.4).abs() < 1e-9);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_stream_sequence_preserves_per_producer_order() {
+        let shield = Arc::new(build_shield(ShieldConfig::default()));
+
+        // Two producers race; every item is volatile so each one leaves an event
+        let producers: Vec<_> = (0..2)
+            .map(|p| {
+                let shield = shield.clone();
+                tokio::spawn(async move {
+                    (0..50).map(|i| shield.submit_stream(format!("bitcoin {} {}", p, i)).unwrap()).collect::<Vec<u64>>()
+                })
+            })
+            .collect();
+        let mut submitted = Vec::new();
+        for producer in producers {
+            submitted.push(producer.await.unwrap());
+        }
+
+        let runner = shield.clone();
+        let processor = tokio::spawn(async move { runner.run_stream_processor(CancellationToken::new()).await });
+        shield.stop_processor();
+        processor.await.unwrap();
+
+        let events = shield.get_events().await;
+        let recorded: Vec<u64> = events.iter().map(|e| e.sequence.expect("Streamed events carry a sequence")).collect();
+        let mut unique = recorded.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len(), 100, "Sequence numbers are unique");
+
+        for sequences in &submitted {
+            assert!(sequences.windows(2).all(|w| w[0] < w[1]), "Increasing at submit time");
+            let seen: Vec<u64> = recorded.iter().copied().filter(|s| sequences.contains(s)).collect();
+            assert_eq!(&seen, sequences, "Events keep each producer's submission order");
+        }
+
+        // Direct calls bypass the queue and carry no sequence
+        assert!(shield.process_stream("bitcoin direct".to_string()).await.is_err());
+        assert_eq!(shield.get_events().await.last().unwrap().sequence, None);
+    }
+
+    #[tokio::test]
+    async fn test_reevaluation_releases_newly_allowlisted_data() {
+        let shield = Arc::new(build_shield(ShieldConfig::default()));
+        let text = "Pi merchant token rewards".to_string();
+
+        assert!(shield.process_stream(text.clone()).await.is_err());
+        let event_id = shield.get_events().await[0].id.clone();
+
+        // Unchanged patterns keep it isolated without logging another event
+        assert!(shield.reevaluate_event(&event_id, text.clone()).await.unwrap_err().starts_with("Data still isolated"));
+        assert_eq!(shield.get_events().await.len(), 1);
+
+        // Other data can't be slipped through under this event's id
+        let err = shield.reevaluate_event(&event_id, "PI reward".to_string()).await.unwrap_err();
+        assert!(err.starts_with("Data does not match"), "{}", err);
+
+        // Shared shields can be extended in place
+        shield.add_allowlist_pattern(r"(?i)\bpi merchant token\b").unwrap();
+        let sealed = shield.reevaluate_event(&event_id, text).await.unwrap();
+        assert!(shield.verify_seal(&sealed));
+
+        let events = shield.get_events().await;
+        assert_eq!(events.len(), 1);
+        assert!(!events[0].quarantined, "Original event released");
+
+        assert!(shield.reevaluate_event("event_missing", "PI".to_string()).await.is_err());
+        assert!(shield.add_allowlist_pattern("(unclosed").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_verdict_distinguishes_sealed_and_isolated() {
+        let shield = build_shield(ShieldConfig::default());
+
+        match shield.process_stream_verdict("PI reward".to_string()).await.unwrap() {
+            IsolationVerdict::Sealed { payload, hash } => {
+                assert!(shield.verify_seal(&payload));
+                assert_eq!(hash, SealAlgorithm::Sha256.digest("PI reward"));
+            }
+            other => panic!("Expected a sealed verdict, got {:?}", other),
+        }
+
+        let verdict = shield.process_stream_verdict("Bitcoin news".to_string()).await.unwrap();
+        let events = shield.get_events().await;
+        assert_eq!(verdict, IsolationVerdict::Isolated { score: events[0].volatility_score, event_id: events[0].id.clone() });
+
+        // The string wrapper keeps its messages
+        assert_eq!(verdict.into_result().unwrap_err(), "Data isolated: volatility score 0.50");
+    }
+
+    #[tokio::test]
+    async fn test_events_export_as_csv() {
+        let shield = build_shield(ShieldConfig::default());
+        assert_eq!(shield.export_events_csv().await, "id,data_type,volatility_score,quarantined,timestamp\n");
+
+        assert!(shield.process_stream_typed("Bitcoin news".to_string(), "chat").await.is_err());
+        assert!(shield.process_stream_typed("crypto memo".to_string(), "tx_memo, external").await.is_err());
+        assert!(shield.process_stream("PI reward".to_string()).await.is_ok());
+
+        let csv = shield.export_events_csv().await;
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "id,data_type,volatility_score,quarantined,timestamp");
+        assert_eq!(lines.len() - 1, shield.get_events().await.len());
+        assert!(lines[1].contains(",chat,0.5,true,"), "{}", lines[1]);
+        assert!(lines[2].contains(",\"tx_memo, external\",0.5,true,"), "Commas are quoted: {}", lines[2]);
+    }
+
+    #[tokio::test]
+    async fn test_clear_events_empties_log() {
+        let shield = build_shield(ShieldConfig::default());
+        assert!(shield.process_stream("Bitcoin news".to_string()).await.is_err());
+        assert_eq!(shield.get_events().await.len(), 1);
+
+        shield.clear_events().await;
+        assert!(shield.get_events().await.is_empty());
+        assert_eq!(shield.recent_event_count(60).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_oversized_input_rejected_before_scoring() {
+        let shield = build_shield(ShieldConfig { max_input_bytes: 64, ..ShieldConfig::default() });
+
+        let oversized = "PI reward ".repeat(10);
+        assert_eq!(shield.process_stream(oversized).await.unwrap_err(), "input too large");
+        assert_eq!(shield.volatility_stats().await.count, 0, "Nothing was scored");
+        assert!(shield.get_events().await.is_empty());
+
+        // At the limit is still scanned
+        assert!(shield.process_stream("P".repeat(64)).await.is_ok());
+        assert_eq!(shield.volatility_stats().await.count, 1);
+
+        assert_eq!(ShieldConfig::default().max_input_bytes, 1024 * 1024);
+    }
+
+    #[tokio::test]
+    async fn test_mock_clock_sets_event_timestamps() {
+        let at = chrono::DateTime::parse_from_rfc3339("2024-03-14T15:09:26Z").unwrap().with_timezone(&chrono::Utc);
+        let clock = Arc::new(MockClock::new(at));
+        let shield = build_shield(ShieldConfig::default()).with_clock(clock.clone());
+
+        assert!(shield.process_stream("Bitcoin news".to_string()).await.is_err());
+        assert_eq!(shield.get_events().await[0].timestamp, at.timestamp() as u64);
+
+        // Windows are measured against the injected clock too
+        assert_eq!(shield.recent_event_count(60).await, 1);
+        clock.advance(chrono::Duration::seconds(61));
+        assert_eq!(shield.recent_event_count(60).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_purge_expired_drops_events_past_ttl() {
+        let at = chrono::DateTime::parse_from_rfc3339("2024-03-14T15:09:26Z").unwrap().with_timezone(&chrono::Utc);
+        let clock = Arc::new(MockClock::new(at));
+        let config = ShieldConfig { quarantine_ttl: Some(std::time::Duration::from_secs(3600)), ..ShieldConfig::default() };
+        let shield = build_shield(config).with_clock(clock.clone());
+
+        assert!(shield.process_stream("Bitcoin news".to_string()).await.is_err());
+        clock.advance(chrono::Duration::minutes(30));
+        assert!(shield.process_stream("Ethereum news".to_string()).await.is_err());
+        assert_eq!(shield.purge_expired().await, 0, "Nothing is older than the TTL yet");
+
+        clock.advance(chrono::Duration::minutes(31));
+        assert_eq!(shield.purge_expired().await, 1);
+        let remaining = shield.get_events().await;
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].timestamp, (at + chrono::Duration::minutes(30)).timestamp() as u64);
+
+        // Without a TTL events are kept; a zero TTL is refused
+        let unbounded = build_shield(ShieldConfig::default()).with_clock(clock.clone());
+        assert!(unbounded.process_stream("Bitcoin news".to_string()).await.is_err());
+        clock.advance(chrono::Duration::days(365));
+        assert_eq!(unbounded.purge_expired().await, 0);
+        let ai_core = Arc::new(AutonomousHyperAI::new());
+        let tx_engine = Arc::new(PITransactionEngine::new(ai_core.clone()));
+        let accelerator = Arc::new(PiMainnetAccelerator::new(ai_core.clone(), tx_engine.clone()));
+        let zero = ShieldConfig { quarantine_ttl: Some(std::time::Duration::ZERO), ..ShieldConfig::default() };
+        assert!(EcosystemIsolationShield::new_with_config(ai_core, tx_engine, accelerator, zero).is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_bulk_isolate_results_stay_in_input_order() {
+        // Inputs naming a delay block their task that long, so later inputs finish first
+        struct SlowModel;
+
+        impl VolatilityModel for SlowModel {
+            fn score(&self, input: &str) -> f64 {
+                if let Some(ms) = input.split_whitespace().find_map(|w| w.strip_prefix("delay_")) {
+                    std::thread::sleep(std::time::Duration::from_millis(ms.parse().unwrap()));
+                }
+                0.0
+            }
+        }
+
+        let ai_core = Arc::new(AutonomousHyperAI::new().with_model(Box::new(SlowModel)));
+        let tx_engine = Arc::new(PITransactionEngine::new(ai_core.clone()));
+        let mainnet_accelerator = Arc::new(PiMainnetAccelerator::new(ai_core.clone(), tx_engine.clone()));
+        let shield = Arc::new(EcosystemIsolationShield::new(ai_core, tx_engine, mainnet_accelerator));
+
+        let batch: Vec<String> = (0..8)
+            .map(|i| {
+                let topic = if i % 3 == 0 { "Bitcoin" } else { "PI" };
+                format!("item_{} {} delay_{}", i, topic, (8 - i) * 15)
+            })
+            .collect();
+        let results = shield.bulk_isolate(batch.clone()).await;
+
+        assert_eq!(results.len(), batch.len());
+        for (i, (data, result)) in batch.iter().zip(&results).enumerate() {
+            if i % 3 == 0 {
+                assert!(result.as_ref().unwrap_err().starts_with("Data isolated"), "{}: {:?}", i, result);
+            } else {
+                assert_eq!(result.as_ref().unwrap(), &shield.seal_data(data), "Result {} matches its input", i);
+            }
+        }
+        assert!(shield.bulk_isolate(Vec::new()).await.is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_parallel_stream_processors_record_each_item_once() {
+        let shield = Arc::new(build_shield(ShieldConfig::default()));
+        let submitted: Vec<u64> = (0..200).map(|i| shield.submit_stream(format!("Bitcoin item {}", i)).unwrap()).collect();
+
+        let runner = shield.clone();
+        let processors = tokio::spawn(async move { runner.run_stream_processors(4, CancellationToken::new()).await });
+        tokio::time::timeout(std::time::Duration::from_secs(10), async {
+            while shield.get_events().await.len() < submitted.len() {
+                tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("All items processed");
+        shield.stop_processor();
+        processors.await.unwrap();
+
+        let mut recorded: Vec<u64> = shield.get_events().await.iter().map(|e| e.sequence.unwrap()).collect();
+        recorded.sort();
+        assert_eq!(recorded, submitted, "Every item recorded exactly once");
+    }
+
+    #[test]
+    fn test_wire_seal_round_trips_delimiter_in_payload() {
+        let shield = build_shield(ShieldConfig::default());
+        let data = "PI memo | Hash: sha256:deadbeef | Hash: :3:x";
+
+        let sealed = shield.seal_data(data);
+        assert!(sealed.starts_with("Sealed PI Data: sha256:"));
+        let recovered = shield.unseal(&sealed).unwrap();
+        assert_eq!(recovered.payload, data, "Payload recovered exactly");
+        assert!(recovered.is_intact());
+        assert!(shield.verify_seal(&sealed));
+
+        let structured = shield.seal(data, SealAlgorithm::Sha512);
+        assert_eq!(SealedData::from_wire(&structured.to_wire()).unwrap(), structured);
+        assert!(SealedData::from_wire("sha256:abc:99:short").is_err(), "Length must match the payload");
+    }
+
+    #[test]
+    fn test_legacy_seal_format_and_custom_prefix() {
+        let legacy = build_shield(ShieldConfig { legacy_seal_format: true, ..ShieldConfig::default() });
+        let hash = SealAlgorithm::Sha256.digest("PI reward");
+        let sealed = legacy.seal_data("PI reward");
+        assert_eq!(sealed, format!("Sealed PI Data: PI reward | Hash: sha256:{}", hash));
+        assert!(legacy.verify_seal(&sealed));
+        assert_eq!(legacy.unseal(&sealed).unwrap().payload, "PI reward");
+
+        // Seals made before the switch still verify under the wire format
+        assert!(build_shield(ShieldConfig::default()).verify_seal(&sealed));
+
+        let custom = build_shield(ShieldConfig { seal_prefix: "PISEAL/".to_string(), ..ShieldConfig::default() });
+        let sealed = custom.seal_data("PI reward");
+        assert_eq!(sealed, format!("PISEAL/sha256:{}:9:PI reward", hash));
+        assert!(custom.verify_seal(&sealed));
+        assert!(!custom.verify_seal(&legacy.seal_data("PI reward")), "Prefix must match");
+    }
+}
@@ -0,0 +1,28 @@
+#[cfg(test)]
+mod test_ecosystem_readme_config {
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{Address, Env, String, Symbol};
+    use crate::ecosystem_readme_config::{EcosystemConfig, EcosystemReadmeConfig};
+
+    #[test]
+    fn test_readme_renders_large_config_values() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, EcosystemReadmeConfig);
+        env.mock_all_auths();
+
+        env.as_contract(&contract_id, || {
+            EcosystemReadmeConfig::init(env.clone(), Address::generate(&env));
+            let config = EcosystemReadmeConfig::get_current_config(env.clone());
+            assert_eq!(
+                EcosystemReadmeConfig::update_config(env.clone(), EcosystemConfig { max_apps: i64::MAX, ..config }),
+                Ok(Symbol::new(&env, "updated"))
+            );
+
+            let readme = EcosystemReadmeConfig::generate_readme(env.clone());
+            assert_eq!(readme.get(1), Some(String::from_str(&env, "Stable Value: 314159")));
+            assert_eq!(readme.get(2), Some(String::from_str(&env, "Max Apps: 9223372036854775807")));
+            assert_eq!(readme.get(3), Some(String::from_str(&env, "Compliance: high")));
+            assert_eq!(readme.get(4), Some(String::from_str(&env, "Version: v_eternal_supremacy")));
+        });
+    }
+}
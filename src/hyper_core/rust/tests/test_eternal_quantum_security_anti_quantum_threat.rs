@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod test_eternal_quantum_security_anti_quantum_threat {
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{Address, Env, Symbol, Vec};
+    use crate::comprehensive_test_suite_validation::{ComprehensiveTestSuiteValidation, ValidationTest};
+    use crate::eternal_quantum_security_anti_quantum_threat::EternalQuantumSecurityAntiQuantumThreat;
+    use crate::rule_chain::validate_rule_chain;
+
+    #[test]
+    fn test_rule_chain_rejects_unhealthy_dependency() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, EternalQuantumSecurityAntiQuantumThreat);
+        env.mock_all_auths();
+
+        env.as_contract(&contract_id, || {
+            let healthy = (Symbol::new(&env, "holographic_archive"), Symbol::new(&env, "holographic_integrity"));
+            assert!(validate_rule_chain(env.clone(), Vec::from_array(&env, [healthy.clone()])));
+
+            let unknown = (Symbol::new(&env, "missing_module"), Symbol::new(&env, "integrity"));
+            assert!(!validate_rule_chain(env.clone(), Vec::from_array(&env, [healthy, unknown])));
+
+            // Archive and test suite are both healthy, so the admin's update passes
+            EternalQuantumSecurityAntiQuantumThreat::init(env.clone(), Address::generate(&env));
+            assert_eq!(
+                EternalQuantumSecurityAntiQuantumThreat::update_security_rules(env.clone(), Symbol::new(&env, "new_rule")),
+                Ok(Symbol::new(&env, "updated"))
+            );
+        });
+
+        // A failed validation test drops the suite's validation rate below 100
+        env.as_contract(&contract_id, || {
+            let failed = ValidationTest {
+                id: Symbol::new(&env, "test_1"),
+                test_target: Symbol::new(&env, "mainnet"),
+                test_passed: false,
+                validation_score: 0,
+                timestamp: env.ledger().timestamp(),
+            };
+            ComprehensiveTestSuiteValidation::enforce_test_integrity(env.clone(), failed);
+            let status = ComprehensiveTestSuiteValidation::get_test_status(env.clone());
+            assert_eq!(status.get(Symbol::new(&env, "validation_rate")), Some(0));
+        });
+
+        // The archive is still healthy, but the unhealthy test suite rejects the update
+        env.as_contract(&contract_id, || {
+            assert_eq!(
+                EternalQuantumSecurityAntiQuantumThreat::update_security_rules(env.clone(), Symbol::new(&env, "next_rule")),
+                Err(Symbol::new(&env, "update_rejected"))
+            );
+        });
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod test_final_ecosystem_synthesis_ui_hub {
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{Address, Env, String, Symbol};
+    use crate::ecosystem_readme_config::{EcosystemConfig, EcosystemReadmeConfig};
+    use crate::final_ecosystem_synthesis_ui_hub::FinalEcosystemSynthesisUiHub;
+
+    #[test]
+    fn test_synthesis_uses_configured_stable_value() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, FinalEcosystemSynthesisUiHub);
+        env.mock_all_auths();
+
+        let config = env.as_contract(&contract_id, || {
+            EcosystemReadmeConfig::init(env.clone(), Address::generate(&env));
+            let synthesis = FinalEcosystemSynthesisUiHub::synthesize_ui(env.clone(), Symbol::new(&env, "dashboard"));
+            assert_eq!(synthesis.data_points.get(2), Some(Symbol::new(&env, "pi_stable_value_314159")), "Defaults to the engine's stable value");
+            EcosystemReadmeConfig::get_current_config(env.clone())
+        });
+
+        // Each invocation authorizes the admin once, so every update gets its own frame
+        env.as_contract(&contract_id, || {
+            assert_eq!(
+                EcosystemReadmeConfig::update_config(env.clone(), EcosystemConfig { pi_stable_value: 271828, ..config.clone() }),
+                Ok(Symbol::new(&env, "updated"))
+            );
+        });
+        env.as_contract(&contract_id, || {
+            assert_eq!(
+                EcosystemReadmeConfig::update_config(env.clone(), EcosystemConfig { pi_stable_value: 0, ..config.clone() }),
+                Err(Symbol::new(&env, "config_rejected"))
+            );
+        });
+
+        env.as_contract(&contract_id, || {
+            let synthesis = FinalEcosystemSynthesisUiHub::synthesize_ui(env.clone(), Symbol::new(&env, "dashboard"));
+            assert_eq!(synthesis.data_points.get(2), Some(Symbol::new(&env, "pi_stable_value_271828")));
+        });
+    }
+
+    #[test]
+    fn test_holographic_ui_renders_large_scores() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, FinalEcosystemSynthesisUiHub);
+
+        env.as_contract(&contract_id, || {
+            EcosystemReadmeConfig::init(env.clone(), Address::generate(&env));
+            let mut synthesis = FinalEcosystemSynthesisUiHub::synthesize_ui(env.clone(), Symbol::new(&env, "dashboard"));
+            synthesis.ethical_score = i64::MAX;
+            let data_points = synthesis.data_points.len();
+
+            let ui = FinalEcosystemSynthesisUiHub::render_holographic_ui(env.clone(), synthesis);
+            assert_eq!(ui.get(1), Some(String::from_str(&env, "dashboard")));
+            assert_eq!(ui.get(2), Some(String::from_str(&env, "Ethical Score: 9223372036854775807")));
+            assert_eq!(ui.len(), 3 + data_points, "Every data point is rendered");
+            assert_eq!(ui.get(5), Some(String::from_str(&env, "pi_stable_value_314159")));
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unauthorized_config_update_is_rejected() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, FinalEcosystemSynthesisUiHub);
+
+        env.as_contract(&contract_id, || {
+            EcosystemReadmeConfig::init(env.clone(), Address::generate(&env));
+            let config = EcosystemReadmeConfig::get_current_config(env.clone());
+            // No auth from the admin has been provided
+            let _ = EcosystemReadmeConfig::update_config(env.clone(), config);
+        });
+    }
+}
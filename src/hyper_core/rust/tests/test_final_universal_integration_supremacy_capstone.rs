@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod test_final_universal_integration_supremacy_capstone {
+    use soroban_sdk::testutils::{Address as _, Events};
+    use soroban_sdk::{vec, Address, Env, IntoVal, Symbol};
+    use crate::final_universal_integration_supremacy_capstone::FinalUniversalIntegrationSupremacyCapstone;
+
+    #[test]
+    fn test_integration_publishes_event() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, FinalUniversalIntegrationSupremacyCapstone);
+
+        let integration = env.as_contract(&contract_id, || {
+            FinalUniversalIntegrationSupremacyCapstone::integrate_into_capstone(env.clone(), Symbol::new(&env, "ai_core"))
+        });
+
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "capstone_integrated"), Symbol::new(&env, "ai_core")).into_val(&env),
+                    (integration.id, true, 100i64, integration.timestamp).into_val(&env),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_enforcement_survives_missing_ai_core() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, FinalUniversalIntegrationSupremacyCapstone);
+        env.mock_all_auths();
+
+        let breach = |env: &Env| {
+            let mut integration = FinalUniversalIntegrationSupremacyCapstone::integrate_into_capstone(env.clone(), Symbol::new(env, "ai_core"));
+            integration.supremacy_achieved = false;
+            FinalUniversalIntegrationSupremacyCapstone::enforce_capstone_supremacy(env.clone(), integration)
+        };
+
+        // Dependency address unset: enforcement falls back to the in-crate AI core
+        env.as_contract(&contract_id, || {
+            FinalUniversalIntegrationSupremacyCapstone::init(env.clone(), Address::generate(&env));
+            assert_eq!(breach(&env), Symbol::new(&env, "capstone_enforced"));
+        });
+
+        // Address set but nothing deployed there: the failed call falls back too
+        env.as_contract(&contract_id, || {
+            FinalUniversalIntegrationSupremacyCapstone::set_dependency(env.clone(), Symbol::new(&env, "ahi_ai_core"), Address::generate(&env));
+        });
+        env.as_contract(&contract_id, || {
+            assert_eq!(breach(&env), Symbol::new(&env, "capstone_enforced"));
+        });
+    }
+
+    #[test]
+    fn test_status_counts_stored_integrations() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, FinalUniversalIntegrationSupremacyCapstone);
+
+        env.as_contract(&contract_id, || {
+            assert!(FinalUniversalIntegrationSupremacyCapstone::get_integration(env.clone(), Symbol::new(&env, "ai_core")).is_none());
+
+            for module in ["ai_core", "mainnet", "oracle"] {
+                FinalUniversalIntegrationSupremacyCapstone::integrate_into_capstone(env.clone(), Symbol::new(&env, module));
+            }
+            // Re-integrating a module does not inflate the count
+            FinalUniversalIntegrationSupremacyCapstone::integrate_into_capstone(env.clone(), Symbol::new(&env, "mainnet"));
+
+            let status = FinalUniversalIntegrationSupremacyCapstone::get_capstone_status(env.clone());
+            assert_eq!(status.get(Symbol::new(&env, "modules_integrated")), Some(3));
+
+            let stored = FinalUniversalIntegrationSupremacyCapstone::get_integration(env.clone(), Symbol::new(&env, "oracle")).unwrap();
+            assert_eq!(stored.integrated_module, Symbol::new(&env, "oracle"));
+            assert_eq!(stored.integration_level, 100);
+        });
+    }
+}
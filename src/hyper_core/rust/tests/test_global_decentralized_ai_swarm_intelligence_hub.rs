@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod test_global_decentralized_ai_swarm_intelligence_hub {
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{Address, Env, Symbol};
+    use crate::global_decentralized_ai_swarm_intelligence_hub::GlobalDecentralizedAiSwarmIntelligenceHub;
+
+    #[test]
+    fn test_swarm_size_tracks_registrations_and_quorum() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, GlobalDecentralizedAiSwarmIntelligenceHub);
+        env.mock_all_auths();
+
+        // Each invocation authorizes the admin once, so every admin call gets its own frame
+        env.as_contract(&contract_id, || {
+            GlobalDecentralizedAiSwarmIntelligenceHub::init(env.clone(), Address::generate(&env));
+        });
+        env.as_contract(&contract_id, || GlobalDecentralizedAiSwarmIntelligenceHub::set_swarm_quorum(env.clone(), 3));
+        for agent in ["agent_a", "agent_b", "agent_c"] {
+            env.as_contract(&contract_id, || {
+                assert!(GlobalDecentralizedAiSwarmIntelligenceHub::register_agent(env.clone(), Symbol::new(&env, agent)));
+            });
+        }
+        env.as_contract(&contract_id, || {
+            assert!(!GlobalDecentralizedAiSwarmIntelligenceHub::register_agent(env.clone(), Symbol::new(&env, "agent_a")), "Duplicate registration ignored");
+        });
+
+        let task = Symbol::new(&env, "predict_threats");
+        env.as_contract(&contract_id, || {
+            let intelligence = GlobalDecentralizedAiSwarmIntelligenceHub::coordinate_swarm_intelligence(env.clone(), task.clone());
+            assert_eq!(intelligence.swarm_size, 3);
+            assert_eq!(
+                GlobalDecentralizedAiSwarmIntelligenceHub::enforce_swarm_integrity(env.clone(), intelligence),
+                Symbol::new(&env, "swarm_intelligence_active")
+            );
+        });
+
+        // Dropping below quorum fails consensus
+        env.as_contract(&contract_id, || {
+            assert!(GlobalDecentralizedAiSwarmIntelligenceHub::deregister_agent(env.clone(), Symbol::new(&env, "agent_b")));
+        });
+        env.as_contract(&contract_id, || {
+            assert!(!GlobalDecentralizedAiSwarmIntelligenceHub::deregister_agent(env.clone(), Symbol::new(&env, "agent_b")));
+        });
+        env.as_contract(&contract_id, || {
+            let intelligence = GlobalDecentralizedAiSwarmIntelligenceHub::coordinate_swarm_intelligence(env.clone(), task.clone());
+            assert_eq!(intelligence.swarm_size, 2);
+            assert_eq!(
+                GlobalDecentralizedAiSwarmIntelligenceHub::enforce_swarm_integrity(env.clone(), intelligence),
+                Symbol::new(&env, "integrity_enforced")
+            );
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unauthorized_agent_registration_is_rejected() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, GlobalDecentralizedAiSwarmIntelligenceHub);
+
+        env.as_contract(&contract_id, || {
+            GlobalDecentralizedAiSwarmIntelligenceHub::init(env.clone(), Address::generate(&env));
+            // No auth from the admin has been provided
+            GlobalDecentralizedAiSwarmIntelligenceHub::register_agent(env.clone(), Symbol::new(&env, "agent_rogue"));
+        });
+    }
+}
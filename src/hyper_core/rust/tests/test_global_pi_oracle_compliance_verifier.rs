@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod test_global_pi_oracle_compliance_verifier {
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{Address, Env, Symbol, Vec};
+    use crate::global_pi_oracle_compliance_verifier::GlobalPiOracleComplianceVerifier;
+
+    #[test]
+    fn test_added_source_verifies() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, GlobalPiOracleComplianceVerifier);
+        env.mock_all_auths();
+
+        let staking = Symbol::new(&env, "staking_rewards");
+        env.as_contract(&contract_id, || {
+            GlobalPiOracleComplianceVerifier::init(env.clone(), Address::generate(&env));
+            // The default keeps every baseline source, contribution_rewards included
+            let results = GlobalPiOracleComplianceVerifier::global_compliance_check(env.clone());
+            assert_eq!(results.len(), 3);
+            assert_eq!(results.get(Symbol::new(&env, "contribution_rewards")), Some(100));
+            assert!(!GlobalPiOracleComplianceVerifier::fetch_verify_oracle(env.clone(), staking.clone()).verified);
+        });
+
+        env.as_contract(&contract_id, || {
+            let sources = Vec::from_array(&env, [Symbol::new(&env, "mining"), staking.clone()]);
+            GlobalPiOracleComplianceVerifier::set_allowed_sources(env.clone(), sources);
+        });
+
+        env.as_contract(&contract_id, || {
+            let added = GlobalPiOracleComplianceVerifier::fetch_verify_oracle(env.clone(), staking.clone());
+            assert_eq!(added.compliance_score, 100);
+            assert!(added.verified);
+
+            // Sources dropped from the list no longer verify
+            assert!(!GlobalPiOracleComplianceVerifier::fetch_verify_oracle(env.clone(), Symbol::new(&env, "p2p")).verified);
+            assert_eq!(GlobalPiOracleComplianceVerifier::get_oracle_status(env.clone()).len(), 2);
+        });
+    }
+}
@@ -0,0 +1,290 @@
+#[cfg(test)]
+mod test_hyper_ai_core {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use crate::hyper_ai_core::{AutonomousHyperAI, ComplianceMode, ComplianceNotification, VolatilityModel};
+
+    struct FixedModel(f64);
+
+    impl VolatilityModel for FixedModel {
+        fn score(&self, _input: &str) -> f64 {
+            self.0
+        }
+    }
+
+    #[tokio::test]
+    async fn test_injected_volatile_model_rejects_everything() {
+        let ai = AutonomousHyperAI::new().with_model(Box::new(FixedModel(0.9)));
+
+        for input in ["PI", "Stable PI reward", ""] {
+            let result = ai.filter_io(input).await;
+            assert_eq!(result.unwrap_err(), "Volatile input rejected: volatility score 0.90");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_injected_stable_model_accepts_long_input() {
+        // The built-in model rejects long inputs; a stable model lets them through
+        let input = "Stable PI transaction data ".repeat(10);
+        assert!(AutonomousHyperAI::new().filter_io(&input).await.is_err());
+
+        let ai = AutonomousHyperAI::new().with_model(Box::new(FixedModel(0.0)));
+        assert!(ai.filter_io(&input).await.is_ok());
+    }
+
+    #[cfg(feature = "tracing-logs")]
+    #[tokio::test]
+    async fn test_rejection_emits_warn_with_score_field() {
+        use std::io::Write;
+        use std::sync::{Arc, Mutex};
+
+        // Capture formatted log output in memory
+        #[derive(Clone, Default)]
+        struct Capture(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for Capture {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let capture = Capture::default();
+        let writer = capture.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(move || writer.clone())
+            .with_ansi(false)
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let ai = AutonomousHyperAI::new().with_model(Box::new(FixedModel(0.9)));
+        assert!(ai.filter_io("Bitcoin").await.is_err());
+
+        let output = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+        assert!(output.contains("WARN"), "Rejection logged at warn level: {}", output);
+        assert!(output.contains("volatility_score=0.9"), "Score field present: {}", output);
+    }
+
+    #[tokio::test]
+    async fn test_scripted_compliance_flips_and_halts_stellar() {
+        let ai = AutonomousHyperAI::new().with_compliance_mode(ComplianceMode::Scripted(vec![true, false]));
+
+        // Probes peek without consuming answers
+        assert_eq!(ai.probe_compliance().await, Ok(true));
+        assert_eq!(ai.probe_compliance().await, Ok(true));
+
+        ai.enforce_compliance().await.unwrap();
+        assert_eq!(ai.get_status().await, (true, false), "First answer is compliant");
+
+        ai.enforce_compliance().await.unwrap();
+        assert_eq!(ai.get_status().await, (false, true), "Second answer halts Stellar");
+
+        // Exhausted script keeps repeating its last answer
+        assert_eq!(ai.probe_compliance().await, Ok(false));
+    }
+
+    #[tokio::test]
+    async fn test_fixed_compliance_modes_work_offline() {
+        let compliant = AutonomousHyperAI::new().with_compliance_mode(ComplianceMode::AlwaysCompliant);
+        compliant.enforce_compliance().await.unwrap();
+        assert_eq!(compliant.get_status().await, (true, false));
+
+        let non_compliant = AutonomousHyperAI::new().with_compliance_mode(ComplianceMode::AlwaysNonCompliant);
+        non_compliant.enforce_compliance().await.unwrap();
+        assert_eq!(non_compliant.get_status().await, (false, true));
+
+        let empty = AutonomousHyperAI::new().with_compliance_mode(ComplianceMode::Scripted(Vec::new()));
+        assert!(empty.enforce_compliance().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_chunked_filter_rejects_early_volatile_token() {
+        // Scores any content mentioning bitcoin as volatile
+        struct KeywordModel;
+
+        impl VolatilityModel for KeywordModel {
+            fn score(&self, input: &str) -> f64 {
+                if input.contains("bitcoin") { 0.9 } else { 0.1 }
+            }
+        }
+
+        let ai = AutonomousHyperAI::new().with_model(Box::new(KeywordModel));
+        let chunks: Vec<String> = std::iter::once("bitcoin ".to_string())
+            .chain((0..100).map(|i| format!("stable PI chunk {} ", i)))
+            .collect();
+
+        let mut read = 0;
+        let result = ai.filter_io_chunks(chunks.iter().map(|c| { read += 1; c.as_str() })).await;
+
+        assert_eq!(read, 1, "Stops pulling chunks after the volatile one");
+        assert_eq!(result, ai.filter_io(&chunks.concat()).await, "Same verdict as scoring the full payload");
+
+        // A clean stream is scored to the end and sanitized like filter_io
+        let clean = ["stable ", "volatile-free ", "PI"];
+        assert_eq!(ai.filter_io_chunks(clean.into_iter()).await, ai.filter_io(&clean.concat()).await);
+    }
+
+    #[tokio::test]
+    async fn test_chunked_filter_scores_incrementally_and_surfaces_unavailable_model() {
+        // The built-in model scores each chunk once yet matches scoring the prefix read so far
+        let ai = AutonomousHyperAI::new();
+        let clean = ["P", "I", " ", "1"];
+        assert_eq!(ai.filter_io_chunks(clean.into_iter()).await, ai.filter_io(&clean.concat()).await);
+
+        let mut read = 0;
+        let crossing = ["PI PI ", "PI PI ", "PI PI "];
+        let result = ai.filter_io_chunks(crossing.iter().map(|c| { read += 1; *c })).await;
+        assert_eq!(read, 2, "Rejected once the running score crosses the threshold");
+        assert_eq!(result, ai.filter_io(&crossing[..2].concat()).await);
+
+        // A model that can't score fails the stream instead of being bypassed
+        struct OfflineModel;
+
+        impl VolatilityModel for OfflineModel {
+            fn score(&self, _input: &str) -> f64 {
+                0.0
+            }
+
+            fn try_score(&self, _input: &str) -> Result<f64, String> {
+                Err("model offline".to_string())
+            }
+        }
+
+        let offline = AutonomousHyperAI::new().with_model(Box::new(OfflineModel));
+        assert_eq!(offline.filter_io_chunks(["PI"].into_iter()).await.unwrap_err(), "AI unavailable: model offline");
+    }
+
+    // Local compliance endpoint that answers compliant and counts requests
+    async fn spawn_counting_compliance_stub() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let mut buf = [0u8; 1024];
+                let _ = socket.read(&mut buf).await;
+                let body = r#"{"compliant":true}"#;
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+        (format!("http://{}/compliance", addr), hits)
+    }
+
+    #[tokio::test]
+    async fn test_compliance_cache_skips_network_within_ttl() {
+        let (url, hits) = spawn_counting_compliance_stub().await;
+        let ai = AutonomousHyperAI::new()
+            .with_compliance_url(&url)
+            .with_compliance_ttl(Duration::from_secs(60));
+
+        ai.enforce_compliance().await.unwrap();
+        ai.enforce_compliance().await.unwrap();
+        assert_eq!(hits.load(Ordering::SeqCst), 1, "Second call within TTL is served from cache");
+        assert_eq!(ai.get_status().await, (true, false));
+
+        ai.invalidate_cache().await;
+        ai.enforce_compliance().await.unwrap();
+        assert_eq!(hits.load(Ordering::SeqCst), 2, "Invalidation forces a refresh");
+
+        // Without a TTL every enforcement queries the endpoint
+        let uncached = AutonomousHyperAI::new().with_compliance_url(&url);
+        uncached.enforce_compliance().await.unwrap();
+        uncached.enforce_compliance().await.unwrap();
+        assert_eq!(hits.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_explanation_lists_crypto_keyword() {
+        let ai = AutonomousHyperAI::new();
+        let input = "pi app bitcoin pi";
+
+        let explanation = ai.filter_io_explain(input);
+        assert!(explanation.rejected);
+        assert_eq!(explanation.threshold, 0.5);
+        assert_eq!(explanation.contributing_tokens[0].0, "bitcoin", "{:?}", explanation.contributing_tokens);
+        assert_eq!(explanation.contributing_tokens.len(), 3, "Repeated tokens are merged");
+
+        // Same score the filter rejected with
+        let err = ai.filter_io(input).await.unwrap_err();
+        assert_eq!(err, format!("Volatile input rejected: volatility score {:.2}", explanation.score));
+
+        // Models without attribution still report score and threshold
+        let fixed = AutonomousHyperAI::new().with_model(Box::new(FixedModel(0.2))).filter_io_explain(input);
+        assert_eq!((fixed.score, fixed.rejected), (0.2, false));
+        assert!(fixed.contributing_tokens.is_empty());
+    }
+
+    // Local webhook receiver that forwards each POSTed body to the returned channel
+    async fn spawn_webhook_capture() -> (String, tokio::sync::mpsc::UnboundedReceiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                // Read until the headers and the Content-Length body have arrived
+                let body = loop {
+                    let n = socket.read(&mut buf).await.unwrap_or(0);
+                    request.extend_from_slice(&buf[..n]);
+                    let text = String::from_utf8_lossy(&request).to_string();
+                    if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                        let length = head
+                            .lines()
+                            .find_map(|l| l.to_ascii_lowercase().strip_prefix("content-length:").map(|v| v.trim().parse::<usize>().unwrap()))
+                            .unwrap_or(0);
+                        if body.len() >= length || n == 0 {
+                            break body.to_string();
+                        }
+                    } else if n == 0 {
+                        break String::new();
+                    }
+                };
+                let _ = tx.send(body);
+                let _ = socket.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n").await;
+            }
+        });
+        (format!("http://{}/webhook", addr), rx)
+    }
+
+    #[tokio::test]
+    async fn test_compliance_webhook_posts_on_transition() {
+        let (url, mut bodies) = spawn_webhook_capture().await;
+        let ai = AutonomousHyperAI::new().with_compliance_mode(ComplianceMode::Scripted(vec![true, false, false]));
+        ai.set_compliance_webhook(url).await;
+
+        // Starts compliant, so the first answer changes nothing
+        ai.enforce_compliance().await.unwrap();
+        assert!(bodies.try_recv().is_err(), "No post without a transition");
+
+        ai.enforce_compliance().await.unwrap();
+        let body = tokio::time::timeout(Duration::from_secs(5), bodies.recv()).await.unwrap().unwrap();
+        let notification: ComplianceNotification = serde_json::from_str(&body).unwrap();
+        assert_eq!((notification.compliant, notification.stellar_halted), (false, true));
+        assert!(notification.ts > 0);
+
+        // Same state again: no further post
+        ai.enforce_compliance().await.unwrap();
+        assert!(bodies.try_recv().is_err());
+
+        // An unreachable webhook is logged, not returned
+        let unreachable = AutonomousHyperAI::new().with_compliance_mode(ComplianceMode::AlwaysNonCompliant);
+        unreachable.set_compliance_webhook("http://127.0.0.1:9/webhook".to_string()).await;
+        assert!(unreachable.enforce_compliance().await.is_ok());
+        assert_eq!(unreachable.get_status().await, (false, true));
+    }
+}
@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod test_master_control_final_integration_script {
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{Address, Env, Map, Symbol};
+    use crate::master_control_final_integration_script::MasterControlFinalIntegrationScript;
+    use crate::pi_mainnet_integration_real_time_synchronization::PiMainnetIntegrationRealTimeSynchronization;
+
+    #[test]
+    fn test_aggregate_reflects_degraded_subsystem() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, MasterControlFinalIntegrationScript);
+        let admin = Address::generate(&env);
+        env.mock_all_auths();
+
+        env.as_contract(&contract_id, || {
+            PiMainnetIntegrationRealTimeSynchronization::init(env.clone(), admin.clone());
+            assert_eq!(MasterControlFinalIntegrationScript::supremacy_readings(env.clone()).len(), 4);
+            assert_eq!(MasterControlFinalIntegrationScript::aggregate_supremacy(env.clone()), 100);
+            assert_eq!(MasterControlFinalIntegrationScript::lowest_supremacy(env.clone(), Map::new(&env)), 0);
+        });
+
+        // Each report authorizes the admin once, so every report gets its own frame
+        let report = |component: &str, latency_ms: i64| {
+            env.as_contract(&contract_id, || {
+                PiMainnetIntegrationRealTimeSynchronization::report_sync_result(env.clone(), admin.clone(), Symbol::new(&env, component), latency_ms);
+            });
+        };
+
+        // One of two reported components misses the 100ms SLA, so synchronization drags the aggregate to 50
+        report("transactions", 20);
+        report("nodes", 250);
+        env.as_contract(&contract_id, || {
+            let readings = MasterControlFinalIntegrationScript::supremacy_readings(env.clone());
+            assert_eq!(readings.get(Symbol::new(&env, "synchronization")), Some(50));
+            assert_eq!(MasterControlFinalIntegrationScript::aggregate_supremacy(env.clone()), 50);
+        });
+
+        // Recovering under the SLA restores the aggregate
+        report("nodes", 40);
+        env.as_contract(&contract_id, || {
+            assert_eq!(MasterControlFinalIntegrationScript::aggregate_supremacy(env.clone()), 100);
+        });
+    }
+
+    #[test]
+    fn test_snapshot_covers_every_subsystem() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, MasterControlFinalIntegrationScript);
+
+        env.as_contract(&contract_id, || {
+            let snapshot = MasterControlFinalIntegrationScript::ecosystem_snapshot(env.clone());
+            assert_eq!(snapshot.len(), 6);
+            for subsystem in ["security", "governance", "synchronization", "swarm", "capstone", "guardian"] {
+                let status = snapshot.get(Symbol::new(&env, subsystem));
+                assert!(status.is_some_and(|status| !status.is_empty()), "Missing status for {}", subsystem);
+            }
+        });
+    }
+}
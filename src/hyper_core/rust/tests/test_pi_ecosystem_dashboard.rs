@@ -0,0 +1,144 @@
+#[cfg(test)]
+mod common;
+
+#[cfg(test)]
+mod test_pi_ecosystem_dashboard {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use crate::hyper_ai_core::AutonomousHyperAI;
+    use crate::pi_transaction_engine::PITransactionEngine;
+    use crate::pi_mainnet_accelerator::{EvolutionSignals, PiMainnetAccelerator};
+    use crate::ecosystem_isolation_shield::EcosystemIsolationShield;
+    use crate::developer_app_orchestrator::DeveloperAppOrchestrator;
+    use crate::super_app_controller::SuperAppController;
+    use crate::pi_ecosystem_dashboard::{diff_dashboards, kpi_score, DashboardDiff, PiEcosystemDashboardModule};
+    use super::common::StableModel;
+
+    fn build_dashboard() -> PiEcosystemDashboardModule {
+        let ai_core = Arc::new(AutonomousHyperAI::new());
+        let tx_engine = Arc::new(PITransactionEngine::new(ai_core.clone()));
+        let mainnet_accelerator = Arc::new(PiMainnetAccelerator::new(ai_core.clone(), tx_engine.clone()));
+        let isolation_shield = Arc::new(EcosystemIsolationShield::new(ai_core.clone(), tx_engine.clone(), mainnet_accelerator.clone()));
+        let app_orchestrator = Arc::new(DeveloperAppOrchestrator::new(ai_core.clone(), tx_engine.clone(), mainnet_accelerator.clone(), isolation_shield.clone()));
+        let controller = Arc::new(SuperAppController::new(ai_core.clone(), tx_engine.clone(), mainnet_accelerator.clone(), isolation_shield.clone(), app_orchestrator.clone()));
+        PiEcosystemDashboardModule::new(ai_core, tx_engine, mainnet_accelerator, isolation_shield, app_orchestrator, controller)
+    }
+
+    fn parse_samples(text: &str) -> HashMap<String, f64> {
+        let mut samples = HashMap::new();
+        for line in text.lines().filter(|l| !l.starts_with('#')) {
+            let mut parts = line.split_whitespace();
+            let name = parts.next().expect("metric name");
+            let value: f64 = parts.next().expect("metric value").parse().expect("numeric value");
+            samples.insert(name.to_string(), value);
+        }
+        samples
+    }
+
+    #[tokio::test]
+    async fn test_export_prometheus_metric_names_and_values() {
+        let dashboard = build_dashboard();
+        let text = dashboard.export_prometheus().await;
+        let samples = parse_samples(&text);
+
+        // Fresh ecosystem: nothing processed yet, AI assumed compliant
+        assert_eq!(samples.len(), 5);
+        assert_eq!(samples["superpi_transactions_total"], 0.0);
+        assert_eq!(samples["superpi_mainnet_progress"], 0.0);
+        assert_eq!(samples["superpi_apps_running"], 0.0);
+        assert_eq!(samples["superpi_isolation_events_total"], 0.0);
+        assert_eq!(samples["superpi_compliance_rate"], 1.0);
+        assert!(text.contains("# TYPE superpi_compliance_rate gauge"));
+        assert!(text.contains("# HELP superpi_transactions_total"));
+    }
+
+    #[tokio::test]
+    async fn test_export_prometheus_reports_running_apps_and_evolved_compliance() {
+        let ai_core = Arc::new(AutonomousHyperAI::new().with_model(Box::new(StableModel)));
+        let tx_engine = Arc::new(PITransactionEngine::new(ai_core.clone()));
+        let mainnet_accelerator = Arc::new(PiMainnetAccelerator::new(ai_core.clone(), tx_engine.clone()).with_compliance_decay(0.05));
+        let isolation_shield = Arc::new(EcosystemIsolationShield::new(ai_core.clone(), tx_engine.clone(), mainnet_accelerator.clone()));
+        let app_orchestrator = Arc::new(DeveloperAppOrchestrator::new(ai_core.clone(), tx_engine.clone(), mainnet_accelerator.clone(), isolation_shield.clone()));
+        let controller = Arc::new(SuperAppController::new(ai_core.clone(), tx_engine.clone(), mainnet_accelerator.clone(), isolation_shield.clone(), app_orchestrator.clone()));
+        let dashboard = PiEcosystemDashboardModule::new(ai_core, tx_engine, mainnet_accelerator.clone(), isolation_shield, app_orchestrator.clone(), controller);
+
+        // Two apps managed, only one finished building
+        mainnet_accelerator.accelerate_mainnet().await.unwrap();
+        let running = app_orchestrator.deploy_app("dev_1".to_string(), "Stable PI code".to_string()).await.unwrap();
+        app_orchestrator.finalize_build(&running.app_id).await.unwrap();
+        app_orchestrator.deploy_app("dev_2".to_string(), "Stable PI code".to_string()).await.unwrap();
+
+        let idle = EvolutionSignals { recent_isolation_events: 0, app_uptime_rate: 0.0 };
+        let _ = mainnet_accelerator.evolve_system(idle).await;
+        let evolved = mainnet_accelerator.get_metrics().await.compliance_rate;
+        assert!(evolved < 1.0);
+
+        let samples = parse_samples(&dashboard.export_prometheus().await);
+        assert_eq!(samples["superpi_apps_running"], 1.0);
+        assert_eq!(samples["superpi_compliance_rate"], evolved);
+    }
+
+    #[tokio::test]
+    async fn test_kpi_score_blends_subsystem_states() {
+        let ai_core = Arc::new(AutonomousHyperAI::new().with_model(Box::new(StableModel)));
+        let tx_engine = Arc::new(PITransactionEngine::new(ai_core.clone()));
+        let mainnet_accelerator = Arc::new(PiMainnetAccelerator::new(ai_core.clone(), tx_engine.clone()));
+        let isolation_shield = Arc::new(EcosystemIsolationShield::new(ai_core.clone(), tx_engine.clone(), mainnet_accelerator.clone()));
+        let app_orchestrator = Arc::new(DeveloperAppOrchestrator::new(ai_core.clone(), tx_engine.clone(), mainnet_accelerator.clone(), isolation_shield.clone()));
+        let controller = Arc::new(SuperAppController::new(ai_core.clone(), tx_engine.clone(), mainnet_accelerator.clone(), isolation_shield.clone(), app_orchestrator.clone()));
+        let dashboard = PiEcosystemDashboardModule::new(ai_core, tx_engine, mainnet_accelerator.clone(), isolation_shield.clone(), app_orchestrator, controller);
+
+        // Fresh: full compliance and uptime, no isolations, mainnet not yet open
+        assert!((dashboard.generate_dashboard().await.kpi_score - 0.75).abs() < 1e-9);
+
+        // Mainnet open and five isolations in the window (one per minute) halve the isolation term
+        mainnet_accelerator.accelerate_mainnet().await.unwrap();
+        for _ in 0..5 {
+            assert!(isolation_shield.process_stream("Bitcoin news".to_string()).await.is_err());
+        }
+        assert!((dashboard.generate_dashboard().await.kpi_score - 0.925).abs() < 1e-9);
+
+        // Out-of-range inputs are clamped
+        assert_eq!(kpi_score(2.0, 2.0, 2.0, -1.0), 1.0);
+        assert_eq!(kpi_score(-1.0, -1.0, -1.0, f64::INFINITY), 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_export_json_serializes_valid_state() {
+        let dashboard = build_dashboard();
+        let json = dashboard.export_json().await.expect("Valid dashboard state serializes");
+
+        // A real document, not the old "{}" fallback
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["mainnet_status"]["progress"], 0.0);
+        assert!(value["kpi_score"].is_number());
+        assert!(value["controller_status"].is_object());
+    }
+
+    #[tokio::test]
+    async fn test_diff_reports_deltas_between_snapshots() {
+        let old = build_dashboard().generate_dashboard().await;
+        let mut new = old.clone();
+        new.timestamp = old.timestamp + chrono::Duration::seconds(30);
+        new.transaction_summary.total_transactions = old.transaction_summary.total_transactions + 12;
+        new.app_overview.apps_running = old.app_overview.apps_running + 3;
+        new.mainnet_status.progress = old.mainnet_status.progress + 0.25;
+        new.isolation_report.events_quarantined = old.isolation_report.events_quarantined + 4;
+
+        assert_eq!(
+            diff_dashboards(&old, &new),
+            DashboardDiff {
+                elapsed_secs: 30,
+                transactions_delta: 12,
+                apps_running_delta: 3,
+                mainnet_progress_delta: 0.25,
+                new_isolation_events: 4,
+            }
+        );
+
+        // Reversed order reports shrinkage; isolation counts never go negative
+        let reversed = diff_dashboards(&new, &old);
+        assert_eq!((reversed.elapsed_secs, reversed.transactions_delta, reversed.apps_running_delta), (-30, -12, -3));
+        assert_eq!(reversed.new_isolation_events, 0);
+    }
+}
@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod test_pi_exclusivity {
+    use crate::pi_exclusivity::{PiExclusivityConfig, PiExclusivityPolicy};
+    use crate::pi_transaction_engine::is_pi_address;
+
+    #[test]
+    fn test_default_policy_matches_pi_address_rule() {
+        let policy = PiExclusivityPolicy::default();
+        for receiver in ["pi_dev456", "pi_A1", "dev_456", "pi_", "pi_dev-456", "PI_dev456", "pi_dev456\n"] {
+            assert_eq!(policy.validate_receiver(receiver).is_ok(), is_pi_address(receiver), "{:?}", receiver);
+        }
+        assert_eq!(policy.validate_receiver("0x71C7").unwrap_err(), "receiver not a Pi address");
+    }
+
+    #[test]
+    fn test_banned_terms_match_whole_words_case_insensitively() {
+        let policy = PiExclusivityPolicy::default();
+        assert!(policy.validate_sender("miner_123").is_ok());
+        assert_eq!(policy.validate_sender("BTC_whale").unwrap_err(), "sender references non-PI network: btc");
+        assert_eq!(policy.validate_receiver("pi_eth").unwrap_err(), "receiver references non-PI network: eth", "Underscores split words");
+        assert!(policy.validate_sender("").is_err());
+
+        assert!(policy.validate_field("memo", "Bridge to Stellar").is_err());
+        assert!(policy.validate_field("memo", "method together with PI").is_ok(), "eth inside a word is fine");
+    }
+
+    #[test]
+    fn test_custom_policy_for_testnet() {
+        let policy = PiExclusivityPolicy::new(PiExclusivityConfig {
+            address_pattern: r"^testnet_[a-z]+$".to_string(),
+            banned_terms: vec!["Solana".to_string()],
+        })
+        .unwrap();
+        assert!(policy.validate_receiver("testnet_dev").is_ok());
+        assert!(policy.validate_receiver("pi_dev456").is_err());
+        assert!(policy.validate_field("memo", "solana airdrop").is_err());
+        assert!(policy.validate_field("memo", "bitcoin").is_ok(), "Only configured terms are banned");
+
+        let invalid = PiExclusivityConfig { address_pattern: "(".to_string(), ..PiExclusivityConfig::default() };
+        assert!(PiExclusivityPolicy::new(invalid).is_err());
+        let empty_term = PiExclusivityConfig { banned_terms: vec![" ".to_string()], ..PiExclusivityConfig::default() };
+        assert!(PiExclusivityPolicy::new(empty_term).is_err());
+    }
+}
@@ -0,0 +1,249 @@
+#[cfg(test)]
+mod common;
+
+#[cfg(test)]
+mod test_pi_mainnet_accelerator {
+    use std::sync::Arc;
+    use crate::hyper_ai_core::AutonomousHyperAI;
+    use crate::pi_transaction_engine::PITransactionEngine;
+    use crate::pi_mainnet_accelerator::{EvolutionSignals, PiMainnetAccelerator};
+    use super::common::StableModel;
+
+    fn build_accelerator() -> PiMainnetAccelerator {
+        // Unroutable compliance endpoint keeps tests offline
+        let ai_core = Arc::new(AutonomousHyperAI::new().with_compliance_url("http://127.0.0.1:9/compliance"));
+        let tx_engine = Arc::new(PITransactionEngine::new(ai_core.clone()));
+        PiMainnetAccelerator::new(ai_core, tx_engine)
+    }
+
+    #[tokio::test]
+    async fn test_high_isolation_drives_compliance_down() {
+        let accelerator = build_accelerator();
+        let mut previous = accelerator.get_metrics().await.compliance_rate;
+
+        for _ in 0..5 {
+            // Enforcement fails offline; the adaptation is applied before it
+            let _ = accelerator
+                .evolve_system(EvolutionSignals { recent_isolation_events: 100, app_uptime_rate: 1.0 })
+                .await;
+            let current = accelerator.get_metrics().await.compliance_rate;
+            assert!(current < previous, "Compliance decreases under isolation pressure");
+            assert!((0.0..=1.0).contains(&current));
+            previous = current;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_clean_signals_keep_compliance_bounded() {
+        let accelerator = build_accelerator();
+        let _ = accelerator
+            .evolve_system(EvolutionSignals { recent_isolation_events: 0, app_uptime_rate: 1.0 })
+            .await;
+        assert_eq!(accelerator.get_metrics().await.compliance_rate, 1.0, "Clamped at the upper bound");
+    }
+
+    #[tokio::test]
+    async fn test_manage_apps_reports_overflow_beyond_capacity() {
+        let ai_core = Arc::new(
+            AutonomousHyperAI::new()
+                .with_model(Box::new(StableModel))
+                .with_compliance_url("http://127.0.0.1:9/compliance"),
+        );
+        let tx_engine = Arc::new(PITransactionEngine::new(ai_core.clone()));
+        let accelerator = PiMainnetAccelerator::new(ai_core, tx_engine).with_max_apps_per_node(2);
+        accelerator.accelerate_mainnet().await.unwrap();
+
+        // 1000 nodes x 2 apps = 2000 slots; three apps overflow
+        let apps: Vec<String> = (0..2003).map(|i| format!("app_{}", i)).collect();
+        let err = accelerator.manage_apps(apps).await.unwrap_err();
+
+        assert!(err.contains("3 app(s) could not be placed"), "{}", err);
+        assert!(err.ends_with("app_2000, app_2001, app_2002"), "{}", err);
+        assert_eq!(accelerator.app_count().await, 2000, "Apps that fit are still placed");
+
+        // Full nodes reject further apps outright
+        let err = accelerator.manage_apps(vec!["app_late".to_string()]).await.unwrap_err();
+        assert!(err.ends_with("app_late"));
+        assert_eq!(accelerator.app_count().await, 2000);
+    }
+
+    #[tokio::test]
+    async fn test_node_load_sums_to_app_count() {
+        let ai_core = Arc::new(
+            AutonomousHyperAI::new()
+                .with_model(Box::new(StableModel))
+                .with_compliance_url("http://127.0.0.1:9/compliance"),
+        );
+        let tx_engine = Arc::new(PITransactionEngine::new(ai_core.clone()));
+        let accelerator = PiMainnetAccelerator::new(ai_core, tx_engine);
+        assert!(accelerator.node_load().await.is_empty());
+        assert_eq!(accelerator.most_loaded_node().await, None);
+
+        accelerator.accelerate_mainnet().await.unwrap();
+        let apps: Vec<String> = (0..1503).map(|i| format!("app_{}", i)).collect();
+        accelerator.manage_apps(apps).await.unwrap();
+
+        let load = accelerator.node_load().await;
+        assert_eq!(load.len(), 1000);
+        assert_eq!(load.iter().map(|(_, apps)| apps).sum::<usize>() as u64, accelerator.app_count().await);
+        assert_eq!(load[0], ("node_0".to_string(), 2));
+        assert_eq!(load[999], ("node_999".to_string(), 1));
+        assert_eq!(accelerator.most_loaded_node().await, Some(("node_0".to_string(), 2)));
+    }
+
+    #[tokio::test]
+    async fn test_node_failure_rate_halts_about_half_the_nodes() {
+        let ai_core = Arc::new(
+            AutonomousHyperAI::new()
+                .with_model(Box::new(StableModel))
+                .with_compliance_url("http://127.0.0.1:9/compliance"),
+        );
+        let tx_engine = Arc::new(PITransactionEngine::new(ai_core.clone()));
+        let build = || PiMainnetAccelerator::new(ai_core.clone(), tx_engine.clone()).with_node_failure_rate(0.5, 42);
+
+        let accelerator = build();
+        accelerator.accelerate_mainnet().await.unwrap();
+        let active = accelerator.active_node_count().await;
+        assert!((400..=600).contains(&active), "Roughly half of 1000 nodes survive: {}", active);
+
+        // Same seed, same survivors
+        let replay = build();
+        replay.accelerate_mainnet().await.unwrap();
+        assert_eq!(replay.active_node_count().await, active);
+
+        // Apps land only on survivors
+        let apps: Vec<String> = (0..active * 2).map(|i| format!("app_{}", i)).collect();
+        accelerator.manage_apps(apps).await.unwrap();
+        let loaded = accelerator.node_load().await.iter().filter(|(_, apps)| *apps > 0).count();
+        assert_eq!(loaded, active);
+    }
+
+    #[tokio::test]
+    async fn test_least_loaded_assignment_balances_uneven_nodes() {
+        let ai_core = Arc::new(
+            AutonomousHyperAI::new()
+                .with_model(Box::new(StableModel))
+                .with_compliance_url("http://127.0.0.1:9/compliance"),
+        );
+        let tx_engine = Arc::new(PITransactionEngine::new(ai_core.clone()));
+        let accelerator = PiMainnetAccelerator::new(ai_core, tx_engine);
+        accelerator.accelerate_mainnet().await.unwrap();
+
+        // Uneven start: the first 1000 nodes carry two apps each when 1000 empty nodes join
+        let first: Vec<String> = (0..2000).map(|i| format!("app_{}", i)).collect();
+        accelerator.manage_apps(first).await.unwrap();
+        accelerator.accelerate_mainnet().await.unwrap();
+
+        // New apps go to the idle nodes instead of piling onto loaded ones
+        let more: Vec<String> = (2000..4000).map(|i| format!("app_{}", i)).collect();
+        accelerator.manage_apps(more).await.unwrap();
+        let counts: Vec<usize> = accelerator.node_load().await.into_iter().map(|(_, apps)| apps).collect();
+        assert_eq!(counts.len(), 2000);
+        assert_eq!(counts.iter().sum::<usize>(), 4000);
+        assert_eq!((counts.iter().min(), counts.iter().max()), (Some(&2), Some(&2)), "Perfectly balanced");
+    }
+
+    #[tokio::test]
+    async fn test_reset_metrics_zeroes_counters_and_assignments() {
+        let ai_core = Arc::new(
+            AutonomousHyperAI::new()
+                .with_model(Box::new(StableModel))
+                .with_compliance_url("http://127.0.0.1:9/compliance"),
+        );
+        let tx_engine = Arc::new(PITransactionEngine::new(ai_core.clone()));
+        let accelerator = PiMainnetAccelerator::new(ai_core, tx_engine);
+        accelerator.accelerate_mainnet().await.unwrap();
+        accelerator.manage_apps(vec!["app_1".to_string()]).await.unwrap();
+        assert_eq!(accelerator.get_metrics().await.apps_processed, 1);
+        let _ = accelerator.evolve_system(EvolutionSignals { recent_isolation_events: 100, app_uptime_rate: 0.0 }).await;
+        assert!(accelerator.get_metrics().await.compliance_rate < 1.0);
+
+        accelerator.reset_metrics().await;
+        let metrics = accelerator.get_metrics().await;
+        assert_eq!(metrics.compliance_rate, 1.0);
+        assert_eq!(metrics.apps_processed, 0);
+        assert_eq!(accelerator.app_count().await, 0);
+        assert!(accelerator.node_load().await.iter().all(|(_, apps)| *apps == 0), "Assignments released with the counters");
+
+        // Nodes stay synced, so apps can be placed again straight away
+        assert_eq!(metrics.mainnet_open_progress, 1.0);
+        accelerator.manage_apps(vec!["app_2".to_string()]).await.unwrap();
+        assert_eq!(accelerator.app_count().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_compliance_decays_without_positive_signals() {
+        let accelerator = build_accelerator().with_compliance_decay(0.05);
+        let idle = EvolutionSignals { recent_isolation_events: 0, app_uptime_rate: 0.0 };
+
+        let mut previous = accelerator.get_metrics().await.compliance_rate;
+        for _ in 0..5 {
+            let _ = accelerator.evolve_system(idle.clone()).await;
+            let current = accelerator.get_metrics().await.compliance_rate;
+            assert!(current < previous, "Idle cycles lose compliance: {} -> {}", previous, current);
+            previous = current;
+        }
+        assert!((previous - 0.75).abs() < 1e-9);
+
+        // Decay bottoms out at zero
+        for _ in 0..20 {
+            let _ = accelerator.evolve_system(idle.clone()).await;
+        }
+        assert_eq!(accelerator.get_metrics().await.compliance_rate, 0.0);
+
+        // A ceiling caps how far healthy cycles can climb
+        let capped = build_accelerator().with_compliance_ceiling(0.9);
+        let _ = capped.evolve_system(EvolutionSignals { recent_isolation_events: 0, app_uptime_rate: 1.0 }).await;
+        assert_eq!(capped.get_metrics().await.compliance_rate, 0.9);
+    }
+
+    #[tokio::test]
+    async fn test_managing_apps_keeps_decayed_compliance() {
+        let ai_core = Arc::new(
+            AutonomousHyperAI::new()
+                .with_model(Box::new(StableModel))
+                .with_compliance_url("http://127.0.0.1:9/compliance"),
+        );
+        let tx_engine = Arc::new(PITransactionEngine::new(ai_core.clone()));
+        let accelerator = PiMainnetAccelerator::new(ai_core, tx_engine).with_compliance_decay(0.05);
+        accelerator.accelerate_mainnet().await.unwrap();
+
+        let idle = EvolutionSignals { recent_isolation_events: 0, app_uptime_rate: 0.0 };
+        for _ in 0..3 {
+            let _ = accelerator.evolve_system(idle.clone()).await;
+        }
+        let decayed = accelerator.get_metrics().await.compliance_rate;
+        assert!(decayed < 1.0);
+
+        // Deploying apps is not a compliance signal and must not reset the decayed rate
+        accelerator.manage_apps(vec!["app_a".to_string(), "app_b".to_string()]).await.unwrap();
+        let metrics = accelerator.get_metrics().await;
+        assert_eq!(metrics.compliance_rate, decayed);
+        assert_eq!(metrics.apps_processed, 2);
+    }
+
+    #[tokio::test]
+    async fn test_single_thread_pool_gives_same_assignment() {
+        let ai_core = Arc::new(
+            AutonomousHyperAI::new()
+                .with_model(Box::new(StableModel))
+                .with_compliance_url("http://127.0.0.1:9/compliance"),
+        );
+        let tx_engine = Arc::new(PITransactionEngine::new(ai_core.clone()));
+        let build = || PiMainnetAccelerator::new(ai_core.clone(), tx_engine.clone()).with_node_failure_rate(0.3, 7);
+
+        let limited = build().with_parallelism(1).unwrap();
+        assert_eq!(limited.parallelism(), 1);
+        let global = build();
+
+        let apps: Vec<String> = (0..1503).map(|i| format!("app_{}", i)).collect();
+        for accelerator in [&limited, &global] {
+            accelerator.accelerate_mainnet().await.unwrap();
+            accelerator.manage_apps(apps.clone()).await.unwrap();
+        }
+        assert_eq!(limited.node_load().await, global.node_load().await);
+        assert_eq!(limited.app_count().await, 1503);
+
+        assert!(build().with_parallelism(0).is_err());
+    }
+}
@@ -0,0 +1,125 @@
+#[cfg(test)]
+mod test_pi_mainnet_integration_real_time_synchronization {
+    use soroban_sdk::testutils::{Address as _, Events};
+    use soroban_sdk::{vec, Address, Env, IntoVal, String, Symbol};
+    use crate::pi_mainnet_integration_real_time_synchronization::PiMainnetIntegrationRealTimeSynchronization;
+
+    #[test]
+    fn test_sync_publishes_event() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, PiMainnetIntegrationRealTimeSynchronization);
+        let admin = Address::generate(&env);
+        env.mock_all_auths();
+
+        let event = env.as_contract(&contract_id, || {
+            PiMainnetIntegrationRealTimeSynchronization::init(env.clone(), admin.clone());
+            PiMainnetIntegrationRealTimeSynchronization::report_sync_result(env.clone(), admin.clone(), Symbol::new(&env, "nodes"), 1);
+            PiMainnetIntegrationRealTimeSynchronization::synchronize_real_time(env.clone(), Symbol::new(&env, "nodes"))
+        });
+
+        assert_eq!(
+            env.events().all(),
+            vec![
+                &env,
+                (
+                    contract_id.clone(),
+                    (Symbol::new(&env, "real_time_synced"), Symbol::new(&env, "nodes")).into_val(&env),
+                    (event.id, Symbol::new(&env, "synced"), 1i64, event.timestamp).into_val(&env),
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_latency_over_sla_desyncs_and_enforces() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, PiMainnetIntegrationRealTimeSynchronization);
+        let reporter = Address::generate(&env);
+        env.mock_all_auths();
+
+        // Each invocation authorizes the admin once, so every admin call gets its own frame
+        env.as_contract(&contract_id, || {
+            PiMainnetIntegrationRealTimeSynchronization::init(env.clone(), Address::generate(&env));
+            PiMainnetIntegrationRealTimeSynchronization::set_latency_sla(env.clone(), 50);
+        });
+        env.as_contract(&contract_id, || PiMainnetIntegrationRealTimeSynchronization::set_reporter(env.clone(), reporter.clone()));
+
+        env.as_contract(&contract_id, || {
+            let component = Symbol::new(&env, "transactions");
+            let pending = PiMainnetIntegrationRealTimeSynchronization::synchronize_real_time(env.clone(), component.clone());
+            assert_eq!(pending.sync_status, Symbol::new(&env, "pending"), "No fabricated success before a report");
+
+            assert_eq!(
+                PiMainnetIntegrationRealTimeSynchronization::report_sync_result(env.clone(), reporter.clone(), component.clone(), 250),
+                Symbol::new(&env, "desynced")
+            );
+
+            let event = PiMainnetIntegrationRealTimeSynchronization::synchronize_real_time(env.clone(), component.clone());
+            assert_eq!(event.sync_status, Symbol::new(&env, "desynced"));
+            assert_eq!(event.real_time_latency, 250);
+            assert_eq!(
+                PiMainnetIntegrationRealTimeSynchronization::enforce_synchronization_integrity(env.clone(), event),
+                Symbol::new(&env, "integrity_enforced")
+            );
+        });
+
+        // Recovering under the SLA restores integrity
+        env.as_contract(&contract_id, || {
+            let component = Symbol::new(&env, "transactions");
+            PiMainnetIntegrationRealTimeSynchronization::report_sync_result(env.clone(), reporter.clone(), component.clone(), 20);
+            let event = PiMainnetIntegrationRealTimeSynchronization::synchronize_real_time(env.clone(), component);
+            assert_eq!(
+                PiMainnetIntegrationRealTimeSynchronization::enforce_synchronization_integrity(env.clone(), event),
+                Symbol::new(&env, "integrity_maintained")
+            );
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "reporter not authorized")]
+    fn test_unregistered_reporter_is_rejected() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, PiMainnetIntegrationRealTimeSynchronization);
+        env.mock_all_auths();
+
+        env.as_contract(&contract_id, || {
+            PiMainnetIntegrationRealTimeSynchronization::init(env.clone(), Address::generate(&env));
+            // Signed, but neither the admin nor the registered reporter
+            PiMainnetIntegrationRealTimeSynchronization::report_sync_result(env.clone(), Address::generate(&env), Symbol::new(&env, "nodes"), 1);
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unauthorized_sla_change_is_rejected() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, PiMainnetIntegrationRealTimeSynchronization);
+
+        env.as_contract(&contract_id, || {
+            PiMainnetIntegrationRealTimeSynchronization::init(env.clone(), Address::generate(&env));
+            // No auth from the admin has been provided
+            PiMainnetIntegrationRealTimeSynchronization::set_latency_sla(env.clone(), 1000);
+        });
+    }
+
+    #[test]
+    fn test_hologram_renders_large_latency() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, PiMainnetIntegrationRealTimeSynchronization);
+        let admin = Address::generate(&env);
+        env.mock_all_auths();
+
+        env.as_contract(&contract_id, || {
+            PiMainnetIntegrationRealTimeSynchronization::init(env.clone(), admin.clone());
+            let component = Symbol::new(&env, "transactions");
+            PiMainnetIntegrationRealTimeSynchronization::report_sync_result(env.clone(), admin.clone(), component.clone(), i64::MAX);
+            let event = PiMainnetIntegrationRealTimeSynchronization::synchronize_real_time(env.clone(), component.clone());
+
+            let hologram = PiMainnetIntegrationRealTimeSynchronization::render_synchronization_hologram(env.clone(), event);
+            assert_eq!(hologram.len(), 4);
+            // Every line is a String; the component symbol is rendered as its text
+            assert_eq!(hologram.get(1).unwrap(), String::from_str(&env, "transactions"));
+            assert_eq!(hologram.get(3).unwrap(), String::from_str(&env, "Latency: 9223372036854775807ms"));
+        });
+    }
+}
@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod test_pi_mainnet_launch_governance_protocol {
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{Address, Env, Symbol};
+    use crate::pi_mainnet_launch_governance_protocol::PiMainnetLaunchGovernanceProtocol;
+
+    #[test]
+    fn test_mixed_votes_flip_approval_at_quorum() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, PiMainnetLaunchGovernanceProtocol);
+
+        env.mock_all_auths();
+
+        env.as_contract(&contract_id, || {
+            PiMainnetLaunchGovernanceProtocol::init(env.clone(), Address::generate(&env));
+            PiMainnetLaunchGovernanceProtocol::set_quorum(env.clone(), 100);
+            let proposal = PiMainnetLaunchGovernanceProtocol::submit_vote_proposal(env.clone(), Symbol::new(&env, "sync_nodes"));
+            assert_eq!((proposal.votes_for, proposal.votes_against), (0, 0), "Proposals open with zero votes");
+            assert!(!proposal.approved);
+
+            // Majority in favor but below quorum
+            assert!(!PiMainnetLaunchGovernanceProtocol::cast_vote(env.clone(), Address::generate(&env), proposal.id.clone(), true, 60));
+            assert!(!PiMainnetLaunchGovernanceProtocol::cast_vote(env.clone(), Address::generate(&env), proposal.id.clone(), false, 30));
+
+            // Reaching the quorum with a majority in favor approves it
+            assert!(PiMainnetLaunchGovernanceProtocol::cast_vote(env.clone(), Address::generate(&env), proposal.id.clone(), true, 10));
+            assert_eq!(PiMainnetLaunchGovernanceProtocol::get_tally(env.clone(), proposal.id.clone()), (70, 30));
+
+            // Opposition overtaking the majority revokes approval
+            assert!(!PiMainnetLaunchGovernanceProtocol::cast_vote(env.clone(), Address::generate(&env), proposal.id.clone(), false, 50));
+        });
+    }
+
+    #[test]
+    fn test_status_counts_stored_proposals() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, PiMainnetLaunchGovernanceProtocol);
+
+        env.mock_all_auths();
+
+        env.as_contract(&contract_id, || {
+            PiMainnetLaunchGovernanceProtocol::init(env.clone(), Address::generate(&env));
+            PiMainnetLaunchGovernanceProtocol::set_quorum(env.clone(), 10);
+            let first = PiMainnetLaunchGovernanceProtocol::submit_vote_proposal(env.clone(), Symbol::new(&env, "activate_mainnet"));
+            let second = PiMainnetLaunchGovernanceProtocol::submit_vote_proposal(env.clone(), Symbol::new(&env, "sync_nodes"));
+            assert_ne!(first.id, second.id, "Proposals in the same ledger get distinct ids");
+
+            PiMainnetLaunchGovernanceProtocol::cast_vote(env.clone(), Address::generate(&env), first.id.clone(), true, 10);
+
+            let stored = PiMainnetLaunchGovernanceProtocol::get_proposal(env.clone(), first.id.clone()).unwrap();
+            assert_eq!(stored.proposal_type, Symbol::new(&env, "activate_mainnet"));
+            assert_eq!(stored.votes_for, 10);
+            assert!(stored.approved);
+            assert!(PiMainnetLaunchGovernanceProtocol::get_proposal(env.clone(), Symbol::new(&env, "missing")).is_none());
+
+            let status = PiMainnetLaunchGovernanceProtocol::get_governance_status(env.clone());
+            assert_eq!(status.get(Symbol::new(&env, "proposals_submitted")), Some(2));
+            assert_eq!(status.get(Symbol::new(&env, "proposals_approved")), Some(1));
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unauthorized_rule_update_is_rejected() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, PiMainnetLaunchGovernanceProtocol);
+
+        env.as_contract(&contract_id, || {
+            PiMainnetLaunchGovernanceProtocol::init(env.clone(), Address::generate(&env));
+            // No auth from the admin has been provided
+            let _ = PiMainnetLaunchGovernanceProtocol::update_governance_rules(env.clone(), Symbol::new(&env, "new_rule"));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "proposal not found")]
+    fn test_vote_on_unknown_proposal_is_rejected() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, PiMainnetLaunchGovernanceProtocol);
+        env.mock_all_auths();
+
+        env.as_contract(&contract_id, || {
+            PiMainnetLaunchGovernanceProtocol::init(env.clone(), Address::generate(&env));
+            PiMainnetLaunchGovernanceProtocol::cast_vote(env.clone(), Address::generate(&env), Symbol::new(&env, "missing"), true, 10);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "already voted")]
+    fn test_second_vote_by_same_voter_is_rejected() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, PiMainnetLaunchGovernanceProtocol);
+        env.mock_all_auths();
+
+        let voter = Address::generate(&env);
+        let proposal = env.as_contract(&contract_id, || {
+            PiMainnetLaunchGovernanceProtocol::init(env.clone(), Address::generate(&env));
+            let proposal = PiMainnetLaunchGovernanceProtocol::submit_vote_proposal(env.clone(), Symbol::new(&env, "sync_nodes"));
+            PiMainnetLaunchGovernanceProtocol::cast_vote(env.clone(), voter.clone(), proposal.id.clone(), true, 10);
+            proposal
+        });
+        // Each invocation authorizes the voter once, so the repeat vote gets its own frame
+        env.as_contract(&contract_id, || {
+            PiMainnetLaunchGovernanceProtocol::cast_vote(env.clone(), voter.clone(), proposal.id.clone(), false, 10);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "vote tally overflow")]
+    fn test_vote_tally_overflow_is_rejected() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, PiMainnetLaunchGovernanceProtocol);
+        env.mock_all_auths();
+
+        env.as_contract(&contract_id, || {
+            PiMainnetLaunchGovernanceProtocol::init(env.clone(), Address::generate(&env));
+            let proposal = PiMainnetLaunchGovernanceProtocol::submit_vote_proposal(env.clone(), Symbol::new(&env, "sync_nodes"));
+            PiMainnetLaunchGovernanceProtocol::cast_vote(env.clone(), Address::generate(&env), proposal.id.clone(), true, i64::MAX);
+            PiMainnetLaunchGovernanceProtocol::cast_vote(env.clone(), Address::generate(&env), proposal.id.clone(), true, 1);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "already initialized")]
+    fn test_second_init_is_rejected() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, PiMainnetLaunchGovernanceProtocol);
+
+        env.as_contract(&contract_id, || {
+            PiMainnetLaunchGovernanceProtocol::init(env.clone(), Address::generate(&env));
+            // A second init must not be able to replace the admin
+            PiMainnetLaunchGovernanceProtocol::init(env.clone(), Address::generate(&env));
+        });
+    }
+
+    #[test]
+    fn test_admin_updates_rules_and_transfers_admin() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, PiMainnetLaunchGovernanceProtocol);
+        env.mock_all_auths();
+
+        // Each invocation authorizes the admin once, so every call gets its own frame
+        env.as_contract(&contract_id, || {
+            PiMainnetLaunchGovernanceProtocol::init(env.clone(), Address::generate(&env));
+            assert_eq!(
+                PiMainnetLaunchGovernanceProtocol::update_governance_rules(env.clone(), Symbol::new(&env, "new_rule")),
+                Ok(Symbol::new(&env, "updated"))
+            );
+        });
+
+        let new_admin = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            PiMainnetLaunchGovernanceProtocol::transfer_admin(env.clone(), new_admin.clone());
+        });
+        env.as_contract(&contract_id, || {
+            assert_eq!(crate::access_control::get_admin(&env), new_admin);
+        });
+    }
+}
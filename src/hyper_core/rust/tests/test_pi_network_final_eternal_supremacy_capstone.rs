@@ -0,0 +1,20 @@
+#[cfg(test)]
+mod test_pi_network_final_eternal_supremacy_capstone {
+    use soroban_sdk::Env;
+    use crate::pi_network_final_eternal_supremacy_capstone::PiNetworkFinalEternalSupremacyCapstone;
+
+    #[test]
+    fn test_persistent_validation_failure_stops_after_max_attempts() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, PiNetworkFinalEternalSupremacyCapstone);
+
+        env.as_contract(&contract_id, || {
+            // The quantum validation score (0.99) never exceeds the 0.99 threshold, so every attempt fails
+            assert!(!PiNetworkFinalEternalSupremacyCapstone::achieve_final_eternal_supremacy(env.clone(), 4));
+            assert_eq!(PiNetworkFinalEternalSupremacyCapstone::get_last_attempts(env.clone()), 4);
+
+            assert!(!PiNetworkFinalEternalSupremacyCapstone::achieve_final_eternal_supremacy(env.clone(), 0));
+            assert_eq!(PiNetworkFinalEternalSupremacyCapstone::get_last_attempts(env.clone()), 0);
+        });
+    }
+}
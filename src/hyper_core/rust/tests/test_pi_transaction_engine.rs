@@ -0,0 +1,541 @@
+#[cfg(test)]
+mod common;
+
+#[cfg(test)]
+mod test_pi_transaction_engine {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use tokio_util::sync::CancellationToken;
+    use crate::hyper_ai_core::{AutonomousHyperAI, VolatilityModel};
+    use crate::pi_exclusivity::{PiExclusivityConfig, PiExclusivityPolicy};
+    use crate::pi_transaction_engine::{
+        is_pi_address, round_amount, EngineConfig, InMemoryTransactionStore, JsonFileTransactionStore, ProofKey, PITransaction, PITransactionEngine, PITransactionType, PITransactionTypeDiscriminant, TransactionStore,
+    };
+    use super::common::StableModel;
+
+    fn stable_ai() -> Arc<AutonomousHyperAI> {
+        Arc::new(AutonomousHyperAI::new().with_model(Box::new(StableModel)))
+    }
+
+    fn sample_tx(id: &str) -> PITransaction {
+        PITransaction {
+            id: id.to_string(),
+            sender: "miner_123".to_string(),
+            receiver: "pi_dev456".to_string(),
+            amount: 1000.0,
+            tx_type: PITransactionType::MiningReward,
+            source_proof: String::new(),
+            timestamp: now_secs(),
+            fee: 0.0,
+            nonce: None,
+        }
+    }
+
+    fn now_secs() -> u64 {
+        std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs()
+    }
+
+    fn temp_store_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("{}_{}.jsonl", name, uuid::Uuid::new_v4()))
+    }
+
+    #[tokio::test]
+    async fn test_file_store_recovers_history_after_restart() {
+        let path = temp_store_path("superpi_tx_history");
+
+        // First engine accepts transactions and its processor writes them through the file-backed store
+        let engine = PITransactionEngine::with_store(stable_ai(), Box::new(JsonFileTransactionStore::new(path.clone())));
+        engine.process_transaction(signed_tx(&engine, "tx_001")).await.unwrap();
+        engine.process_transaction(signed_tx(&engine, "tx_002")).await.unwrap();
+        let shutdown = CancellationToken::new();
+        shutdown.cancel();
+        engine.run_processor_until_drained(shutdown).await;
+        assert_eq!(engine.get_transactions().await.len(), 2);
+        drop(engine);
+
+        // A fresh engine against the same file recovers the prior history
+        let restarted = PITransactionEngine::with_store(stable_ai(), Box::new(JsonFileTransactionStore::new(path.clone())));
+        let history = restarted.get_transactions().await;
+        let ids: Vec<&str> = history.iter().map(|tx| tx.id.as_str()).collect();
+        assert_eq!(ids, vec!["tx_001", "tx_002"], "History recovered in append order");
+
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[tokio::test]
+    async fn test_default_engine_starts_with_empty_history() {
+        let engine = PITransactionEngine::new(Arc::new(AutonomousHyperAI::new()));
+        assert!(engine.get_transactions().await.is_empty());
+    }
+
+    #[test]
+    fn test_custom_stable_value_drives_amount_boundary() {
+        let config = EngineConfig { pi_stable_value: 500.0, dual_value_multiplier: 2.0, ..EngineConfig::default() };
+        let engine = PITransactionEngine::new_with_config(Arc::new(AutonomousHyperAI::new()), config).unwrap();
+
+        assert!(engine.validate_amount(500.0).is_ok(), "Boundary value accepted");
+        assert!(engine.validate_amount(500.01).is_err(), "Above custom stable value rejected");
+        assert!(engine.validate_amount(1000.0).is_err(), "Default-valid amount rejected under testnet config");
+        assert!(engine.validate_amount(0.0).is_err());
+    }
+
+    #[test]
+    fn test_non_positive_multiplier_rejected() {
+        let config = EngineConfig { pi_stable_value: 500.0, dual_value_multiplier: 0.0, ..EngineConfig::default() };
+        assert!(PITransactionEngine::new_with_config(Arc::new(AutonomousHyperAI::new()), config).is_err());
+    }
+
+    fn keyed_engine(key: &[u8]) -> PITransactionEngine {
+        let config = EngineConfig { proof_key: Some(ProofKey(key.to_vec())), ..EngineConfig::default() };
+        PITransactionEngine::new_with_config(Arc::new(AutonomousHyperAI::new()), config).unwrap()
+    }
+
+    #[test]
+    fn test_hmac_proof_with_wrong_key_rejected() {
+        let engine = keyed_engine(b"mainnet-secret");
+        let attacker = keyed_engine(b"guessed-secret");
+
+        let mut tx = sample_tx("tx_hmac");
+        tx.source_proof = engine.generate_source_proof_hmac(&tx.tx_type, &tx.sender).unwrap();
+        assert!(engine.verify_source_proof(&tx).is_ok(), "Proof under the engine key verifies");
+
+        tx.source_proof = attacker.generate_source_proof_hmac(&tx.tx_type, &tx.sender).unwrap();
+        assert!(engine.verify_source_proof(&tx).is_err(), "Proof under a different key is rejected");
+
+        // The unkeyed hash is no longer accepted once a key is configured
+        tx.source_proof = engine.generate_source_proof(&tx.tx_type, &tx.sender);
+        assert!(engine.verify_source_proof(&tx).is_err());
+    }
+
+    #[test]
+    fn test_unkeyed_engine_keeps_plain_hash_proof() {
+        let engine = PITransactionEngine::new(Arc::new(AutonomousHyperAI::new()));
+        let mut tx = sample_tx("tx_plain");
+        tx.source_proof = engine.generate_source_proof(&tx.tx_type, &tx.sender);
+        assert!(engine.verify_source_proof(&tx).is_ok());
+        assert!(engine.generate_source_proof_hmac(&tx.tx_type, &tx.sender).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_batch_reports_duplicate_and_invalid_amount_in_order() {
+        let engine = PITransactionEngine::new(stable_ai());
+        let signed = |id: &str, amount: f64| {
+            let mut tx = sample_tx(id);
+            tx.amount = amount;
+            tx.source_proof = engine.generate_source_proof(&tx.tx_type, &tx.sender);
+            tx
+        };
+
+        let batch = vec![signed("tx_a", 10.0), signed("tx_a", 10.0), signed("tx_b", -5.0), signed("tx_c", 10.0)];
+        let results = engine.process_transactions(batch).await;
+
+        assert_eq!(results.len(), 4, "One result per submitted transaction");
+        assert!(results[0].is_ok());
+        assert_eq!(results[1].as_ref().unwrap_err(), "Duplicate transaction id: tx_a");
+        assert!(results[2].as_ref().unwrap_err().starts_with("Invalid PI amount"));
+        assert!(results[3].is_ok());
+
+        // An id accepted in an earlier batch stays a duplicate
+        let retry = engine.process_transactions(vec![signed("tx_c", 10.0)]).await;
+        assert!(retry[0].is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_batch_validation_does_not_block_other_submitters() {
+        // Scoring of the batch's transaction waits until the test opens the gate
+        struct GatedModel {
+            gate: std::sync::Mutex<std::sync::mpsc::Receiver<()>>,
+        }
+
+        impl VolatilityModel for GatedModel {
+            fn score(&self, input: &str) -> f64 {
+                if input.contains("tx_slow") {
+                    self.gate.lock().unwrap().recv().unwrap();
+                }
+                0.0
+            }
+        }
+
+        let (open_gate, gate) = std::sync::mpsc::channel();
+        let ai_core = Arc::new(AutonomousHyperAI::new().with_model(Box::new(GatedModel { gate: std::sync::Mutex::new(gate) })));
+        let engine = Arc::new(PITransactionEngine::new(ai_core));
+
+        let batch_engine = engine.clone();
+        let slow = signed_tx(&engine, "tx_slow");
+        let batch = tokio::spawn(async move { batch_engine.process_transactions(vec![slow]).await });
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        // The batch is still validating, yet a single submission goes straight through
+        let fast = tokio::time::timeout(std::time::Duration::from_secs(5), engine.process_transaction(signed_tx(&engine, "tx_fast"))).await;
+        open_gate.send(()).unwrap();
+        assert!(matches!(fast, Ok(Ok(()))), "{:?}", fast);
+        assert!(batch.await.unwrap()[0].is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_transaction_pagination() {
+        let store = InMemoryTransactionStore::new();
+        for i in 0..5 {
+            store.append(&sample_tx(&format!("tx_{}", i))).unwrap();
+        }
+        let engine = PITransactionEngine::with_store(Arc::new(AutonomousHyperAI::new()), Box::new(store));
+
+        let (first, total) = engine.get_transactions_page(0, 2).await;
+        assert_eq!(total, 5);
+        assert_eq!(first.iter().map(|tx| tx.id.as_str()).collect::<Vec<_>>(), vec!["tx_0", "tx_1"]);
+
+        // Last page is partial
+        let (last, total) = engine.get_transactions_page(4, 2).await;
+        assert_eq!(total, 5);
+        assert_eq!(last.len(), 1);
+        assert_eq!(last[0].id, "tx_4");
+
+        // Offset beyond the end yields an empty page with the correct total
+        let (empty, total) = engine.get_transactions_page(10, 2).await;
+        assert!(empty.is_empty());
+        assert_eq!(total, 5);
+    }
+
+    fn fee_engine(fee_rate: f64) -> PITransactionEngine {
+        let config = EngineConfig { fee_rate, ..EngineConfig::default() };
+        PITransactionEngine::new_with_config(stable_ai(), config).unwrap()
+    }
+
+    fn signed_tx(engine: &PITransactionEngine, id: &str) -> PITransaction {
+        let mut tx = sample_tx(id);
+        tx.source_proof = engine.generate_source_proof(&tx.tx_type, &tx.sender);
+        tx
+    }
+
+    #[tokio::test]
+    async fn test_fee_computed_and_recorded() {
+        let engine = Arc::new(fee_engine(0.01));
+        engine.process_transaction(signed_tx(&engine, "tx_fee_1")).await.unwrap();
+        engine.process_transaction(signed_tx(&engine, "tx_fee_2")).await.unwrap();
+
+        let processor = engine.clone();
+        let shutdown = CancellationToken::new();
+        let token = shutdown.clone();
+        let handle = tokio::spawn(async move { processor.run_processor(token).await });
+        while engine.get_transactions().await.len() < 2 {
+            tokio::task::yield_now().await;
+        }
+        shutdown.cancel();
+        handle.await.unwrap();
+
+        // 1% of the recorded (multiplier-adjusted) amount, not of the 1000 PI submitted
+        let history = engine.get_transactions().await;
+        assert!((history[0].amount - 3141.59).abs() < 1e-9);
+        assert!(history.iter().all(|tx| (tx.fee - tx.amount * 0.01).abs() < 1e-9), "Fee stored on each transaction");
+        let recorded_fees: f64 = history.iter().map(|tx| tx.fee).sum();
+        assert!((engine.total_fees().await - recorded_fees).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_fee_rate_consuming_the_amount_rejected_at_config_time() {
+        // A rate of 1.0 or more would leave nothing of any transaction, so it never reaches processing
+        for fee_rate in [1.0, 1.5, -0.1, f64::NAN, f64::INFINITY] {
+            let config = EngineConfig { fee_rate, ..EngineConfig::default() };
+            let err = PITransactionEngine::new_with_config(stable_ai(), config).err();
+            assert_eq!(err.as_deref(), Some("Invalid engine config: fee rate must be at least 0.0 and below 1.0"), "{}", fee_rate);
+        }
+
+        // Just below 1.0 still leaves a remainder, so transactions are accepted
+        let engine = fee_engine(0.99);
+        engine.process_transaction(signed_tx(&engine, "tx_fee_most")).await.unwrap();
+        assert!(engine.total_fees().await > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_replay_window_rejects_stale_and_future_timestamps() {
+        let config = EngineConfig { max_tx_age: std::time::Duration::from_secs(60), ..EngineConfig::default() };
+        let engine = PITransactionEngine::new_with_config(stable_ai(), config).unwrap();
+
+        let mut stale = signed_tx(&engine, "tx_stale");
+        stale.timestamp = now_secs() - 3600;
+        let err = engine.process_transaction(stale).await.unwrap_err();
+        assert!(err.starts_with("Stale transaction"), "{}", err);
+
+        let mut future = signed_tx(&engine, "tx_future");
+        future.timestamp = now_secs() + 3600;
+        let err = engine.process_transaction(future).await.unwrap_err();
+        assert!(err.contains("too far in the future"), "{}", err);
+
+        let mut fresh = signed_tx(&engine, "tx_fresh");
+        fresh.timestamp = now_secs() - 30;
+        assert!(engine.process_transaction(fresh).await.is_ok(), "Within the window and skew");
+    }
+
+    #[test]
+    fn test_round_amount_is_exact_and_stable_when_summed() {
+        // 1000 * 3.14159 carries float noise before rounding
+        assert_ne!(1000.0 * 3.14159, 3141.59);
+        assert_eq!(round_amount(1000.0 * 3.14159, 6), 3141.59);
+        assert_eq!(round_amount(2.0000005, 6), 2.000001, "Half rounds away from zero");
+        assert_eq!(round_amount(-2.0000005, 6), -2.000001);
+
+        let total: f64 = (0..10_000).map(|_| round_amount(0.1 * 3.14159, 6)).sum();
+        assert!((total - 3141.59).abs() < 1e-6, "Summed drift stays within tolerance: {}", total);
+
+        let config = EngineConfig { amount_precision: 16, ..EngineConfig::default() };
+        assert!(PITransactionEngine::new_with_config(stable_ai(), config).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_adjusted_amount_is_rounded_to_precision() {
+        let config = EngineConfig { amount_precision: 2, ..EngineConfig::default() };
+        let engine = Arc::new(PITransactionEngine::new_with_config(stable_ai(), config).unwrap());
+        engine.process_transaction(signed_tx(&engine, "tx_round")).await.unwrap();
+
+        let processor = engine.clone();
+        let shutdown = CancellationToken::new();
+        let token = shutdown.clone();
+        let handle = tokio::spawn(async move { processor.run_processor(token).await });
+        while engine.get_transactions().await.is_empty() {
+            tokio::task::yield_now().await;
+        }
+        shutdown.cancel();
+        handle.await.unwrap();
+
+        assert_eq!(engine.get_transactions().await[0].amount, 3141.59);
+    }
+
+    #[test]
+    fn test_default_rule_accepts_only_pi_addresses() {
+        assert!(is_pi_address("pi_dev456"));
+        assert!(is_pi_address("pi_A1"));
+        for external in ["dev_456", "pi_", "pi_dev-456", "0x71C7656EC7ab88b098defB751B7401B5f6d8976F", "PI_dev456", "pi_dev456\n"] {
+            assert!(!is_pi_address(external), "{:?} is not a Pi address", external);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_external_receiver_rejected() {
+        let engine = PITransactionEngine::new(stable_ai());
+        assert!(engine.process_transaction(signed_tx(&engine, "tx_pi")).await.is_ok());
+
+        let mut external = signed_tx(&engine, "tx_external");
+        external.receiver = "0x71C7656EC7ab88b098defB751B7401B5f6d8976F".to_string();
+        assert_eq!(engine.process_transaction(external).await.unwrap_err(), "receiver not a Pi address");
+
+        // A custom validator replaces the default rule
+        let engine = PITransactionEngine::new(stable_ai()).with_receiver_validator(Box::new(|r: &str| r.starts_with("testnet_")));
+        let mut testnet = signed_tx(&engine, "tx_testnet");
+        testnet.receiver = "testnet_dev".to_string();
+        assert!(engine.process_transaction(testnet).await.is_ok());
+        assert!(engine.process_transaction(signed_tx(&engine, "tx_mainnet")).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_drain_processes_queued_transactions_before_exit() {
+        let engine = PITransactionEngine::new(stable_ai());
+        for i in 0..5 {
+            engine.process_transaction(signed_tx(&engine, &format!("tx_drain_{}", i))).await.unwrap();
+        }
+
+        // Cancelled before the processor ever runs: everything queued is still handled
+        let shutdown = CancellationToken::new();
+        shutdown.cancel();
+        engine.run_processor_until_drained(shutdown).await;
+
+        let history = engine.get_transactions().await;
+        assert_eq!(history.len(), 5);
+        assert_eq!(history[4].id, "tx_drain_4");
+        assert!(!engine.is_processor_running().await);
+
+        let err = engine.process_transaction(signed_tx(&engine, "tx_late")).await.unwrap_err();
+        assert!(err.starts_with("Queue error"), "Closed queue rejects new transactions: {}", err);
+
+        // The refused transaction claimed nothing, so resubmitting it isn't reported as a duplicate
+        let retry = engine.process_transaction(signed_tx(&engine, "tx_late")).await.unwrap_err();
+        assert!(retry.starts_with("Queue error"), "{}", retry);
+    }
+
+    #[tokio::test]
+    async fn test_p2p_transfer_over_type_limit_rejected() {
+        let config = EngineConfig {
+            type_limits: [(PITransactionTypeDiscriminant::P2PTransfer, 500.0)].into_iter().collect(),
+            ..EngineConfig::default()
+        };
+        let engine = PITransactionEngine::new_with_config(stable_ai(), config).unwrap();
+
+        // 1000 PI is well within the global ceiling but over the P2P limit
+        let mut transfer = sample_tx("tx_p2p");
+        transfer.tx_type = PITransactionType::P2PTransfer;
+        transfer.source_proof = engine.generate_source_proof(&transfer.tx_type, &transfer.sender);
+        assert_eq!(engine.process_transaction(transfer).await.unwrap_err(), "Invalid PI amount: exceeds P2PTransfer limit of 500");
+
+        // Types without a limit only face the global ceiling
+        assert!(engine.process_transaction(signed_tx(&engine, "tx_mining")).await.is_ok());
+
+        let config = EngineConfig {
+            type_limits: [(PITransactionTypeDiscriminant::MiningReward, 0.0)].into_iter().collect(),
+            ..EngineConfig::default()
+        };
+        assert!(PITransactionEngine::new_with_config(stable_ai(), config).is_err());
+    }
+
+    fn nonce_engine() -> PITransactionEngine {
+        let config = EngineConfig { enforce_nonces: true, ..EngineConfig::default() };
+        PITransactionEngine::new_with_config(stable_ai(), config).unwrap()
+    }
+
+    fn nonced_tx(engine: &PITransactionEngine, id: &str, nonce: u64) -> PITransaction {
+        let mut tx = signed_tx(engine, id);
+        tx.nonce = Some(nonce);
+        tx
+    }
+
+    #[tokio::test]
+    async fn test_refused_transaction_releases_its_nonce() {
+        let engine = nonce_engine();
+        engine.process_transaction(nonced_tx(&engine, "tx_1", 1)).await.unwrap();
+        let shutdown = CancellationToken::new();
+        shutdown.cancel();
+        engine.run_processor_until_drained(shutdown).await;
+
+        // The closed queue refuses nonce 2, and the claim is undone rather than leaving 2 as the last accepted
+        let err = engine.process_transaction(nonced_tx(&engine, "tx_2", 2)).await.unwrap_err();
+        assert_eq!(err, "Queue error: channel closed");
+        let retry = engine.process_transaction(nonced_tx(&engine, "tx_2_again", 2)).await.unwrap_err();
+        assert_eq!(retry, "Queue error: channel closed", "Not a stale nonce");
+
+        // Likewise within a batch: each refusal is rolled back before the next item claims
+        let results = engine.process_transactions(vec![nonced_tx(&engine, "tx_3", 3), nonced_tx(&engine, "tx_3b", 3)]).await;
+        assert_eq!(results, vec![Err("Queue error: channel closed".to_string()), Err("Queue error: channel closed".to_string())]);
+    }
+
+    #[tokio::test]
+    async fn test_in_order_nonces_accepted() {
+        let engine = nonce_engine();
+        assert!(engine.process_transaction(nonced_tx(&engine, "tx_1", 1)).await.is_ok());
+        assert!(engine.process_transaction(nonced_tx(&engine, "tx_2", 2)).await.is_ok());
+        assert!(engine.process_transaction(nonced_tx(&engine, "tx_5", 5)).await.is_ok(), "Gaps are allowed");
+
+        // Nonces are tracked per sender
+        let mut other = nonced_tx(&engine, "tx_other", 1);
+        other.sender = "miner_789".to_string();
+        other.source_proof = engine.generate_source_proof(&other.tx_type, &other.sender);
+        assert!(engine.process_transaction(other).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_out_of_order_and_replayed_nonces_rejected() {
+        let engine = nonce_engine();
+        assert!(engine.process_transaction(nonced_tx(&engine, "tx_3", 3)).await.is_ok());
+
+        let err = engine.process_transaction(nonced_tx(&engine, "tx_2", 2)).await.unwrap_err();
+        assert_eq!(err, "Stale nonce 2 for sender miner_123: last accepted 3");
+
+        // Same nonce under a fresh id is a replay
+        assert!(engine.process_transaction(nonced_tx(&engine, "tx_3_again", 3)).await.is_err());
+
+        let results = engine
+            .process_transactions(vec![nonced_tx(&engine, "tx_4", 4), nonced_tx(&engine, "tx_4b", 4), signed_tx(&engine, "tx_none")])
+            .await;
+        assert!(results[0].is_ok());
+        assert!(results[1].as_ref().unwrap_err().starts_with("Stale nonce"));
+        assert_eq!(results[2].as_ref().unwrap_err(), "Missing nonce for sender miner_123");
+
+        // A rejected nonce doesn't burn the id
+        assert!(engine.process_transaction(nonced_tx(&engine, "tx_2", 5)).await.is_ok());
+
+        // Without enforcement nonces are ignored
+        let lax = PITransactionEngine::new(stable_ai());
+        assert!(lax.process_transaction(nonced_tx(&lax, "tx_9", 9)).await.is_ok());
+        assert!(lax.process_transaction(nonced_tx(&lax, "tx_1", 1)).await.is_ok());
+    }
+
+    #[test]
+    fn test_transaction_type_json_names_are_pinned() {
+        let pinned = [
+            (PITransactionType::MiningReward, "\"mining_reward\""),
+            (PITransactionType::ContributionReward, "\"contribution_reward\""),
+            (PITransactionType::P2PTransfer, "\"p2p_transfer\""),
+        ];
+        for (tx_type, json) in pinned {
+            assert_eq!(serde_json::to_string(&tx_type).unwrap(), json);
+            let parsed: PITransactionType = serde_json::from_str(json).unwrap();
+            assert_eq!(serde_json::to_string(&parsed).unwrap(), json, "Round trip for {:?}", tx_type);
+        }
+
+        // Records written before the rename still parse
+        let legacy: PITransactionType = serde_json::from_str("\"P2PTransfer\"").unwrap();
+        assert!(matches!(legacy, PITransactionType::P2PTransfer));
+        assert!(serde_json::from_str::<PITransactionType>("\"p2_p_transfer\"").is_err());
+    }
+
+    // Remote-style model: unavailable until switched on, then volatile only for marked transactions
+    struct FlakyModel {
+        available: Arc<AtomicBool>,
+    }
+
+    impl VolatilityModel for FlakyModel {
+        fn score(&self, input: &str) -> f64 {
+            if input.contains("tx_volatile") { 0.9 } else { 0.0 }
+        }
+
+        fn try_score(&self, input: &str) -> Result<f64, String> {
+            if self.available.load(Ordering::SeqCst) {
+                Ok(self.score(input))
+            } else {
+                Err("model offline".to_string())
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transient_ai_failure_is_retried_after_recovery() {
+        let available = Arc::new(AtomicBool::new(false));
+        let ai_core = Arc::new(AutonomousHyperAI::new().with_model(Box::new(FlakyModel { available: available.clone() })));
+        let engine = PITransactionEngine::new(ai_core);
+
+        let err = engine.process_transaction(signed_tx(&engine, "tx_1")).await.unwrap_err();
+        assert_eq!(err, "AI unavailable: model offline; transaction queued for retry");
+        assert!(engine.process_transaction(signed_tx(&engine, "tx_volatile")).await.is_err());
+        assert_eq!(engine.retry_queue_len().await, 2);
+
+        // Still down: both stay parked
+        assert!(engine.retry_failed().await.iter().all(|r| r.is_err()));
+        assert_eq!(engine.retry_queue_len().await, 2);
+
+        available.store(true, Ordering::SeqCst);
+        let results = engine.retry_failed().await;
+        assert!(results[0].is_ok(), "Recovered AI accepts the parked transaction: {:?}", results[0]);
+        assert!(results[1].as_ref().unwrap_err().starts_with("Volatile input rejected"));
+        assert_eq!(engine.retry_queue_len().await, 0, "Volatile rejections are final, not requeued");
+
+        // Direct volatile rejections never enter the queue
+        assert!(engine.process_transaction(signed_tx(&engine, "tx_volatile_2")).await.is_err());
+        assert_eq!(engine.retry_queue_len().await, 0);
+        assert!(engine.retry_failed().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_engine_enforces_exclusivity_policy() {
+        let engine = PITransactionEngine::new(stable_ai());
+
+        let mut bridged = signed_tx(&engine, "tx_bridge");
+        bridged.sender = "eth_bridge".to_string();
+        bridged.source_proof = engine.generate_source_proof(&bridged.tx_type, &bridged.sender);
+        assert_eq!(engine.process_transaction(bridged).await.unwrap_err(), "sender references non-PI network: eth");
+
+        let mut memo = signed_tx(&engine, "tx_memo");
+        memo.source_proof = "stellar".to_string();
+        assert_eq!(engine.process_transaction(memo).await.unwrap_err(), "source proof references non-PI network: stellar");
+
+        // A configured policy replaces the default rules
+        let policy = PiExclusivityPolicy::new(PiExclusivityConfig {
+            address_pattern: r"^testnet_[a-z]+$".to_string(),
+            banned_terms: vec!["miner".to_string()],
+        })
+        .unwrap();
+        let engine = PITransactionEngine::new(stable_ai()).with_exclusivity_policy(policy);
+        let mut testnet = signed_tx(&engine, "tx_testnet");
+        testnet.sender = "pool_7".to_string();
+        testnet.receiver = "testnet_dev".to_string();
+        testnet.source_proof = engine.generate_source_proof(&testnet.tx_type, &testnet.sender);
+        assert!(engine.process_transaction(testnet).await.is_ok());
+        assert_eq!(engine.process_transaction(signed_tx(&engine, "tx_miner")).await.unwrap_err(), "sender references non-PI network: miner");
+    }
+}
@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod test_quantum_ai_optimizer_predictive_maintenance {
+    use soroban_sdk::testutils::Ledger;
+    use soroban_sdk::{Env, Symbol};
+    use crate::quantum_ai_optimizer_predictive_maintenance::QuantumAiOptimizerPredictiveMaintenance;
+
+    #[test]
+    fn test_predicted_failures_reach_optimization_path() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, QuantumAiOptimizerPredictiveMaintenance);
+        let component = Symbol::new(&env, "transaction_engine");
+
+        let mut failures = 0;
+        for sequence in 1..=50 {
+            env.ledger().set_sequence_number(sequence);
+            env.as_contract(&contract_id, || {
+                let maintenance = QuantumAiOptimizerPredictiveMaintenance::predict_optimize_maintenance(env.clone(), component.clone());
+
+                // Same sequence and component always predict the same outcome
+                let repeat = QuantumAiOptimizerPredictiveMaintenance::predict_optimize_maintenance(env.clone(), component.clone());
+                assert_eq!(maintenance.predicted_failure, repeat.predicted_failure);
+
+                if maintenance.predicted_failure {
+                    failures += 1;
+                    assert!(maintenance.optimization_score < 100);
+                    assert_eq!(
+                        QuantumAiOptimizerPredictiveMaintenance::apply_quantum_optimization(env.clone(), maintenance),
+                        Symbol::new(&env, "optimization_applied")
+                    );
+                }
+            });
+        }
+
+        assert!(failures > 0, "At least one failure predicted across sequences");
+        assert!(failures < 50, "Not every sequence predicts a failure");
+    }
+
+    #[test]
+    fn test_history_is_bounded_per_component() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, QuantumAiOptimizerPredictiveMaintenance);
+        let engine = Symbol::new(&env, "transaction_engine");
+
+        for sequence in 1..=20 {
+            env.ledger().set_sequence_number(sequence);
+            env.as_contract(&contract_id, || {
+                QuantumAiOptimizerPredictiveMaintenance::predict_optimize_maintenance(env.clone(), engine.clone());
+            });
+        }
+
+        env.as_contract(&contract_id, || {
+            QuantumAiOptimizerPredictiveMaintenance::predict_optimize_maintenance(env.clone(), Symbol::new(&env, "ui_hub"));
+
+            // Only the last 16 records remain, oldest first
+            let history = QuantumAiOptimizerPredictiveMaintenance::get_maintenance_history(env.clone(), engine.clone());
+            assert_eq!(history.len(), 16);
+            assert_eq!(history.get(0).unwrap().id, Symbol::new(&env, "maintenance_5"));
+            assert_eq!(history.get(15).unwrap().id, Symbol::new(&env, "maintenance_20"));
+
+            assert!(QuantumAiOptimizerPredictiveMaintenance::get_maintenance_history(env.clone(), Symbol::new(&env, "monitor")).is_empty());
+            let status = QuantumAiOptimizerPredictiveMaintenance::get_optimization_status(env.clone());
+            assert_eq!(status.get(Symbol::new(&env, "components_optimized")), Some(2));
+        });
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod common;
+
+#[cfg(test)]
+mod test_shutdown {
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use crate::hyper_ai_core::AutonomousHyperAI;
+    use crate::pi_transaction_engine::PITransactionEngine;
+    use crate::pi_mainnet_accelerator::PiMainnetAccelerator;
+    use crate::ecosystem_isolation_shield::EcosystemIsolationShield;
+    use crate::developer_app_orchestrator::DeveloperAppOrchestrator;
+    use crate::super_app_controller::SuperAppController;
+    use crate::shutdown::ShutdownCoordinator;
+    use super::common::StableModel;
+
+    // Local endpoint that always reports Pi Network as compliant
+    async fn spawn_compliance_stub() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = [0u8; 1024];
+                let _ = socket.read(&mut buf).await;
+                let body = r#"{"compliant":true}"#;
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+        format!("http://{}/compliance", addr)
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_all_stops_every_task() {
+        let compliance_url = spawn_compliance_stub().await;
+        let ai_core = Arc::new(
+            AutonomousHyperAI::new()
+                .with_model(Box::new(StableModel))
+                .with_compliance_url(&compliance_url),
+        );
+        let tx_engine = Arc::new(PITransactionEngine::new(ai_core.clone()));
+        let mainnet_accelerator = Arc::new(PiMainnetAccelerator::new(ai_core.clone(), tx_engine.clone()));
+        let isolation_shield = Arc::new(EcosystemIsolationShield::new(ai_core.clone(), tx_engine.clone(), mainnet_accelerator.clone()));
+        let app_orchestrator = Arc::new(DeveloperAppOrchestrator::new(ai_core.clone(), tx_engine.clone(), mainnet_accelerator.clone(), isolation_shield.clone()));
+        let controller = Arc::new(SuperAppController::new(ai_core, tx_engine.clone(), mainnet_accelerator, isolation_shield.clone(), app_orchestrator));
+
+        let coordinator = ShutdownCoordinator::new();
+
+        let engine = tx_engine.clone();
+        let token = coordinator.token();
+        coordinator.register(tokio::spawn(async move { engine.run_processor(token).await })).await;
+
+        let shield = isolation_shield.clone();
+        let token = coordinator.token();
+        coordinator.register(tokio::spawn(async move { shield.run_stream_processor(token).await })).await;
+
+        let runner = controller.clone();
+        let token = coordinator.token();
+        coordinator
+            .register(tokio::spawn(async move {
+                runner.run_super_app(token).await.expect("controller loop exits cleanly");
+            }))
+            .await;
+
+        // Let every task reach its steady state before stopping
+        while !tx_engine.is_processor_running().await || controller.get_dashboard().await.recent_events.is_empty() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(coordinator.running_tasks().await, 3);
+
+        tokio::time::timeout(Duration::from_secs(5), coordinator.shutdown_all())
+            .await
+            .expect("shutdown_all returns promptly");
+
+        assert_eq!(coordinator.running_tasks().await, 0, "No task left running");
+        assert!(!tx_engine.is_processor_running().await);
+        let dashboard = controller.get_dashboard().await;
+        assert!(!dashboard.status.active);
+        assert_eq!(dashboard.recent_events[0].event_type, "super_app_shutdown");
+    }
+}
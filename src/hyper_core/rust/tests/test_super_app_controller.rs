@@ -0,0 +1,732 @@
+#[cfg(test)]
+mod common;
+
+#[cfg(test)]
+mod test_super_app_controller {
+    use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use async_trait::async_trait;
+    use serde_json::json;
+    use tokio_util::sync::CancellationToken;
+    use crate::clock::MockClock;
+    use crate::hyper_ai_core::{AutonomousHyperAI, ComplianceMode};
+    use crate::pi_transaction_engine::{PITransaction, PITransactionEngine, PITransactionType};
+    use crate::pi_mainnet_accelerator::{EvolutionMetrics, EvolutionSignals, PiMainnetAccelerator};
+    use crate::ecosystem_isolation_shield::{EcosystemIsolationShield, IsolationEvent, IsolationVerdict};
+    use crate::developer_app_orchestrator::{DeployResult, DeveloperAppOrchestrator, OrchestratorMetrics};
+    use crate::super_app_controller::{
+        replay_events, AppManager, CommandOutcome, CommandRequest, ComplianceSource, ControllerConfig, DataIsolator, MainnetManager, NonComplianceAction, ReplayError,
+        SuperAppController, TransactionProcessor,
+        EVENT_SCHEMA_VERSION,
+    };
+    use super::common::StableModel;
+
+    fn build_controller() -> SuperAppController {
+        build_controller_with_config(ControllerConfig::default())
+    }
+
+    fn build_controller_with_config(config: ControllerConfig) -> SuperAppController {
+        // Unroutable compliance endpoint keeps tests offline
+        let ai_core = Arc::new(AutonomousHyperAI::new().with_compliance_url("http://127.0.0.1:9/compliance"));
+        build_controller_with_ai(ai_core, config).0
+    }
+
+    fn build_controller_with_ai(
+        ai_core: Arc<AutonomousHyperAI>,
+        config: ControllerConfig,
+    ) -> (SuperAppController, Arc<PiMainnetAccelerator>) {
+        let tx_engine = Arc::new(PITransactionEngine::new(ai_core.clone()));
+        let mainnet_accelerator = Arc::new(PiMainnetAccelerator::new(ai_core.clone(), tx_engine.clone()));
+        let isolation_shield = Arc::new(EcosystemIsolationShield::new(ai_core.clone(), tx_engine.clone(), mainnet_accelerator.clone()));
+        let app_orchestrator = Arc::new(DeveloperAppOrchestrator::new(ai_core.clone(), tx_engine.clone(), mainnet_accelerator.clone(), isolation_shield.clone()));
+        let controller = SuperAppController::new_with_config(ai_core, tx_engine, mainnet_accelerator.clone(), isolation_shield, app_orchestrator, config);
+        (controller, mainnet_accelerator)
+    }
+
+    #[tokio::test]
+    async fn test_json_command_well_formed() {
+        let controller = build_controller();
+        let request: CommandRequest = serde_json::from_value(json!({
+            "method": "isolate_data",
+            "params": { "data": "PI" }
+        }))
+        .unwrap();
+
+        let response = controller.execute_json_command(request).await;
+        assert!(response.ok, "Well-formed request succeeds: {:?}", response.error);
+        assert!(response.error.is_none());
+        let sealed = response.result["DataSealed"]["payload"].as_str().unwrap();
+        assert!(sealed.starts_with("Sealed PI Data: sha256:") && sealed.ends_with(":2:PI"), "{}", sealed);
+    }
+
+    #[tokio::test]
+    async fn test_json_command_malformed_params() {
+        let controller = build_controller();
+        let request = CommandRequest {
+            method: "deploy_app".to_string(),
+            params: json!({ "developer": 42 }),
+        };
+
+        let response = controller.execute_json_command(request).await;
+        assert!(!response.ok);
+        assert!(response.result.is_null());
+        assert!(response.error.unwrap().starts_with("Invalid params for deploy_app"));
+    }
+
+    #[tokio::test]
+    async fn test_json_command_unknown_method() {
+        let controller = build_controller();
+        let request = CommandRequest { method: "mint_btc".to_string(), params: json!(null) };
+
+        let response = controller.execute_json_command(request).await;
+        assert!(!response.ok);
+        assert_eq!(response.error.as_deref(), Some("Unknown command."));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_command_flood_is_rate_limited() {
+        let controller = build_controller_with_config(ControllerConfig {
+            rate_limit_capacity: 2.0,
+            rate_limit_refill_per_sec: 10.0,
+            ..ControllerConfig::default()
+        });
+
+        // Burst faster than the refill rate: only the bucket capacity gets through
+        let mut results = Vec::new();
+        for _ in 0..5 {
+            results.push(controller.execute_command("isolate_data", vec!["PI".to_string()]).await);
+        }
+        assert!(results[0].is_ok() && results[1].is_ok());
+        assert!(results[2..].iter().all(|r| r.as_ref().unwrap_err() == "rate limited"));
+
+        // After waiting for a refill, commands succeed again
+        tokio::time::advance(std::time::Duration::from_millis(200)).await;
+        assert!(controller.execute_command("isolate_data", vec!["PI".to_string()]).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_event_log_evicts_oldest_beyond_cap() {
+        let controller = build_controller_with_config(ControllerConfig { max_events: 3, ..ControllerConfig::default() });
+
+        // Unknown commands still log one event each, named after the command
+        for i in 0..10 {
+            assert!(controller.execute_command(&format!("cmd_{}", i), vec![]).await.is_err());
+        }
+
+        let events = controller.get_events().await;
+        let types: Vec<&str> = events.iter().map(|e| e.event_type.as_str()).collect();
+        assert_eq!(types, ["cmd_7", "cmd_8", "cmd_9"], "Only the newest events survive");
+
+        let recent = controller.get_dashboard().await.recent_events;
+        assert_eq!(recent.len(), 3);
+        assert_eq!(recent[0].event_type, "cmd_9", "Dashboard still lists newest first");
+    }
+
+    #[tokio::test]
+    async fn test_health_check_unhealthy_when_ai_unreachable() {
+        let controller = build_controller_with_config(ControllerConfig {
+            health_probe_timeout: std::time::Duration::from_millis(500),
+            ..ControllerConfig::default()
+        });
+
+        let report = controller.health_check().await;
+        assert!(!report.ai_reachable, "Compliance endpoint is unreachable");
+        assert!(!report.processor_running, "Processor not started");
+        assert!(!report.mainnet_progress_nonzero, "Mainnet not accelerated");
+        assert!(report.apps_orchestrator_ok, "Fresh orchestrator has no drift");
+        assert!(!report.healthy);
+    }
+
+    #[tokio::test]
+    async fn test_deploy_command_event_records_duration() {
+        let ai_core = Arc::new(AutonomousHyperAI::new().with_model(Box::new(StableModel)));
+        let (controller, accelerator) = build_controller_with_ai(ai_core, ControllerConfig::default());
+        accelerator.accelerate_mainnet().await.unwrap();
+
+        let result = controller.execute_command("deploy_app", vec!["dev_123".to_string(), "Stable PI code".to_string()]).await;
+        assert!(result.is_ok(), "Deploy succeeds: {:?}", result);
+
+        let dashboard = controller.get_dashboard().await;
+        let event = &dashboard.recent_events[0];
+        assert_eq!(event.event_type, "deploy_app");
+        let duration_ms = event.duration_ms.expect("Command events carry a duration");
+        assert!(duration_ms < 5_000, "Plausible latency: {}ms", duration_ms);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_dashboard_and_commands_complete() {
+        let ai_core = Arc::new(
+            AutonomousHyperAI::new()
+                .with_model(Box::new(StableModel))
+                .with_compliance_mode(ComplianceMode::AlwaysCompliant),
+        );
+        let config = ControllerConfig { rate_limit_capacity: 1_000.0, ..ControllerConfig::default() };
+        let (controller, _) = build_controller_with_ai(ai_core, config);
+        let controller = Arc::new(controller);
+
+        // The monitoring loop accelerates mainnet and logs while readers and commands race it
+        let shutdown = CancellationToken::new();
+        let runner = controller.clone();
+        let token = shutdown.clone();
+        let super_app = tokio::spawn(async move { runner.run_super_app(token).await });
+
+        let mut handles = Vec::new();
+        for i in 0..100 {
+            let controller = controller.clone();
+            handles.push(tokio::spawn(async move {
+                if i % 2 == 0 {
+                    controller.get_dashboard().await;
+                } else {
+                    let command = if i % 4 == 1 { "deploy_app" } else { "isolate_data" };
+                    let _ = controller.execute_command(command, vec![format!("dev_{}", i), "Stable PI code".to_string()]).await;
+                }
+            }));
+        }
+
+        let all_done = async {
+            for handle in handles {
+                handle.await.unwrap();
+            }
+            shutdown.cancel();
+            super_app.await.unwrap()
+        };
+        let result = tokio::time::timeout(Duration::from_secs(10), all_done).await.expect("no deadlock under contention");
+        assert!(result.is_ok(), "{:?}", result);
+        assert!(!controller.get_dashboard().await.status.active);
+    }
+
+    // Mock subsystems: compliance reports a breach once the monitoring loop checks status
+    struct BreachingCompliance;
+
+    #[async_trait]
+    impl ComplianceSource for BreachingCompliance {
+        async fn enforce_compliance(&self) -> Result<(), String> {
+            Ok(())
+        }
+
+        async fn probe_compliance(&self) -> Result<bool, String> {
+            Ok(false)
+        }
+
+        async fn get_status(&self) -> (bool, bool) {
+            (false, true)
+        }
+    }
+
+    struct MockSubsystems;
+
+    fn mock_deploy_result() -> DeployResult {
+        DeployResult { app_id: "deployed".to_string(), code_hash: String::new(), content_address: String::new(), pi_charged: 0.0 }
+    }
+
+    #[async_trait]
+    impl TransactionProcessor for MockSubsystems {
+        async fn process_transaction(&self, _tx: PITransaction) -> Result<(), String> {
+            Ok(())
+        }
+
+        async fn get_transactions(&self) -> Vec<PITransaction> {
+            Vec::new()
+        }
+
+        async fn is_processor_running(&self) -> bool {
+            false
+        }
+    }
+
+    #[async_trait]
+    impl MainnetManager for MockSubsystems {
+        async fn accelerate_mainnet(&self) -> Result<(), String> {
+            Ok(())
+        }
+
+        async fn evolve_system(&self, _signals: EvolutionSignals) -> Result<(), String> {
+            Ok(())
+        }
+
+        async fn get_metrics(&self) -> EvolutionMetrics {
+            EvolutionMetrics { mainnet_open_progress: 1.0, apps_processed: 0, compliance_rate: 1.0 }
+        }
+    }
+
+    #[async_trait]
+    impl DataIsolator for MockSubsystems {
+        async fn process_stream_verdict(&self, data: String) -> Result<IsolationVerdict, String> {
+            Ok(IsolationVerdict::Sealed { payload: data, hash: String::new() })
+        }
+
+        async fn get_events(&self) -> Vec<IsolationEvent> {
+            Vec::new()
+        }
+    }
+
+    #[async_trait]
+    impl AppManager for MockSubsystems {
+        async fn deploy_app(&self, _developer: String, _code: String) -> Result<DeployResult, String> {
+            Ok(mock_deploy_result())
+        }
+
+        async fn run_apps(&self) -> Result<(), String> {
+            Ok(())
+        }
+
+        async fn evolution_signals(&self) -> EvolutionSignals {
+            EvolutionSignals::default()
+        }
+
+        async fn get_metrics(&self) -> OrchestratorMetrics {
+            OrchestratorMetrics { apps_managed: 0, pi_consumed_total: 0.0, uptime_rate: 1.0, apps_halted: 0 }
+        }
+
+        async fn reconcile_app_counts(&self) -> (u64, u64) {
+            (0, 0)
+        }
+
+        async fn halt_by_code_hash(&self, _code_hash: String) -> usize {
+            0
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_run_super_app_breaks_on_compliance_breach() {
+        let mocks = Arc::new(MockSubsystems);
+        let controller = SuperAppController::new(
+            Arc::new(BreachingCompliance),
+            mocks.clone(),
+            mocks.clone(),
+            mocks.clone(),
+            mocks,
+        );
+
+        // Paused clock auto-advances through the monitoring interval
+        let result = controller.run_super_app(CancellationToken::new()).await;
+        assert!(result.is_ok(), "Breach ends the loop cleanly: {:?}", result);
+
+        let dashboard = controller.get_dashboard().await;
+        assert!(!dashboard.status.active, "Controller deactivated on breach");
+        assert!(!dashboard.status.pi_ecosystem_stable);
+        let event_types: Vec<&str> = dashboard.recent_events.iter().map(|e| e.event_type.as_str()).collect();
+        assert_eq!(event_types, vec!["compliance_breach", "super_app_init"]);
+    }
+
+    // Deploys hang in the isolation/AI step far longer than the command timeout
+    struct SlowDeployer {
+        recorded: Arc<AtomicU64>,
+    }
+
+    #[async_trait]
+    impl AppManager for SlowDeployer {
+        async fn deploy_app(&self, _developer: String, _code: String) -> Result<DeployResult, String> {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            self.recorded.fetch_add(1, Ordering::SeqCst);
+            Ok(mock_deploy_result())
+        }
+
+        async fn run_apps(&self) -> Result<(), String> {
+            Ok(())
+        }
+
+        async fn evolution_signals(&self) -> EvolutionSignals {
+            EvolutionSignals::default()
+        }
+
+        async fn get_metrics(&self) -> OrchestratorMetrics {
+            OrchestratorMetrics { apps_managed: self.recorded.load(Ordering::SeqCst), pi_consumed_total: 0.0, uptime_rate: 1.0, apps_halted: 0 }
+        }
+
+        async fn reconcile_app_counts(&self) -> (u64, u64) {
+            (0, 0)
+        }
+
+        async fn halt_by_code_hash(&self, _code_hash: String) -> usize {
+            0
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_hung_command_times_out_without_recording() {
+        let recorded = Arc::new(AtomicU64::new(0));
+        let mocks = Arc::new(MockSubsystems);
+        let controller = SuperAppController::new_with_config(
+            Arc::new(BreachingCompliance),
+            mocks.clone(),
+            mocks.clone(),
+            mocks,
+            Arc::new(SlowDeployer { recorded: recorded.clone() }),
+            ControllerConfig { command_timeout: Duration::from_millis(100), ..ControllerConfig::default() },
+        );
+
+        let result = controller.execute_command("deploy_app", vec!["dev_1".to_string(), "PI code".to_string()]).await;
+        assert_eq!(result.unwrap_err(), "command timed out");
+
+        let response = controller
+            .execute_json_command(CommandRequest {
+                method: "deploy_app".to_string(),
+                params: json!({ "developer": "dev_2", "code": "PI code" }),
+            })
+            .await;
+        assert_eq!(response.error.as_deref(), Some("command timed out"));
+
+        // Well past the mock's sleep: the abandoned deploys never complete
+        tokio::time::sleep(Duration::from_secs(120)).await;
+        assert_eq!(recorded.load(Ordering::SeqCst), 0, "Timed-out deploys are not recorded");
+        assert_eq!(controller.get_dashboard().await.recent_events[0].details, "Command failed: command timed out");
+    }
+
+    fn temp_log_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("{}_{}.jsonl", name, uuid::Uuid::new_v4()))
+    }
+
+    #[tokio::test]
+    async fn test_event_log_round_trips_through_replay() {
+        let path = temp_log_path("superpi_events");
+        let controller = build_controller();
+        assert!(controller.execute_command("cmd_a", vec![]).await.is_err());
+        assert!(controller.execute_command("cmd_b", vec![]).await.is_err());
+        controller.export_event_log(&path).await.unwrap();
+
+        let replayed = replay_events(&path).unwrap();
+        let types: Vec<&str> = replayed.iter().map(|e| e.event_type.as_str()).collect();
+        assert_eq!(types, ["cmd_a", "cmd_b"]);
+        assert!(replayed.iter().all(|e| e.schema_version == EVENT_SCHEMA_VERSION && e.duration_ms.is_some()));
+
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_v1_event_migrates_and_unknown_version_rejected() {
+        let path = temp_log_path("superpi_events_v1");
+        let v1 = json!({
+            "schema_version": 1,
+            "id": "evt_1",
+            "event_type": "super_app_init",
+            "details": "Super App fully operational.",
+            "timestamp": "2024-01-01T00:00:00Z"
+        });
+        std::fs::write(&path, format!("{}\n", v1)).unwrap();
+
+        let replayed = replay_events(&path).unwrap();
+        assert_eq!(replayed.len(), 1);
+        assert_eq!(replayed[0].id, "evt_1");
+        assert_eq!(replayed[0].schema_version, EVENT_SCHEMA_VERSION);
+        assert_eq!(replayed[0].duration_ms, None);
+
+        let future = json!({ "schema_version": 99, "id": "evt_2" });
+        std::fs::write(&path, format!("{}\n{}\n", v1, future)).unwrap();
+        assert_eq!(replay_events(&path).unwrap_err(), ReplayError::UnsupportedVersion { line: 2, version: 99 });
+
+        std::fs::write(&path, "not json\n").unwrap();
+        assert!(matches!(replay_events(&path), Err(ReplayError::Malformed { line: 1, .. })));
+
+        let _ = std::fs::remove_file(path);
+    }
+
+    // Compliant subsystems that count compliance checks and evolutions; signals can drift every read
+    #[derive(Default)]
+    struct CadenceMocks {
+        compliance_checks: AtomicU64,
+        evolutions: AtomicU64,
+        signal_reads: AtomicU64,
+        drifting: AtomicBool,
+    }
+
+    #[async_trait]
+    impl ComplianceSource for CadenceMocks {
+        async fn enforce_compliance(&self) -> Result<(), String> {
+            self.compliance_checks.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+
+        async fn probe_compliance(&self) -> Result<bool, String> {
+            Ok(true)
+        }
+
+        async fn get_status(&self) -> (bool, bool) {
+            (true, false)
+        }
+    }
+
+    #[async_trait]
+    impl MainnetManager for CadenceMocks {
+        async fn accelerate_mainnet(&self) -> Result<(), String> {
+            Ok(())
+        }
+
+        async fn evolve_system(&self, _signals: EvolutionSignals) -> Result<(), String> {
+            self.evolutions.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+
+        async fn get_metrics(&self) -> EvolutionMetrics {
+            EvolutionMetrics { mainnet_open_progress: 1.0, apps_processed: 0, compliance_rate: 1.0 }
+        }
+    }
+
+    #[async_trait]
+    impl AppManager for CadenceMocks {
+        async fn deploy_app(&self, _developer: String, _code: String) -> Result<DeployResult, String> {
+            Ok(mock_deploy_result())
+        }
+
+        async fn run_apps(&self) -> Result<(), String> {
+            Ok(())
+        }
+
+        async fn evolution_signals(&self) -> EvolutionSignals {
+            let read = self.signal_reads.fetch_add(1, Ordering::SeqCst);
+            let recent_isolation_events = if self.drifting.load(Ordering::SeqCst) { read as usize } else { 0 };
+            EvolutionSignals { recent_isolation_events, app_uptime_rate: 1.0 }
+        }
+
+        async fn get_metrics(&self) -> OrchestratorMetrics {
+            OrchestratorMetrics { apps_managed: 0, pi_consumed_total: 0.0, uptime_rate: 1.0, apps_halted: 0 }
+        }
+
+        async fn reconcile_app_counts(&self) -> (u64, u64) {
+            (0, 0)
+        }
+
+        async fn halt_by_code_hash(&self, _code_hash: String) -> usize {
+            0
+        }
+    }
+
+    // Run the monitoring loop for nine 10s ticks, then shut it down
+    async fn run_nine_ticks(mocks: Arc<CadenceMocks>, evolve_every_n_ticks: u32) {
+        let subsystems = Arc::new(MockSubsystems);
+        let controller = Arc::new(SuperAppController::new_with_config(
+            mocks.clone(),
+            subsystems.clone(),
+            mocks.clone(),
+            subsystems,
+            mocks,
+            ControllerConfig { evolve_every_n_ticks, ..ControllerConfig::default() },
+        ));
+        let shutdown = CancellationToken::new();
+        let runner = controller.clone();
+        let token = shutdown.clone();
+        let handle = tokio::spawn(async move { runner.run_super_app(token).await });
+
+        tokio::time::sleep(Duration::from_secs(95)).await;
+        shutdown.cancel();
+        handle.await.unwrap().unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_evolution_cadence_respected_while_compliance_checked_every_tick() {
+        let steady = Arc::new(CadenceMocks::default());
+        run_nine_ticks(steady.clone(), 3).await;
+        assert_eq!(steady.compliance_checks.load(Ordering::SeqCst), 10, "Compliance enforced at startup and on every tick");
+        assert_eq!(steady.evolutions.load(Ordering::SeqCst), 3, "Steady signals evolve every 3rd tick");
+
+        // Signals that change between ticks trigger evolution regardless of cadence (from the second tick on)
+        let drifting = Arc::new(CadenceMocks::default());
+        drifting.drifting.store(true, Ordering::SeqCst);
+        run_nine_ticks(drifting.clone(), 3).await;
+        assert_eq!(drifting.evolutions.load(Ordering::SeqCst), 8);
+
+        // The default cadence keeps evolving every tick
+        let default = Arc::new(CadenceMocks::default());
+        run_nine_ticks(default.clone(), ControllerConfig::default().evolve_every_n_ticks).await;
+        assert_eq!(default.evolutions.load(Ordering::SeqCst), 9);
+    }
+
+    // Non-compliant for the first `breaches` status reads, compliant afterwards
+    struct RecoveringCompliance {
+        reads: AtomicU64,
+        breaches: u64,
+    }
+
+    #[async_trait]
+    impl ComplianceSource for RecoveringCompliance {
+        async fn enforce_compliance(&self) -> Result<(), String> {
+            Ok(())
+        }
+
+        async fn probe_compliance(&self) -> Result<bool, String> {
+            Ok(true)
+        }
+
+        async fn get_status(&self) -> (bool, bool) {
+            let compliant = self.reads.fetch_add(1, Ordering::SeqCst) >= self.breaches;
+            (compliant, !compliant)
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_pause_and_retry_resumes_after_compliance_recovers() {
+        let mocks = Arc::new(MockSubsystems);
+        let controller = Arc::new(SuperAppController::new_with_config(
+            Arc::new(RecoveringCompliance { reads: AtomicU64::new(0), breaches: 2 }),
+            mocks.clone(),
+            mocks.clone(),
+            mocks.clone(),
+            mocks,
+            ControllerConfig {
+                non_compliance_action: NonComplianceAction::PauseAndRetry { interval: Duration::from_secs(1) },
+                ..ControllerConfig::default()
+            },
+        ));
+        let shutdown = CancellationToken::new();
+        let runner = controller.clone();
+        let token = shutdown.clone();
+        let handle = tokio::spawn(async move { runner.run_super_app(token).await });
+
+        // Breach at 10s, one failed retry at 11s, recovery at 12s, then a normal tick at 22s
+        tokio::time::sleep(Duration::from_secs(25)).await;
+        assert!(!handle.is_finished(), "Loop keeps running after a breach");
+        let dashboard = controller.get_dashboard().await;
+        assert!(dashboard.status.active && dashboard.status.pi_ecosystem_stable);
+        let event_types: Vec<&str> = dashboard.recent_events.iter().map(|e| e.event_type.as_str()).collect();
+        assert_eq!(event_types, ["evolution_cycle", "compliance_restored", "compliance_breach", "super_app_init"]);
+
+        shutdown.cancel();
+        assert!(handle.await.unwrap().is_ok());
+        assert!(!controller.get_dashboard().await.status.active);
+    }
+
+    // Status follows the latest enforcement: checks 1 and 2 (the first two ticks) find a breach, the rest are compliant
+    #[derive(Default)]
+    struct FlappingCompliance {
+        checks: AtomicU64,
+        breached: AtomicBool,
+    }
+
+    #[async_trait]
+    impl ComplianceSource for FlappingCompliance {
+        async fn enforce_compliance(&self) -> Result<(), String> {
+            let check = self.checks.fetch_add(1, Ordering::SeqCst);
+            self.breached.store((1..=2).contains(&check), Ordering::SeqCst);
+            Ok(())
+        }
+
+        async fn probe_compliance(&self) -> Result<bool, String> {
+            Ok(!self.breached.load(Ordering::SeqCst))
+        }
+
+        async fn get_status(&self) -> (bool, bool) {
+            let breached = self.breached.load(Ordering::SeqCst);
+            (!breached, breached)
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_degraded_mode_resumes_evolution_after_compliance_recovers() {
+        let subsystems = Arc::new(MockSubsystems);
+        let cadence = Arc::new(CadenceMocks::default());
+        let controller = Arc::new(SuperAppController::new_with_config(
+            Arc::new(FlappingCompliance::default()),
+            subsystems.clone(),
+            cadence.clone(),
+            subsystems,
+            cadence.clone(),
+            ControllerConfig { non_compliance_action: NonComplianceAction::DegradedMode, ..ControllerConfig::default() },
+        ));
+        let shutdown = CancellationToken::new();
+        let runner = controller.clone();
+        let token = shutdown.clone();
+        let handle = tokio::spawn(async move { runner.run_super_app(token).await });
+
+        // Breach at 10s and 20s, recovery at 30s, then a normal tick at 40s
+        tokio::time::sleep(Duration::from_secs(45)).await;
+        assert!(!handle.is_finished(), "Degraded mode keeps the loop running");
+        let dashboard = controller.get_dashboard().await;
+        assert!(dashboard.status.active && dashboard.status.pi_ecosystem_stable);
+        let event_types: Vec<&str> = dashboard.recent_events.iter().map(|e| e.event_type.as_str()).collect();
+        assert_eq!(event_types, ["evolution_cycle", "evolution_cycle", "compliance_restored", "compliance_degraded", "super_app_init"]);
+        assert_eq!(cadence.evolutions.load(Ordering::SeqCst), 2, "Evolution suspended while degraded, resumed after recovery");
+
+        shutdown.cancel();
+        assert!(handle.await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_mock_clock_sets_event_timestamps() {
+        let at = chrono::DateTime::parse_from_rfc3339("2024-03-14T15:09:26Z").unwrap().with_timezone(&chrono::Utc);
+        let clock = Arc::new(MockClock::new(at));
+        let controller = build_controller().with_clock(clock.clone());
+
+        assert!(controller.execute_command("cmd_a", vec![]).await.is_err());
+        clock.set(at + chrono::Duration::minutes(5));
+        assert!(controller.execute_command("cmd_b", vec![]).await.is_err());
+
+        let timestamps: Vec<_> = controller.get_events().await.iter().map(|e| e.timestamp).collect();
+        assert_eq!(timestamps, [at, at + chrono::Duration::minutes(5)]);
+    }
+
+    #[tokio::test]
+    async fn test_halt_by_code_hash_command_records_recall() {
+        use sha2::{Digest, Sha256};
+
+        let ai_core = Arc::new(AutonomousHyperAI::new().with_model(Box::new(StableModel)));
+        let (controller, accelerator) = build_controller_with_ai(ai_core, ControllerConfig::default());
+        accelerator.accelerate_mainnet().await.unwrap();
+        for developer in ["dev_1", "dev_2"] {
+            controller.execute_command("deploy_app", vec![developer.to_string(), "Stable PI code".to_string()]).await.unwrap();
+        }
+
+        let code_hash = format!("{:x}", Sha256::digest("Stable PI code"));
+        let response = controller
+            .execute_json_command(CommandRequest { method: "halt_by_code_hash".to_string(), params: json!({ "code_hash": code_hash }) })
+            .await;
+        assert!(response.ok, "{:?}", response.error);
+
+        let dashboard = controller.get_dashboard().await;
+        let recall = dashboard.recent_events.iter().find(|e| e.event_type == "code_hash_recalled").unwrap();
+        assert!(recall.details.starts_with("Halted 2 app(s)"), "{}", recall.details);
+    }
+
+    #[tokio::test]
+    async fn test_commands_return_matching_outcomes() {
+        use sha2::{Digest, Sha256};
+
+        let ai_core = Arc::new(AutonomousHyperAI::new().with_model(Box::new(StableModel)));
+        let (controller, accelerator) = build_controller_with_ai(ai_core, ControllerConfig::default());
+        accelerator.accelerate_mainnet().await.unwrap();
+
+        let deployed = controller.execute_command("deploy_app", vec!["dev_1".to_string(), "Stable PI code".to_string()]).await.unwrap();
+        let CommandOutcome::Deployed { app_id } = &deployed else { panic!("Expected Deployed, got {:?}", deployed) };
+        assert_eq!(deployed.to_string(), format!("App {} deployed successfully.", app_id));
+
+        let sealed = controller.execute_command("isolate_data", vec!["PI".to_string()]).await.unwrap();
+        let CommandOutcome::DataSealed { hash, payload } = &sealed else { panic!("Expected DataSealed, got {:?}", sealed) };
+        assert_eq!(*hash, format!("{:x}", Sha256::digest("PI")));
+        assert_eq!(sealed.to_string(), *payload, "Display keeps the sealed string");
+
+        let isolated = controller.execute_command("isolate_data", vec!["Bitcoin news".to_string()]).await.unwrap();
+        assert!(matches!(isolated, CommandOutcome::DataIsolated { score } if score > 0.3), "{:?}", isolated);
+
+        let code_hash = format!("{:x}", Sha256::digest("Stable PI code"));
+        let halted = controller.execute_command("halt_by_code_hash", vec![code_hash.clone()]).await.unwrap();
+        assert_eq!(halted, CommandOutcome::AppsHalted { code_hash, count: 1 });
+
+        // The JSON interface carries the structured outcome
+        let response = controller
+            .execute_json_command(CommandRequest { method: "isolate_data".to_string(), params: json!({ "data": "PI" }) })
+            .await;
+        assert_eq!(response.result, serde_json::to_value(&sealed).unwrap());
+        assert_eq!(response.result["DataSealed"]["payload"], json!(payload));
+    }
+
+    #[tokio::test]
+    async fn test_process_transaction_command_returns_queued() {
+        let mocks = Arc::new(MockSubsystems);
+        let controller = SuperAppController::new(Arc::new(BreachingCompliance), mocks.clone(), mocks.clone(), mocks.clone(), mocks);
+        let tx = PITransaction {
+            id: "tx_1".to_string(),
+            sender: "miner_123".to_string(),
+            receiver: "pi_dev456".to_string(),
+            amount: 10.0,
+            tx_type: PITransactionType::MiningReward,
+            source_proof: String::new(),
+            timestamp: 0,
+            fee: 0.0,
+            nonce: None,
+        };
+
+        let outcome = controller.execute_command("process_transaction", vec![serde_json::to_string(&tx).unwrap()]).await.unwrap();
+        assert_eq!(outcome, CommandOutcome::TransactionQueued);
+        assert_eq!(outcome.to_string(), "Transaction processed.");
+    }
+}
@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod test_ultimate_deployment_script {
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{Address, Env, Symbol};
+    use crate::ultimate_deployment_script::UltimateDeploymentScript;
+
+    // Every contract module with a source file, excluding the deployment script itself
+    const CONTRACT_MODULE_COUNT: u32 = 38;
+
+    #[test]
+    fn test_init_all_initializes_every_module_in_order() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, UltimateDeploymentScript);
+        let admin = Address::generate(&env);
+        env.mock_all_auths();
+
+        env.as_contract(&contract_id, || {
+            let initialized = UltimateDeploymentScript::init_all(env.clone(), admin.clone());
+
+            assert_eq!(initialized.len(), CONTRACT_MODULE_COUNT, "One symbol per initialized module");
+            assert_eq!(initialized.first().unwrap(), Symbol::new(&env, "ahi_ai_core"), "AI core has no dependencies");
+            assert_eq!(initialized.last().unwrap(), Symbol::new(&env, "eternal_supremacy_capstone"), "Final capstone depends on the rest");
+            assert_eq!(crate::access_control::get_admin(&env), admin);
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_init_all_requires_admin_auth() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, UltimateDeploymentScript);
+
+        env.as_contract(&contract_id, || {
+            // No auth from the admin has been provided
+            UltimateDeploymentScript::init_all(env.clone(), Address::generate(&env));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "already initialized")]
+    fn test_init_all_refuses_second_run() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, UltimateDeploymentScript);
+        env.mock_all_auths();
+
+        env.as_contract(&contract_id, || {
+            UltimateDeploymentScript::init_all(env.clone(), Address::generate(&env));
+        });
+        env.as_contract(&contract_id, || {
+            UltimateDeploymentScript::init_all(env.clone(), Address::generate(&env));
+        });
+    }
+}
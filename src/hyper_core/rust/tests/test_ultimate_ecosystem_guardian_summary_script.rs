@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod test_ultimate_ecosystem_guardian_summary_script {
+    use soroban_sdk::{Env, String, Symbol};
+    use crate::ultimate_ecosystem_guardian_summary_script::UltimateEcosystemGuardianSummaryScript;
+
+    #[test]
+    fn test_enforcing_threat_increments_halt_counter() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, UltimateEcosystemGuardianSummaryScript);
+
+        env.as_contract(&contract_id, || {
+            let mut summary = UltimateEcosystemGuardianSummaryScript::generate_guardian_summary(env.clone(), Symbol::new(&env, "status"));
+            assert_eq!(summary.threats_halted, 0);
+
+            // A summary below full supremacy is a threat and halts the ecosystem
+            summary.supremacy_level = 90;
+            assert_eq!(
+                UltimateEcosystemGuardianSummaryScript::enforce_guardian_protection(env.clone(), summary),
+                Symbol::new(&env, "protection_enforced")
+            );
+
+            let next = UltimateEcosystemGuardianSummaryScript::generate_guardian_summary(env.clone(), Symbol::new(&env, "status"));
+            assert_eq!(next.threats_halted, 1);
+            assert_eq!(
+                UltimateEcosystemGuardianSummaryScript::enforce_guardian_protection(env.clone(), next),
+                Symbol::new(&env, "protection_stable")
+            );
+
+            let status = UltimateEcosystemGuardianSummaryScript::get_guardian_status(env.clone());
+            assert_eq!(status.get(Symbol::new(&env, "threats_neutralized")), Some(1));
+            assert_eq!(status.get(Symbol::new(&env, "summaries_generated")), Some(2));
+        });
+    }
+
+    #[test]
+    fn test_report_renders_large_counters() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, UltimateEcosystemGuardianSummaryScript);
+
+        env.as_contract(&contract_id, || {
+            let mut summary = UltimateEcosystemGuardianSummaryScript::generate_guardian_summary(env.clone(), Symbol::new(&env, "status"));
+            summary.threats_halted = i64::MAX;
+
+            let report = UltimateEcosystemGuardianSummaryScript::render_holographic_report(env.clone(), summary);
+            assert_eq!(report.get(1), Some(String::from_str(&env, "status")));
+            assert_eq!(report.get(3), Some(String::from_str(&env, "Threats Halted: 9223372036854775807")));
+        });
+    }
+}